@@ -0,0 +1,332 @@
+pub use self::function::Function;
+use {
+    crate::query::{
+        Argument,
+        Query,
+        Where,
+    },
+    ord_str_map::OrdStrMap,
+    parser::{
+        alphabetics,
+        brace_close,
+        brace_open,
+        camel_case,
+        colon,
+        doc_comments,
+        literal,
+        option,
+        pascal_case,
+        spaces,
+        Input,
+        ParseError,
+        ParseResult,
+        Span,
+    },
+    std::borrow::Cow,
+};
+
+/// Aggregate functions.
+pub mod function;
+
+/// The fields that are aggregated with a function.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Selection<'a> {
+    /// The aggregate function.
+    pub function: Function,
+    /// The names of the fields.
+    pub field_names: Vec<Cow<'a, str>>,
+    /// The span of the selection, from the function to the closing brace.
+    pub span: Span,
+}
+
+/// An aggregate query: counts the matching records of a model and
+/// aggregates the values of their fields, optionally per group.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Aggregate<'a> {
+    /// The name of the aggregate. Used as the name of the generated
+    /// function.
+    pub name: Cow<'a, str>,
+    /// The arguments of the aggregate.
+    pub arguments: OrdStrMap<Argument<'a>>,
+    /// The name of the aggregated model.
+    pub model_name: Cow<'a, str>,
+    /// Whether the matching records are counted.
+    pub count: bool,
+    /// The aggregated fields, by function.
+    pub selections: Vec<Selection<'a>>,
+    /// The names of the fields whose values form the groups.
+    pub group_by: Vec<Cow<'a, str>>,
+    /// The where clause of the aggregate.
+    pub r#where: Option<Where<'a>>,
+    /// The span of the aggregate, from the `aggregate` keyword to the closing
+    /// brace.
+    pub span: Span,
+    /// The documentation of the aggregate, taken from its doc comments.
+    pub doc: Option<Cow<'a, str>>,
+}
+
+impl<'a> Aggregate<'a> {
+    /// Parse a block of field names from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a block of at
+    /// least one field name.
+    fn parse_field_names(
+        input: Input<'a>
+    ) -> ParseResult<'a, Vec<Cow<'a, str>>> {
+        let (_, input) = brace_open(input)?;
+        let (_, mut input) = spaces(input)?;
+        let mut field_names = Vec::new();
+
+        while let Ok((field_name, new_input)) = camel_case(input) {
+            let (_, new_input) = spaces(new_input)?;
+
+            field_names.push(field_name.into());
+            input = new_input;
+        }
+
+        if field_names.is_empty() {
+            return Err(ParseError::custom(
+                "Expected at least one field name.",
+                input.span(),
+            ));
+        }
+
+        let (_, input) = brace_close(input)?;
+
+        Ok((field_names, input))
+    }
+
+    /// Parse an aggregate from the given input. The body contains `count`,
+    /// `avg`, `max`, `min`, `sum` and `groupBy` in any order, followed by an
+    /// optional where clause.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid
+    /// aggregate, or if the body selects `count`, a function or `groupBy`
+    /// more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::aggregate::{
+    ///         Aggregate,
+    ///         Function,
+    ///     },
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = "aggregate postStats: Post {
+    ///   count
+    ///   avg {
+    ///     views
+    ///   }
+    /// }";
+    ///
+    /// let (aggregate, _) = Aggregate::parse(Input::new(input)).unwrap();
+    ///
+    /// assert_eq!(aggregate.model_name, "Post");
+    /// assert!(aggregate.count);
+    /// assert_eq!(aggregate.selections[0].function, Function::Avg);
+    /// assert_eq!(aggregate.selections[0].field_names, vec!["views"]);
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (doc, start) = doc_comments(input)?;
+        let (_, input) = literal(start, "aggregate")?;
+        let (_, input) = spaces(input)?;
+        let (name, input) = alphabetics(input)?;
+        let (_, input) = spaces(input)?;
+        let (arguments, input) = Query::parse_arguments(input)?;
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+        let (model_name, input) = pascal_case(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, mut input) = spaces(input)?;
+        let mut count = false;
+        let mut selections: Vec<Selection> = Vec::new();
+        let mut group_by = Vec::new();
+
+        loop {
+            let item = input;
+
+            let duplicate = |keyword: &str, end: Input<'a>| {
+                Err(ParseError::custom(
+                    format!("Duplicate `{keyword}` in aggregate `{name}`."),
+                    item.span_to(end),
+                ))
+            };
+
+            if let Ok((_, end)) = literal(input, "count") {
+                if count {
+                    return duplicate("count", end);
+                }
+
+                count = true;
+                (_, input) = spaces(end)?;
+            } else if let Ok((_, end)) = literal(input, "groupBy") {
+                let (_, end) = spaces(end)?;
+                let (field_names, end) = Self::parse_field_names(end)?;
+
+                if !group_by.is_empty() {
+                    return duplicate("groupBy", end);
+                }
+
+                group_by = field_names;
+                (_, input) = spaces(end)?;
+            } else if let Ok((function, end)) = Function::parse(input) {
+                let (_, end) = spaces(end)?;
+                let (field_names, end) = Self::parse_field_names(end)?;
+
+                if selections
+                    .iter()
+                    .any(|selection| selection.function == function)
+                {
+                    return duplicate(&function.to_string(), end);
+                }
+
+                selections.push(Selection {
+                    function,
+                    field_names,
+                    span: item.span_to(end),
+                });
+
+                (_, input) = spaces(end)?;
+            } else {
+                break;
+            }
+        }
+
+        let (r#where, input) = option(input, Where::parse)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_close(input)?;
+
+        Ok((
+            Self {
+                name: name.into(),
+                arguments,
+                model_name: model_name.into(),
+                count,
+                selections,
+                group_by,
+                r#where,
+                span: start.span_to(input),
+                doc: doc.map(Into::into),
+            },
+            input,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::query::Filter,
+    };
+
+    #[test]
+    fn test_parse() {
+        let input = "
+
+/// Post statistics per status.
+aggregate postStats($minViews: Int): Post {
+  count
+  sum {
+    views
+    likes
+  }
+  max {
+    publishedAt
+  }
+  groupBy {
+    status
+  }
+  where {
+    post {
+      views {
+        gte: $minViews
+      }
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let (aggregate, rest) = Aggregate::parse(Input::new(input)).unwrap();
+
+        assert!(rest.is_empty());
+        assert_eq!(aggregate.name, "postStats");
+        assert_eq!(aggregate.doc, Some("Post statistics per status.".into()));
+        assert!(aggregate.arguments.contains_key("minViews"));
+        assert!(aggregate.count);
+
+        assert_eq!(
+            aggregate.selections,
+            vec![
+                Selection {
+                    function: Function::Sum,
+                    field_names: vec!["views".into(), "likes".into()],
+                    span: Span::new(86, 115),
+                },
+                Selection {
+                    function: Function::Max,
+                    field_names: vec!["publishedAt".into()],
+                    span: Span::new(118, 143),
+                },
+            ]
+        );
+
+        assert_eq!(aggregate.group_by, vec!["status"]);
+
+        assert!(matches!(
+            aggregate.r#where.unwrap().filters.as_slice(),
+            [Filter::Condition(_)]
+        ));
+    }
+
+    #[test]
+    fn test_parse_duplicate_function() {
+        let input = "
+
+aggregate postStats: Post {
+  sum {
+    views
+  }
+  sum {
+    likes
+  }
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Aggregate::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Duplicate `sum` in aggregate `postStats`.",
+                Span::new(52, 71),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_field_names() {
+        assert!(Aggregate::parse(Input::new(
+            "aggregate foo: Post {\n  sum {}\n}"
+        ))
+        .is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use {
+    parser::{
+        choice,
+        literal,
+        tag,
+        Input,
+        ParseResult,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// A function that aggregates the values of a field.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Function {
+    /// The average of the values.
+    Avg,
+    /// The highest value.
+    Max,
+    /// The lowest value.
+    Min,
+    /// The sum of the values.
+    Sum,
+}
+
+impl Display for Function {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Avg => write!(f, "avg"),
+            Self::Max => write!(f, "max"),
+            Self::Min => write!(f, "min"),
+            Self::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+impl Function {
+    /// Parse a function from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::aggregate::Function,
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("avg");
+    ///
+    /// assert_eq!(
+    ///     Function::parse(input),
+    ///     Ok((Function::Avg, input.advance(3)))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'_>) -> ParseResult<'_, Self> {
+        choice(
+            input,
+            &[
+                tag!(literal!("avg"), Self::Avg),
+                tag!(literal!("max"), Self::Max),
+                tag!(literal!("min"), Self::Min),
+                tag!(literal!("sum"), Self::Sum),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Function::Avg.to_string(), "avg");
+        assert_eq!(Function::Max.to_string(), "max");
+        assert_eq!(Function::Min.to_string(), "min");
+        assert_eq!(Function::Sum.to_string(), "sum");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Function::parse(Input::new("count")).is_err());
+    }
+}
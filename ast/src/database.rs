@@ -0,0 +1,306 @@
+use {
+    crate::Value,
+    ord_str_map::OrdStrMap,
+    parser::{
+        brace_close,
+        brace_open,
+        camel_case,
+        colon,
+        comma,
+        doc_comments,
+        literal,
+        spaces,
+        Input,
+        ParseError,
+        ParseResult,
+        Span,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// The value of a database setting.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Setting<'a> {
+    /// A list of values, such as `previewFeatures: [fullTextSearch, views]`.
+    List(Vec<Value<'a>>),
+    /// A single value, such as `provider: postgresql`.
+    Value(Value<'a>),
+}
+
+impl<'a> Setting<'a> {
+    /// Parse a list of values: `[a, b]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid list
+    /// of values.
+    fn parse_list(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (_, input) = literal(input, "[")?;
+        let (_, mut input) = spaces(input)?;
+        let mut values = Vec::new();
+
+        while let Ok((value, new_input)) = Value::parse(input) {
+            values.push(value);
+
+            let (_, new_input) = spaces(new_input)?;
+
+            input = new_input;
+
+            let Ok((_, new_input)) = comma(input) else {
+                break;
+            };
+
+            let (_, new_input) = spaces(new_input)?;
+
+            input = new_input;
+        }
+
+        let (_, input) = literal(input, "]")?;
+
+        Ok((Self::List(values), input))
+    }
+
+    /// Parse a setting value from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid value
+    /// or list of values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::{
+    ///         database::Setting,
+    ///         Value,
+    ///     },
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("[views]");
+    ///
+    /// assert_eq!(
+    ///     Setting::parse(input),
+    ///     Ok((
+    ///         Setting::List(vec![Value::Identifier("views".into())]),
+    ///         input.advance(7)
+    ///     ))
+    /// );
+    ///
+    /// let input = Input::new("\"DATABASE_URL\"");
+    ///
+    /// assert_eq!(
+    ///     Setting::parse(input),
+    ///     Ok((
+    ///         Setting::Value(Value::String("DATABASE_URL".into())),
+    ///         input.advance(14)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        if input.peek() == Some('[') {
+            return Self::parse_list(input);
+        }
+
+        let (value, input) = Value::parse(input)?;
+
+        Ok((Self::Value(value), input))
+    }
+}
+
+impl Display for Setting<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::List(values) => {
+                write!(
+                    f,
+                    "[{}]",
+                    values
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Self::Value(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The database of the application, and how the generated code connects to
+/// it.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Database<'a> {
+    /// The settings of the database, by name.
+    pub settings: OrdStrMap<Setting<'a>>,
+    /// The span of the declaration, from the `database` keyword to the
+    /// closing brace.
+    pub span: Span,
+}
+
+impl<'a> Database<'a> {
+    /// Parse a database declaration from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `ParseError` if the input does not start with a valid
+    ///   database declaration.
+    /// * Returns `ParseError::Custom` if a setting occurs more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::{
+    ///         database::Setting,
+    ///         Database,
+    ///         Value,
+    ///     },
+    ///     ord_str_map::OrdStrMap,
+    ///     parser::{
+    ///         Input,
+    ///         Span,
+    ///     },
+    /// };
+    ///
+    /// let input = "
+    ///
+    /// database {
+    ///   provider: postgresql
+    ///   url: \"DATABASE_URL\"
+    ///   previewFeatures: [views]
+    /// }
+    ///
+    /// "
+    /// .trim();
+    ///
+    /// let expected = Database {
+    ///     settings: OrdStrMap::from_iter([
+    ///         (
+    ///             "provider",
+    ///             Setting::Value(Value::Identifier("postgresql".into())),
+    ///         ),
+    ///         ("url", Setting::Value(Value::String("DATABASE_URL".into()))),
+    ///         (
+    ///             "previewFeatures",
+    ///             Setting::List(vec![Value::Identifier("views".into())]),
+    ///         ),
+    ///     ]),
+    ///     span: Span::new(0, 84),
+    /// };
+    ///
+    /// let input = Input::new(input);
+    ///
+    /// assert_eq!(Database::parse(input), Ok((expected, input.advance(84))));
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (_, start) = doc_comments(input)?;
+        let (_, input) = literal(start, "database")?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, mut input) = spaces(input)?;
+        let mut settings = OrdStrMap::new();
+
+        while let Ok((name, name_input)) = camel_case(input) {
+            let (_, new_input) = spaces(name_input)?;
+            let (_, new_input) = colon(new_input)?;
+            let (_, new_input) = spaces(new_input)?;
+            let (setting, new_input) = Setting::parse(new_input)?;
+
+            if settings.contains_key(name) {
+                return Err(ParseError::custom(
+                    format!("Duplicate database setting `{name}`."),
+                    input.span_to(name_input),
+                ));
+            }
+
+            let _: Option<Setting<'a>> = settings.insert(name, setting);
+            let (_, new_input) = spaces(new_input)?;
+
+            input = new_input;
+        }
+
+        let (_, input) = brace_close(input)?;
+
+        Ok((
+            Self {
+                settings,
+                span: start.span_to(input),
+            },
+            input,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duplicate_setting() {
+        let input = "
+
+database {
+  provider: postgresql
+  provider: mysql
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Database::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Duplicate database setting `provider`.",
+                Span::new(36, 44)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_list() {
+        let input = Input::new("[ ]");
+
+        assert_eq!(
+            Setting::parse(input),
+            Ok((Setting::List(Vec::new()), input.advance(3)))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Setting::List(vec![
+                Value::Identifier("views".into()),
+                Value::Identifier("metrics".into()),
+            ])
+            .to_string(),
+            "[views, metrics]"
+        );
+
+        assert_eq!(
+            Setting::Value(Value::String("DATABASE_URL".into())).to_string(),
+            "\"DATABASE_URL\""
+        );
+    }
+}
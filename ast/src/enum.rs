@@ -1,12 +1,16 @@
 use {
+    ord_str_map::OrdStrMap,
     parser::{
         brace_close,
         brace_open,
+        doc_comments,
         literal,
         pascal_case,
         spaces,
+        Input,
         ParseError,
         ParseResult,
+        Span,
     },
     std::borrow::Cow,
     token_set::TokenSet,
@@ -19,26 +23,15 @@ pub struct Enum<'a> {
     pub name: Cow<'a, str>,
     /// The values of the enum.
     pub values: TokenSet,
+    /// The span of the enum, from the `enum` keyword to the closing brace.
+    pub span: Span,
+    /// The documentation of the enum, taken from its doc comments.
+    pub doc: Option<Cow<'a, str>>,
+    /// The documentation of the values of the enum, by value.
+    pub value_docs: OrdStrMap<Cow<'a, str>>,
 }
 
 impl<'a> Enum<'a> {
-    /// Parse an enum value from the given input.
-    ///
-    /// # Arguments
-    ///
-    /// * `input` - The input to parse.
-    ///
-    /// # Errors
-    ///
-    /// Returns `ParseError` if the input does not start with a valid enum
-    /// value.
-    fn parse_value(input: &str) -> ParseResult<String> {
-        let (value, input) = pascal_case(input)?;
-        let (_, input) = spaces(&input)?;
-
-        Ok((value, input))
-    }
-
     /// Parse an enum from the given input.
     ///
     /// # Arguments
@@ -48,35 +41,55 @@ impl<'a> Enum<'a> {
     /// # Errors
     ///
     /// Returns `ParseError` if the input does not start with a valid enum.
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        let (_, input) = literal(input, "enum")?;
-        let (_, input) = spaces(&input)?;
-        let (name, input) = pascal_case(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_open(&input)?;
-        let (_, mut input) = spaces(&input)?;
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (doc, start) = doc_comments(input)?;
+        let (_, input) = literal(start, "enum")?;
+        let (_, input) = spaces(input)?;
+        let (name, input) = pascal_case(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, mut input) = brace_open(input)?;
         let mut values = TokenSet::new();
+        let mut value_docs = OrdStrMap::new();
+
+        loop {
+            let (value_doc, value_input) = doc_comments(input)?;
+
+            let Ok((value, new_input)) = pascal_case(value_input) else {
+                input = value_input;
+
+                break;
+            };
+
+            let span = value_input.span_to(value_input.advance(value.len()));
 
-        while let Ok((value, new_input)) = Self::parse_value(&input) {
             if !values.insert(value) {
-                return Err(ParseError::custom("Duplicate enum value."));
+                return Err(ParseError::custom("Duplicate enum value.", span));
+            }
+
+            if let Some(value_doc) = value_doc {
+                let _: Option<Cow<'a, str>> =
+                    value_docs.insert(value, value_doc.into());
             }
 
             input = new_input;
         }
 
         if values.is_empty() {
-            return Err(ParseError::custom(format!(
-                "Enum `{name}` has no values."
-            )));
+            return Err(ParseError::custom(
+                format!("Enum `{name}` has no values."),
+                start.span_to(input),
+            ));
         }
 
-        let (_, input) = brace_close(&input)?;
+        let (_, input) = brace_close(input)?;
 
         Ok((
             Self {
                 name: name.into(),
                 values,
+                span: start.span_to(input),
+                doc: doc.map(Into::into),
+                value_docs,
             },
             input,
         ))
@@ -102,9 +115,62 @@ mod tests {
         let expected = Enum {
             name: "Foo".into(),
             values: TokenSet::from_iter(["Bar", "Baz"]),
+            span: Span::new(0, 52),
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
-        assert_eq!(Enum::parse(input), Ok((expected, String::new())));
+        let input = Input::new(input);
+
+        assert_eq!(Enum::parse(input), Ok((expected, input.advance(52))));
+    }
+
+    #[test]
+    fn test_parse_doc_comments() {
+        let input = "
+
+/// The side of the road that traffic drives on.
+enum DrivingSide {
+    /// As in the United Kingdom.
+    Left
+    Right
+}
+
+        "
+        .trim();
+
+        let (r#enum, _) = Enum::parse(Input::new(input)).unwrap();
+
+        assert_eq!(
+            r#enum.doc,
+            Some("The side of the road that traffic drives on.".into())
+        );
+
+        assert_eq!(
+            r#enum.value_docs,
+            OrdStrMap::from_iter([(
+                "Left",
+                "As in the United Kingdom.".into()
+            )])
+        );
+    }
+
+    #[test]
+    fn test_parse_stray_doc_comments() {
+        let input = "
+
+enum DrivingSide {
+    Left /// Not documentation.
+    Right
+    /// Not documentation either.
+}
+
+        "
+        .trim();
+
+        let (r#enum, _) = Enum::parse(Input::new(input)).unwrap();
+
+        assert!(r#enum.value_docs.is_empty());
     }
 
     #[test]
@@ -120,8 +186,11 @@ mod tests {
         .trim();
 
         assert_eq!(
-            Enum::parse(input),
-            Err(ParseError::custom("Duplicate enum value."))
+            Enum::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Duplicate enum value.",
+                Span::new(39, 42)
+            ))
         );
     }
 
@@ -136,8 +205,11 @@ mod tests {
         .trim();
 
         assert_eq!(
-            Enum::parse(input),
-            Err(ParseError::custom("Enum `Foo` has no values."))
+            Enum::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Enum `Foo` has no values.",
+                Span::new(0, 19)
+            ))
         );
     }
 }
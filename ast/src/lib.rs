@@ -33,36 +33,57 @@
 )]
 
 pub use {
+    aggregate::Aggregate,
+    database::Database,
     model::Model,
+    mutation::Mutation,
     query::Query,
     r#enum::Enum,
     r#type::Type,
+    value::Value,
 };
 use {
     ord_str_map::OrdStrMap,
     parser::{
+        doc_comments,
+        literal,
         spaces,
+        Input,
         ParseError,
         ParseResult,
     },
 };
 
+/// Aggregate queries.
+pub mod aggregate;
+/// The database of the application.
+pub mod database;
 /// An enumerated type.
 pub mod r#enum;
 /// A data model.
 pub mod model;
+/// A data mutation.
+pub mod mutation;
 /// A data query.
 pub mod query;
 /// Types used inside models and queries.
 pub mod r#type;
+/// Literal values.
+pub mod value;
 
 /// The root of an AST.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Ast<'a> {
+    /// Aggregate declarations.
+    pub aggregates: OrdStrMap<Aggregate<'a>>,
+    /// The database declaration, if any.
+    pub database: Option<Database<'a>>,
     /// Enum declarations.
     pub enums: OrdStrMap<Enum<'a>>,
     /// Model declarations.
     pub models: OrdStrMap<Model<'a>>,
+    /// Mutation declarations.
+    pub mutations: OrdStrMap<Mutation<'a>>,
     /// Query declarations.
     pub queries: OrdStrMap<Query<'a>>,
 }
@@ -72,8 +93,11 @@ impl<'a> Ast<'a> {
     #[must_use]
     pub const fn new() -> Self {
         Self {
+            aggregates: OrdStrMap::new(),
+            database: None,
             enums: OrdStrMap::new(),
             models: OrdStrMap::new(),
+            mutations: OrdStrMap::new(),
             queries: OrdStrMap::new(),
         }
     }
@@ -100,6 +124,10 @@ impl<'a> Ast<'a> {
     ///         Type,
     ///     },
     ///     ord_str_map::OrdStrMap,
+    ///     parser::{
+    ///         Input,
+    ///         Span,
+    ///     },
     ///     token_set::TokenSet,
     /// };
     ///
@@ -189,6 +217,9 @@ impl<'a> Ast<'a> {
     ///                     ast::model::Field {
     ///                         name: "title".into(),
     ///                         r#type: Type::Scalar(ast::r#type::Scalar::String),
+    ///                         span: Span::new(16, 29),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///                 (
@@ -200,6 +231,9 @@ impl<'a> Ast<'a> {
     ///                                 "Country".into(),
     ///                             ),
     ///                         ),
+    ///                         span: Span::new(32, 48),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///                 (
@@ -211,6 +245,9 @@ impl<'a> Ast<'a> {
     ///                                 "Category".into(),
     ///                             ),
     ///                         ),
+    ///                         span: Span::new(51, 71),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///                 (
@@ -220,9 +257,15 @@ impl<'a> Ast<'a> {
     ///                         r#type: Type::Scalar(ast::r#type::Scalar::Owned(
     ///                             "Dimensions".into(),
     ///                         )),
+    ///                         span: Span::new(74, 97),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///             ]),
+    ///             span: Span::new(0, 99),
+    ///             doc: None,
+    ///             attributes: Vec::new(),
     ///         },
     ///     ),
     ///     (
@@ -235,6 +278,9 @@ impl<'a> Ast<'a> {
     ///                     ast::model::Field {
     ///                         name: "width".into(),
     ///                         r#type: Type::Scalar(ast::r#type::Scalar::Int),
+    ///                         span: Span::new(122, 132),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///                 (
@@ -242,9 +288,15 @@ impl<'a> Ast<'a> {
     ///                     ast::model::Field {
     ///                         name: "height".into(),
     ///                         r#type: Type::Scalar(ast::r#type::Scalar::Int),
+    ///                         span: Span::new(135, 146),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///             ]),
+    ///             span: Span::new(101, 148),
+    ///             doc: None,
+    ///             attributes: Vec::new(),
     ///         },
     ///     ),
     ///     (
@@ -257,6 +309,9 @@ impl<'a> Ast<'a> {
     ///                     ast::model::Field {
     ///                         name: "domain".into(),
     ///                         r#type: Type::Scalar(ast::r#type::Scalar::String),
+    ///                         span: Span::new(502, 516),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///                 (
@@ -268,6 +323,9 @@ impl<'a> Ast<'a> {
     ///                                 "DrivingSide".into(),
     ///                             ),
     ///                         ),
+    ///                         span: Span::new(519, 543),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///                 (
@@ -275,6 +333,9 @@ impl<'a> Ast<'a> {
     ///                     ast::model::Field {
     ///                         name: "flag".into(),
     ///                         r#type: Type::Scalar(ast::r#type::Scalar::String),
+    ///                         span: Span::new(546, 558),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///                 (
@@ -286,9 +347,15 @@ impl<'a> Ast<'a> {
     ///                                 "CountryName".into(),
     ///                             ),
     ///                         ),
+    ///                         span: Span::new(561, 578),
+    ///                         doc: None,
+    ///                         attributes: Vec::new(),
     ///                     },
     ///                 ),
     ///             ]),
+    ///             span: Span::new(484, 580),
+    ///             doc: None,
+    ///             attributes: Vec::new(),
     ///         },
     ///     ),
     /// ]);
@@ -299,6 +366,9 @@ impl<'a> Ast<'a> {
     ///         Enum {
     ///             name: "DrivingSide".into(),
     ///             values: TokenSet::from_iter(["Left", "Right"]),
+    ///             span: Span::new(447, 482),
+    ///             doc: None,
+    ///             value_docs: OrdStrMap::new(),
     ///         },
     ///     ),
     ///     (
@@ -309,6 +379,9 @@ impl<'a> Ast<'a> {
     ///                 "Albania", "Andorra", "Austria", "Yemen", "Zambia",
     ///                 "Zimbabwe",
     ///             ]),
+    ///             span: Span::new(582, 660),
+    ///             doc: None,
+    ///             value_docs: OrdStrMap::new(),
     ///         },
     ///     ),
     ///     (
@@ -323,6 +396,9 @@ impl<'a> Ast<'a> {
     ///                 "TrafficSign",
     ///                 "UtilityPole",
     ///             ]),
+    ///             span: Span::new(662, 757),
+    ///             doc: None,
+    ///             value_docs: OrdStrMap::new(),
     ///         },
     ///     ),
     /// ]);
@@ -352,6 +428,12 @@ impl<'a> Ast<'a> {
     ///             },
     ///             r#where: None,
     ///             arguments: OrdStrMap::new(),
+    ///             span: Span::new(150, 243),
+    ///             order_by: None,
+    ///             take: None,
+    ///             skip: None,
+    ///             cursor: None,
+    ///             doc: None,
     ///         },
     ///     ),
     ///     (
@@ -372,11 +454,19 @@ impl<'a> Ast<'a> {
     ///             },
     ///             r#where: Some(ast::query::Where {
     ///                 name: "image".into(),
-    ///                 conditions: vec![ast::query::Condition {
-    ///                     path: ast::query::Path::from_iter(["country", "name"]),
-    ///                     operator: ast::query::Operator::Equals,
-    ///                     argument_name: "name".into(),
-    ///                 }],
+    ///                 filters: vec![ast::query::Filter::Condition(
+    ///                     ast::query::Condition {
+    ///                         path: ast::query::Path::from_iter([
+    ///                             "country", "name",
+    ///                         ]),
+    ///                         operator: ast::query::Operator::Equals,
+    ///                         rhs: ast::query::r#where::Operand::Argument(
+    ///                             "name".into(),
+    ///                         ),
+    ///                         mode: ast::query::r#where::Mode::Default,
+    ///                         span: Span::new(402, 415),
+    ///                     },
+    ///                 )],
     ///             }),
     ///             arguments: OrdStrMap::from_iter([(
     ///                 "name",
@@ -387,17 +477,29 @@ impl<'a> Ast<'a> {
     ///                     )),
     ///                 },
     ///             )]),
+    ///             span: Span::new(245, 445),
+    ///             order_by: None,
+    ///             take: None,
+    ///             skip: None,
+    ///             cursor: None,
+    ///             doc: None,
     ///         },
     ///     ),
     /// ]);
     ///
-    /// assert_eq!(Ast::parse(&input), Ok((expected, String::new())));
+    /// assert_eq!(
+    ///     Ast::parse(input),
+    ///     Ok((expected, Input::new(input).advance(757)))
+    /// );
     /// ```
     ///
     /// ```rust
     /// use {
     ///     ast::Ast,
-    ///     parser::ParseError,
+    ///     parser::{
+    ///         ParseError,
+    ///         Span,
+    ///     },
     /// };
     ///
     /// let input = "
@@ -411,79 +513,352 @@ impl<'a> Ast<'a> {
     /// .trim();
     ///
     /// assert_eq!(
-    ///     Ast::parse(&input),
+    ///     Ast::parse(input),
     ///     Err(ParseError::Custom {
-    ///         message: "Expected an enum, model, or query.".into(),
+    ///         message: "Expected an aggregate, database, enum, model, mutation, \
+    ///                   or query."
+    ///             .into(),
+    ///         span: Span::at(0),
     ///     })
     /// );
     /// ```
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        let mut input = input.to_owned();
+    pub fn parse(input: &'a str) -> ParseResult<'a, Self> {
+        let mut input = Input::new(input);
         let mut ast = Self::new();
 
-        while !input.is_empty() {
-            let (_, new_input) = spaces(&input)?;
-
-            if let Ok((declaration, new_input)) = Model::parse(&new_input) {
-                if ast
-                    .models
-                    .insert(declaration.name.clone(), declaration.clone())
-                    .is_some()
-                {
-                    return Err(ParseError::Custom {
-                        message: format!(
-                            "Duplicate model name `{}`",
-                            declaration.name
-                        ),
-                    });
+        loop {
+            let (_, rest) = spaces(input)?;
+
+            if rest.is_empty() {
+                return Ok((ast, rest));
+            }
+
+            input = ast.parse_declaration(input)?;
+        }
+    }
+
+    /// Parse a Dragonfly program, recovering from errors.
+    ///
+    /// When a declaration can not be parsed, the error is recorded and parsing
+    /// resumes at the next line outside of the braces of the declaration that
+    /// starts with an `aggregate`, `database`, `enum`, `model`, `mutation` or
+    /// `query` keyword, or at the doc comments right above it. The
+    /// declarations that could be parsed are returned together with all
+    /// errors, in source order.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::Ast,
+    ///     parser::{
+    ///         ParseError,
+    ///         Span,
+    ///     },
+    /// };
+    ///
+    /// let input = "
+    ///
+    /// model Image {
+    ///   title String
+    /// }
+    ///
+    /// enum Kind {
+    ///   Photo
+    /// }
+    ///
+    /// modle Country {
+    ///   name: String
+    /// }
+    ///
+    /// "
+    /// .trim();
+    ///
+    /// let (ast, errors) = Ast::parse_with_recovery(input);
+    ///
+    /// assert!(ast.enums.contains_key("Kind"));
+    /// assert!(ast.models.is_empty());
+    ///
+    /// assert_eq!(
+    ///     errors,
+    ///     vec![
+    ///         ParseError::UnexpectedChar {
+    ///             actual: ' ',
+    ///             message: "Expected character ':', found ' '.".to_owned(),
+    ///             span: Span::new(21, 22),
+    ///         },
+    ///         ParseError::Custom {
+    ///             message: "Expected an aggregate, database, enum, model, \
+    ///                       mutation, or query."
+    ///                 .to_owned(),
+    ///             span: Span::at(55),
+    ///         },
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn parse_with_recovery(input: &'a str) -> (Self, Vec<ParseError>) {
+        let mut ast = Self::new();
+        let mut errors = Vec::new();
+        let mut input = Input::new(input);
+
+        loop {
+            match spaces(input) {
+                Ok((_, rest)) if rest.is_empty() => break,
+                Ok(_) => {}
+                Err(error) => {
+                    errors.push(error);
+
+                    break;
                 }
+            }
 
-                input = new_input;
-            } else if let Ok((declaration, new_input)) =
-                Query::parse(&new_input)
-            {
-                if ast
-                    .queries
-                    .insert(declaration.name.clone(), declaration.clone())
-                    .is_some()
-                {
-                    return Err(ParseError::custom(format!(
-                        "Duplicate query name `{}`",
-                        declaration.name
-                    )));
+            match ast.parse_declaration(input) {
+                Ok(new_input) => input = new_input,
+                Err(error) => {
+                    errors.push(error);
+                    input = Self::skip_declaration(input);
                 }
+            }
+        }
+
+        (ast, errors)
+    }
+
+    /// Parse a single aggregate, database, enum, model, mutation or query
+    /// declaration and add it to the AST.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the error of the declaration parser that matches the keyword
+    ///   of the declaration, if it could not be parsed.
+    /// * Returns `ParseError::Custom` if the input does not start with a
+    ///   declaration keyword.
+    /// * Returns `ParseError::Custom` if a declaration with the same name
+    ///   already exists.
+    fn parse_declaration(
+        &mut self,
+        input: Input<'a>,
+    ) -> Result<Input<'a>, ParseError> {
+        let (_, keyword) = doc_comments(input)?;
+
+        if literal(keyword, "model").is_ok() {
+            let (declaration, input) = Model::parse(input)?;
+
+            if self.models.contains_key(&declaration.name) {
+                return Err(ParseError::custom(
+                    format!("Duplicate model name `{}`", declaration.name),
+                    declaration.span,
+                ));
+            }
+
+            let _: Option<Model> =
+                self.models.insert(declaration.name.clone(), declaration);
+
+            Ok(input)
+        } else if literal(keyword, "query").is_ok() {
+            let (declaration, input) = Query::parse(input)?;
+
+            if self.queries.contains_key(&declaration.name) {
+                return Err(ParseError::custom(
+                    format!("Duplicate query name `{}`", declaration.name),
+                    declaration.span,
+                ));
+            }
+
+            let _: Option<Query> =
+                self.queries.insert(declaration.name.clone(), declaration);
+
+            Ok(input)
+        } else if literal(keyword, "mutation").is_ok() {
+            let (declaration, input) = Mutation::parse(input)?;
+
+            if self.mutations.contains_key(&declaration.name) {
+                return Err(ParseError::custom(
+                    format!("Duplicate mutation name `{}`", declaration.name),
+                    declaration.span,
+                ));
+            }
+
+            let _: Option<Mutation> =
+                self.mutations.insert(declaration.name.clone(), declaration);
+
+            Ok(input)
+        } else if literal(keyword, "aggregate").is_ok() {
+            let (declaration, input) = Aggregate::parse(input)?;
+
+            if self.aggregates.contains_key(&declaration.name) {
+                return Err(ParseError::custom(
+                    format!("Duplicate aggregate name `{}`", declaration.name),
+                    declaration.span,
+                ));
+            }
+
+            let _: Option<Aggregate> = self
+                .aggregates
+                .insert(declaration.name.clone(), declaration);
+
+            Ok(input)
+        } else if literal(keyword, "database").is_ok() {
+            let (declaration, input) = Database::parse(input)?;
+
+            if self.database.is_some() {
+                return Err(ParseError::custom(
+                    "Duplicate database declaration",
+                    declaration.span,
+                ));
+            }
 
-                input = new_input;
-            } else if let Ok((declaration, new_input)) = Enum::parse(&new_input)
-            {
-                if ast
-                    .enums
-                    .insert(declaration.name.clone(), declaration.clone())
-                    .is_some()
-                {
-                    return Err(ParseError::Custom {
-                        message: format!(
-                            "Duplicate enum name `{}`",
-                            declaration.name
-                        ),
-                    });
+            self.database = Some(declaration);
+
+            Ok(input)
+        } else if literal(keyword, "enum").is_ok() {
+            let (declaration, input) = Enum::parse(input)?;
+
+            if self.enums.contains_key(&declaration.name) {
+                return Err(ParseError::custom(
+                    format!("Duplicate enum name `{}`", declaration.name),
+                    declaration.span,
+                ));
+            }
+
+            let _: Option<Enum> =
+                self.enums.insert(declaration.name.clone(), declaration);
+
+            Ok(input)
+        } else {
+            Err(ParseError::custom(
+                "Expected an aggregate, database, enum, model, mutation, or \
+                 query.",
+                keyword.span(),
+            ))
+        }
+    }
+
+    /// Skip the declaration that starts at the given input, returning the
+    /// input at the start of the next line that begins with a declaration
+    /// keyword and is not inside braces opened by the skipped declaration.
+    /// Doc comments right above that line are not skipped.
+    ///
+    /// If the braces of the declaration are never closed, the input at the
+    /// first line that begins with a declaration keyword is returned instead.
+    /// Returns the end of the input if there is no next declaration.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The start of the declaration to skip.
+    fn skip_declaration(input: Input<'a>) -> Input<'a> {
+        let input = doc_comments(input).map_or(input, |(_, input)| input);
+        let rest = input.rest();
+        let mut offset = rest.find('\n').map_or(rest.len(), |index| index + 1);
+        let (mut depth, mut in_block_comment) =
+            Self::brace_depth(0, false, &rest[..offset]);
+
+        let mut doc_offset = None;
+        let mut unbalanced_offset = None;
+
+        while offset < rest.len() {
+            let line = &rest[offset..];
+            let line_length =
+                line.find('\n').map_or(line.len(), |index| index + 1);
+            let trimmed = line.trim_start_matches([' ', '\t']);
+
+            let starts_declaration = [
+                "aggregate",
+                "database",
+                "model",
+                "mutation",
+                "enum",
+                "query",
+            ]
+            .iter()
+            .any(|keyword| {
+                trimmed.strip_prefix(keyword).is_some_and(|rest| {
+                    rest.starts_with(|char: char| char.is_ascii_whitespace())
+                })
+            });
+
+            if starts_declaration && !in_block_comment {
+                let start = doc_offset.unwrap_or(offset);
+
+                if depth == 0 {
+                    return input.advance(start);
                 }
 
-                input = new_input;
-            } else {
-                return Err(ParseError::Custom {
-                    message: "Expected an enum, model, or query.".to_owned(),
-                });
+                let _: &mut usize = unbalanced_offset.get_or_insert(start);
             }
 
-            let (_, new_input) = spaces(&input)?;
+            if !in_block_comment && trimmed.starts_with("///") {
+                let _: &mut usize = doc_offset.get_or_insert(offset);
+            } else if in_block_comment || !trimmed.trim().is_empty() {
+                doc_offset = None;
+            }
 
-            input = new_input;
+            (depth, in_block_comment) = Self::brace_depth(
+                depth,
+                in_block_comment,
+                &line[..line_length],
+            );
+            offset += line_length;
         }
 
-        let (_, input) = spaces(&input)?;
+        input.advance(unbalanced_offset.unwrap_or(rest.len()))
+    }
+
+    /// Update a brace depth with the braces on a line, and whether the line
+    /// ends inside a block comment. Braces in strings, line comments and
+    /// block comments are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The depth at the start of the line.
+    /// * `in_block_comment` - Whether the line starts inside a block comment.
+    /// * `line` - The line.
+    fn brace_depth(
+        mut depth: usize,
+        mut in_block_comment: bool,
+        line: &str,
+    ) -> (usize, bool) {
+        let mut in_string = false;
+        let mut chars = line.chars();
+
+        while let Some(char) = chars.next() {
+            if in_block_comment {
+                if char == '*' && chars.as_str().starts_with('/') {
+                    let _: Option<char> = chars.next();
+
+                    in_block_comment = false;
+                }
+
+                continue;
+            }
+
+            match char {
+                '\\' if in_string => {
+                    let _: Option<char> = chars.next();
+                }
+                '"' => in_string = !in_string,
+                '/' if !in_string && chars.as_str().starts_with('/') => break,
+                '/' if !in_string && chars.as_str().starts_with('*') => {
+                    let _: Option<char> = chars.next();
 
-        Ok((ast, input))
+                    in_block_comment = true;
+                }
+                '{' if !in_string => depth += 1,
+                '}' if !in_string => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        (depth, in_block_comment)
     }
 }
 
@@ -495,7 +870,10 @@ impl Default for Ast<'_> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        parser::Span,
+    };
 
     #[test]
     fn test_parse_duplicate_model_name() {
@@ -516,6 +894,7 @@ model Image {
             Ast::parse(input),
             Err(ParseError::Custom {
                 message: "Duplicate model name `Image`".into(),
+                span: Span::new(33, 64),
             })
         );
     }
@@ -543,6 +922,7 @@ query images: [Image] {
             Ast::parse(input),
             Err(ParseError::Custom {
                 message: "Duplicate query name `images`".into(),
+                span: Span::new(51, 100),
             })
         );
     }
@@ -568,15 +948,404 @@ enum DrivingSide {
             Ast::parse(input),
             Err(ParseError::Custom {
                 message: "Duplicate enum name `DrivingSide`".into(),
+                span: Span::new(37, 72),
             })
         );
     }
 
+    #[test]
+    fn test_parse_duplicate_database() {
+        let input = "
+
+database {
+  provider: postgresql
+}
+
+database {
+  provider: mysql
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Ast::parse(input),
+            Err(ParseError::custom(
+                "Duplicate database declaration",
+                Span::new(37, 67)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate_aggregate() {
+        let input = "
+
+aggregate stats: Post {
+  count
+}
+
+aggregate stats: Image {
+  count
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Ast::parse(input),
+            Err(ParseError::custom(
+                "Duplicate aggregate name `stats`",
+                Span::new(35, 69)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate_mutation() {
+        let input = "
+
+mutation deleteAll: Post {
+  delete
+}
+
+mutation deleteAll: Image {
+  delete
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Ast::parse(input),
+            Err(ParseError::custom(
+                "Duplicate mutation name `deleteAll`",
+                Span::new(39, 77)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_database() {
+        let input = "
+
+enum Kind {
+  Photo
+}
+
+database {
+  provider: sqlite
+  relationMode: foreignKeys
+}
+
+        "
+        .trim();
+
+        let (ast, _) = Ast::parse(input).unwrap();
+        let database = ast.database.unwrap();
+
+        assert_eq!(
+            database.settings.get("relationMode"),
+            Some(&database::Setting::Value(Value::Identifier(
+                "foreignKeys".into()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_parse_comments() {
+        let input = "
+
+// The colours of a car.
+enum Colour {
+  Red /* The default. */
+  Blue
+}
+
+/*
+ * A car.
+ */
+model Car {
+  // Required by law.
+  licensePlate: String
+  colour: Colour // Repainting is rare.
+}
+
+query cars($colour: Colour): [Car] {
+  car {
+    licensePlate // Only the plate.
+  }
+  where {
+    // Filter on colour.
+    car {
+      colour {
+        equals: $colour
+      }
+    }
+  }
+}
+
+// The end.
+
+        "
+        .trim();
+
+        let (ast, input) = Ast::parse(input).unwrap();
+
+        assert!(input.is_empty());
+        assert!(ast.enums.contains_key("Colour"));
+        assert_eq!(ast.models.get("Car").unwrap().fields.len(), 2);
+        assert!(ast.queries.get("cars").unwrap().r#where.is_some());
+    }
+
+    #[test]
+    fn test_parse_stray_doc_comments() {
+        let input = "
+
+model Car {
+  licensePlate: String /// The plate.
+  wheels: Int
+  /// Dangling.
+}
+
+query cars: [Car] {
+  car {
+    /// Not a declaration.
+    licensePlate
+  }
+  where {
+    /// Not a declaration either.
+    car {
+      wheels {
+        /// Nor this.
+        equals: 4
+      }
+    }
+  }
+}
+
+/// The end.
+
+        "
+        .trim();
+
+        let (ast, input) = Ast::parse(input).unwrap();
+        let car = ast.models.get("Car").unwrap();
+
+        assert!(input.is_empty());
+        assert_eq!(car.fields.get("wheels").unwrap().doc, None);
+        assert!(ast.queries.get("cars").unwrap().r#where.is_some());
+    }
+
+    #[test]
+    fn test_parse_unterminated_block_comment() {
+        assert_eq!(
+            Ast::parse("/* model Car {\n  licensePlate: String\n}"),
+            Err(ParseError::Custom {
+                message: "Unterminated block comment.".into(),
+                span: Span::new(0, 39),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_only_comments() {
+        let input = "// Nothing here yet.";
+
+        assert_eq!(
+            Ast::parse(input),
+            Ok((Ast::new(), Input::new(input).advance(input.len())))
+        );
+    }
+
+    #[test]
+    fn test_parse_with_recovery() {
+        let input = "
+
+model Image {
+  title: String
+  country Country
+}
+
+/// A country.
+model Country {
+  name: String
+}
+
+query images: [Image] {
+  image {
+    title
+  }
+  where
+}
+
+enum Kind {
+  Photo
+}
+
+enum Kind {
+  Drawing
+}
+
+        "
+        .trim();
+
+        let (ast, errors) = Ast::parse_with_recovery(input);
+
+        assert_eq!(
+            ast.models.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            vec!["Country"]
+        );
+
+        assert_eq!(
+            ast.models.get("Country").unwrap().doc,
+            Some("A country.".into())
+        );
+
+        assert!(ast.queries.is_empty());
+        assert_eq!(ast.enums.len(), 1);
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::unexpected_char(
+                    ' ',
+                    "Expected character ':', found ' '.",
+                    Span::new(39, 40)
+                ),
+                ParseError::unexpected_char(
+                    '}',
+                    "Expected character '{', found '}'.",
+                    Span::new(156, 157)
+                ),
+                ParseError::custom(
+                    "Duplicate enum name `Kind`",
+                    Span::new(182, 205)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_recovery_nested_keyword() {
+        let input = "
+
+model Image {
+  title: String
+  country Country
+  model Camera
+}
+
+enum Kind {
+  Photo
+}
+
+        "
+        .trim();
+
+        let (ast, errors) = Ast::parse_with_recovery(input);
+
+        assert!(ast.models.is_empty());
+        assert!(ast.enums.contains_key("Kind"));
+
+        assert_eq!(
+            errors,
+            vec![ParseError::unexpected_char(
+                ' ',
+                "Expected character ':', found ' '.",
+                Span::new(39, 40)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_recovery_block_comment() {
+        let input = "
+
+model Image {
+  title: String
+  country Country
+  /* An unbalanced { brace.
+  model Camera */
+}
+
+enum Kind {
+  Photo
+}
+
+        "
+        .trim();
+
+        let (ast, errors) = Ast::parse_with_recovery(input);
+
+        assert!(ast.models.is_empty());
+        assert!(ast.enums.contains_key("Kind"));
+
+        assert_eq!(
+            errors,
+            vec![ParseError::unexpected_char(
+                ' ',
+                "Expected character ':', found ' '.",
+                Span::new(39, 40)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_recovery_unclosed_braces() {
+        let input = "
+
+model Image {
+  title: String
+  country Country
+
+enum Kind {
+  Photo
+}
+
+        "
+        .trim();
+
+        let (ast, errors) = Ast::parse_with_recovery(input);
+
+        assert!(ast.models.is_empty());
+        assert!(ast.enums.contains_key("Kind"));
+
+        assert_eq!(
+            errors,
+            vec![ParseError::unexpected_char(
+                ' ',
+                "Expected character ':', found ' '.",
+                Span::new(39, 40)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_recovery_unterminated_block_comment() {
+        let input = "enum Kind {\n  Photo\n}\n\n/* enum Size {\n  Large\n}";
+        let (ast, errors) = Ast::parse_with_recovery(input);
+
+        assert_eq!(ast.enums.len(), 1);
+
+        assert_eq!(
+            errors,
+            vec![ParseError::custom(
+                "Unterminated block comment.",
+                Span::new(23, 47)
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_with_recovery_empty_input() {
+        assert_eq!(Ast::parse_with_recovery(""), (Ast::new(), Vec::new()));
+    }
+
     #[test]
     fn test_parse_empty_input() {
         let input = "";
 
-        assert_eq!(Ast::parse(input), Ok((Ast::new(), String::new())));
+        assert_eq!(Ast::parse(input), Ok((Ast::new(), Input::new(input))));
     }
 
     #[test]
@@ -4,15 +4,21 @@ use {
     parser::{
         brace_close,
         brace_open,
+        camel_case,
         capitalized,
+        doc_comments,
         literal,
         spaces,
+        Input,
         ParseError,
         ParseResult,
+        Span,
     },
     std::borrow::Cow,
 };
 
+/// Field and block attributes.
+pub mod attribute;
 /// A field belonging to a model.
 pub mod field;
 
@@ -24,6 +30,12 @@ pub struct Model<'a> {
     pub name: Cow<'a, str>,
     /// The fields of the model.
     pub fields: OrdStrMap<Field<'a>>,
+    /// The span of the model, from the `model` keyword to the closing brace.
+    pub span: Span,
+    /// The documentation of the model, taken from its doc comments.
+    pub doc: Option<Cow<'a, str>>,
+    /// The block attributes of the model, such as indexes.
+    pub attributes: Vec<attribute::Block<'a>>,
 }
 
 impl<'a> Model<'a> {
@@ -38,41 +50,66 @@ impl<'a> Model<'a> {
     /// * Returns a `ParseError` if the input does not contain a valid model.
     /// * Returns a `ParseError` if the model contains duplicate field names.
     /// * Returns a `ParseError` if the model does not contain any fields.
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        let (_, input) = literal(input, "model")?;
-        let (_, input) = spaces(&input)?;
-        let (name, input) = capitalized(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_open(&input)?;
-        let (_, mut input) = spaces(&input)?;
+    /// * Returns a `ParseError` if a field name is not followed by a valid type
+    ///   and attributes.
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (doc, start) = doc_comments(input)?;
+        let (_, input) = literal(start, "model")?;
+        let (_, input) = spaces(input)?;
+        let (name, input) = capitalized(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, mut input) = brace_open(input)?;
         let mut fields = OrdStrMap::new();
+        let mut attributes = Vec::new();
 
-        while let Ok((field, new_input)) = Field::parse(&input) {
-            let (_, new_input) = spaces(&new_input)?;
+        loop {
+            let (_, item_input) = spaces(input)?;
 
-            if fields.insert(field.name.clone(), field.clone()).is_some() {
-                return Err(ParseError::custom(format!(
-                    "Duplicate field name `{}` in model `{name}`.",
-                    field.name
-                )));
-            }
+            if camel_case(item_input).is_ok() {
+                let (field, new_input) = Field::parse(input)?;
+
+                if fields.contains_key(&field.name) {
+                    return Err(ParseError::custom(
+                        format!(
+                            "Duplicate field name `{}` in model `{name}`.",
+                            field.name
+                        ),
+                        field.span,
+                    ));
+                }
+
+                let _: Option<Field> = fields.insert(field.name.clone(), field);
+
+                input = new_input;
+            } else if literal(item_input, "@@").is_ok() {
+                let (attribute, new_input) =
+                    attribute::Block::parse(item_input)?;
+
+                attributes.push(attribute);
+                input = new_input;
+            } else {
+                input = item_input;
 
-            input = new_input;
+                break;
+            }
         }
 
         if fields.is_empty() {
-            return Err(ParseError::custom(format!(
-                "Expected at least one field in model `{name}`."
-            )));
+            return Err(ParseError::custom(
+                format!("Expected at least one field in model `{name}`."),
+                start.span_to(input),
+            ));
         }
 
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_close(&input)?;
+        let (_, input) = brace_close(input)?;
 
         Ok((
             Self {
                 name: name.into(),
                 fields,
+                span: start.span_to(input),
+                doc: doc.map(Into::into),
+                attributes,
             },
             input,
         ))
@@ -112,6 +149,9 @@ model Foo {
                     Field {
                         name: "bar".into(),
                         r#type: Type::Scalar(Scalar::String),
+                        span: Span::new(16, 27),
+                        doc: None,
+                        attributes: Vec::new(),
                     },
                 ),
                 (
@@ -119,6 +159,9 @@ model Foo {
                     Field {
                         name: "baz".into(),
                         r#type: Type::Scalar(Scalar::Int),
+                        span: Span::new(32, 40),
+                        doc: None,
+                        attributes: Vec::new(),
                     },
                 ),
                 (
@@ -126,6 +169,9 @@ model Foo {
                     Field {
                         name: "qux".into(),
                         r#type: Type::Array(Scalar::Reference("Bar".into())),
+                        span: Span::new(45, 55),
+                        doc: None,
+                        attributes: Vec::new(),
                     },
                 ),
                 (
@@ -133,6 +179,9 @@ model Foo {
                     Field {
                         name: "quy".into(),
                         r#type: Type::Scalar(Scalar::Owned("Bar".into())),
+                        span: Span::new(60, 69),
+                        doc: None,
+                        attributes: Vec::new(),
                     },
                 ),
                 (
@@ -140,12 +189,72 @@ model Foo {
                     Field {
                         name: "quz".into(),
                         r#type: Type::Array(Scalar::Owned("Bar".into())),
+                        span: Span::new(74, 85),
+                        doc: None,
+                        attributes: Vec::new(),
                     },
                 ),
             ]),
+            span: Span::new(0, 87),
+            doc: None,
+            attributes: Vec::new(),
         };
 
-        assert_eq!(Model::parse(input), Ok((expected, String::new())));
+        let input = Input::new(input);
+
+        assert_eq!(Model::parse(input), Ok((expected, input.advance(87))));
+    }
+
+    #[test]
+    fn test_parse_doc_comments() {
+        let input = "
+
+/// A vehicle.
+///
+/// Registered with the authorities.
+model Car {
+    /// The license plate.
+    licensePlate: String
+    //// Not documentation.
+    wheels: Int
+}
+
+        "
+        .trim();
+
+        let (model, _) = Model::parse(Input::new(input)).unwrap();
+
+        assert_eq!(
+            model.doc,
+            Some("A vehicle.\n\nRegistered with the authorities.".into())
+        );
+
+        assert_eq!(
+            model.fields.get("licensePlate").unwrap().doc,
+            Some("The license plate.".into())
+        );
+
+        assert_eq!(model.fields.get("wheels").unwrap().doc, None);
+        assert_eq!(model.span, Span::new(56, 165));
+    }
+
+    #[test]
+    fn test_parse_stray_doc_comments() {
+        let input = "
+
+model Car {
+    licensePlate: String /// Not documentation.
+    wheels: Int
+    /// Not documentation either.
+}
+
+        "
+        .trim();
+
+        let (model, _) = Model::parse(Input::new(input)).unwrap();
+
+        assert_eq!(model.fields.get("licensePlate").unwrap().doc, None);
+        assert_eq!(model.fields.get("wheels").unwrap().doc, None);
     }
 
     #[test]
@@ -161,9 +270,51 @@ model Foo {
         .trim();
 
         assert_eq!(
-            Model::parse(input),
+            Model::parse(Input::new(input)),
             Err(ParseError::custom(
-                "Duplicate field name `bar` in model `Foo`."
+                "Duplicate field name `bar` in model `Foo`.",
+                Span::new(32, 40),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_field_attribute() {
+        let input = "
+
+model User {
+    name: String
+    email: String @uniq
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Model::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Unknown field attribute `@uniq`.",
+                Span::new(48, 53),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate_field_attribute() {
+        let input = "
+
+model User {
+    email: String @unique @unique
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Model::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Duplicate attribute `@unique` on field `email`.",
+                Span::new(39, 46),
             ))
         );
     }
@@ -179,10 +330,41 @@ model Foo {
         .trim();
 
         assert_eq!(
-            Model::parse(input),
+            Model::parse(Input::new(input)),
             Err(ParseError::custom(
-                "Expected at least one field in model `Foo`."
+                "Expected at least one field in model `Foo`.",
+                Span::new(0, 12),
             ))
         );
     }
+
+    #[test]
+    fn test_parse_block_attributes() {
+        let input = "
+
+model Post {
+    title: String
+    @@index([title, createdAt])
+    createdAt: DateTime
+    @@unique([title])
+}
+
+        "
+        .trim();
+
+        let (model, _) = Model::parse(Input::new(input)).unwrap();
+
+        assert_eq!(model.fields.len(), 2);
+
+        assert_eq!(
+            model.attributes,
+            vec![
+                attribute::Block::Index(vec![
+                    "title".into(),
+                    "createdAt".into()
+                ]),
+                attribute::Block::Unique(vec!["title".into()]),
+            ]
+        );
+    }
 }
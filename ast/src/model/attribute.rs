@@ -0,0 +1,761 @@
+use {
+    crate::Value,
+    parser::{
+        alphabetics,
+        at,
+        camel_case,
+        chars_if,
+        colon,
+        comma,
+        literal,
+        paren_close,
+        paren_open,
+        pascal_case,
+        spaces,
+        Input,
+        ParseError,
+        ParseResult,
+    },
+    std::{
+        borrow::Cow,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+    },
+};
+
+/// A field attribute, written after the type of a field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Field<'a> {
+    /// `@db.Name(arguments)`: the native database type of the field.
+    Db(NativeType<'a>),
+    /// `@default(value)`: the value of the field if none is given.
+    Default(Value<'a>),
+    /// `@id`: the field identifies the record.
+    Id,
+    /// `@inverse(name)`: the name of the field on the related model that
+    /// refers back to this relation.
+    Inverse(Cow<'a, str>),
+    /// `@onDelete(action)`: what happens to the records of a relation when
+    /// the record they refer to is deleted.
+    OnDelete(Cow<'a, str>),
+    /// `@onUpdate(action)`: what happens to the records of a relation when
+    /// the id of the record they refer to is updated.
+    OnUpdate(Cow<'a, str>),
+    /// `@unique`: no two records can have the same value for the field.
+    Unique,
+}
+
+impl Display for Field<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Db(native_type) => write!(f, "@db.{native_type}"),
+            Self::Default(value) => write!(f, "@default({value})"),
+            Self::Id => write!(f, "@id"),
+            Self::Inverse(name) => write!(f, "@inverse({name})"),
+            Self::OnDelete(action) => write!(f, "@onDelete({action})"),
+            Self::OnUpdate(action) => write!(f, "@onUpdate({action})"),
+            Self::Unique => write!(f, "@unique"),
+        }
+    }
+}
+
+impl<'a> Field<'a> {
+    /// The name of the attribute, without the leading `@`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Db(_) => "db",
+            Self::Default(_) => "default",
+            Self::Id => "id",
+            Self::Inverse(_) => "inverse",
+            Self::OnDelete(_) => "onDelete",
+            Self::OnUpdate(_) => "onUpdate",
+            Self::Unique => "unique",
+        }
+    }
+
+    /// Parse a field attribute from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `ParseError` if the input does not start with a valid field
+    ///   attribute.
+    /// * Returns `ParseError::Custom` if the attribute is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::{
+    ///         model::attribute::{
+    ///             Field,
+    ///             NativeType,
+    ///         },
+    ///         Value,
+    ///     },
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("@default( 0 )");
+    ///
+    /// assert_eq!(
+    ///     Field::parse(input),
+    ///     Ok((Field::Default(Value::Int("0".into())), input.advance(13)))
+    /// );
+    ///
+    /// let input = Input::new("@unique");
+    ///
+    /// assert_eq!(Field::parse(input), Ok((Field::Unique, input.advance(7))));
+    ///
+    /// let input = Input::new("@db.VarChar(255)");
+    ///
+    /// assert_eq!(
+    ///     Field::parse(input),
+    ///     Ok((
+    ///         Field::Db(NativeType {
+    ///             name: "VarChar".into(),
+    ///             arguments: vec!["255".into()],
+    ///         }),
+    ///         input.advance(16)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (_, name_input) = at(input)?;
+        let (name, end) = alphabetics(name_input)?;
+
+        match name {
+            "id" => Ok((Self::Id, end)),
+            "unique" => Ok((Self::Unique, end)),
+            "inverse" => {
+                let (_, end) = paren_open(end)?;
+                let (_, end) = spaces(end)?;
+                let (name, end) = camel_case(end)?;
+                let (_, end) = spaces(end)?;
+                let (_, end) = paren_close(end)?;
+
+                Ok((Self::Inverse(name.into()), end))
+            }
+            "onDelete" | "onUpdate" => {
+                let (_, end) = paren_open(end)?;
+                let (_, end) = spaces(end)?;
+                let (action, end) = pascal_case(end)?;
+                let (_, end) = spaces(end)?;
+                let (_, end) = paren_close(end)?;
+
+                if name == "onDelete" {
+                    Ok((Self::OnDelete(action.into()), end))
+                } else {
+                    Ok((Self::OnUpdate(action.into()), end))
+                }
+            }
+            "db" => {
+                let (_, end) = literal(end, ".")?;
+                let (native_type, end) = NativeType::parse(end)?;
+
+                Ok((Self::Db(native_type), end))
+            }
+            "default" => {
+                let (_, end) = paren_open(end)?;
+                let (_, end) = spaces(end)?;
+                let (value, end) = Value::parse(end)?;
+                let (_, end) = spaces(end)?;
+                let (_, end) = paren_close(end)?;
+
+                Ok((Self::Default(value), end))
+            }
+            _ => {
+                Err(ParseError::custom(
+                    format!("Unknown field attribute `@{name}`."),
+                    input.span_to(end),
+                ))
+            }
+        }
+    }
+}
+
+/// A native database type in a `@db` attribute: `Name` or
+/// `Name(argument, ...)`, such as `Text` or `Decimal(10, 2)`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NativeType<'a> {
+    /// The name of the native type.
+    pub name: Cow<'a, str>,
+    /// The arguments of the native type, such as a length or precision.
+    pub arguments: Vec<Cow<'a, str>>,
+}
+
+impl<'a> NativeType<'a> {
+    /// Parse an alphanumeric word: the name of a native type or one of its
+    /// arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with an
+    /// alphanumeric character.
+    fn parse_word(input: Input<'a>) -> ParseResult<'a, &'a str> {
+        chars_if(
+            input,
+            |char| char.is_ascii_alphanumeric(),
+            "Expected alphanumeric character.",
+        )
+    }
+
+    /// Parse a native type from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid
+    /// native type.
+    fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (name, input) = Self::parse_word(input)?;
+        let mut arguments = Vec::new();
+
+        let Ok((_, mut argument_input)) = paren_open(input) else {
+            return Ok((
+                Self {
+                    name: name.into(),
+                    arguments,
+                },
+                input,
+            ));
+        };
+
+        loop {
+            let (_, new_input) = spaces(argument_input)?;
+            let (argument, new_input) = Self::parse_word(new_input)?;
+            let (_, new_input) = spaces(new_input)?;
+
+            arguments.push(argument.into());
+            argument_input = new_input;
+
+            let Ok((_, new_input)) = comma(argument_input) else {
+                break;
+            };
+
+            argument_input = new_input;
+        }
+
+        let (_, input) = paren_close(argument_input)?;
+
+        Ok((
+            Self {
+                name: name.into(),
+                arguments,
+            },
+            input,
+        ))
+    }
+}
+
+impl Display for NativeType<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let Self { name, arguments } = self;
+
+        if arguments.is_empty() {
+            write!(f, "{name}")
+        } else {
+            write!(f, "{name}({})", arguments.join(", "))
+        }
+    }
+}
+
+/// A timestamp in a `@@timestamps` attribute: `kind` or `kind: name`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamp<'a> {
+    /// The kind of timestamp, such as `createdAt`, `updatedAt` or
+    /// `deletedAt`.
+    pub kind: Cow<'a, str>,
+    /// The name of the field, if it differs from the kind.
+    pub name: Option<Cow<'a, str>>,
+}
+
+impl<'a> Timestamp<'a> {
+    /// Parse a timestamp from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid
+    /// timestamp.
+    fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (kind, input) = camel_case(input)?;
+        let (_, name_input) = spaces(input)?;
+
+        let Ok((_, name_input)) = colon(name_input) else {
+            return Ok((
+                Self {
+                    kind: kind.into(),
+                    name: None,
+                },
+                input,
+            ));
+        };
+
+        let (_, name_input) = spaces(name_input)?;
+        let (name, input) = camel_case(name_input)?;
+
+        Ok((
+            Self {
+                kind: kind.into(),
+                name: Some(name.into()),
+            },
+            input,
+        ))
+    }
+}
+
+impl Display for Timestamp<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{}: {name}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+/// A block attribute, written inside a model after `@@`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Block<'a> {
+    /// `@@id([a, b])`: the combination of the given fields identifies the
+    /// record.
+    Id(Vec<Cow<'a, str>>),
+    /// `@@id(strategy)`: the record is identified by a generated `id` field,
+    /// for example `uuid`, `cuid` or `autoincrement`.
+    IdStrategy(Cow<'a, str>),
+    /// `@@index([a, b])`: an index on the given fields.
+    Index(Vec<Cow<'a, str>>),
+    /// `@@timestamps(createdAt: insertedAt, updatedAt)`: the automatic
+    /// timestamp fields of the model. Empty if the model has none.
+    Timestamps(Vec<Timestamp<'a>>),
+    /// `@@unique([a, b])`: no two records can have the same values for the
+    /// given fields.
+    Unique(Vec<Cow<'a, str>>),
+}
+
+impl<'a> Block<'a> {
+    /// The names of the fields that the attribute applies to.
+    #[must_use]
+    pub fn fields(&self) -> &[Cow<'a, str>] {
+        match self {
+            Self::Id(fields) | Self::Index(fields) | Self::Unique(fields) => {
+                fields
+            }
+            Self::IdStrategy(_) | Self::Timestamps(_) => &[],
+        }
+    }
+
+    /// Parse a list of field names: `[a, b]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a non-empty
+    /// list of field names.
+    fn parse_fields(input: Input<'a>) -> ParseResult<'a, Vec<Cow<'a, str>>> {
+        let (_, input) = literal(input, "[")?;
+        let (_, input) = spaces(input)?;
+        let (field, mut input) = camel_case(input)?;
+        let mut fields = vec![field.into()];
+
+        loop {
+            let (_, new_input) = spaces(input)?;
+
+            let Ok((_, new_input)) = comma(new_input) else {
+                break;
+            };
+
+            let (_, new_input) = spaces(new_input)?;
+            let (field, new_input) = camel_case(new_input)?;
+
+            fields.push(field.into());
+            input = new_input;
+        }
+
+        let (_, input) = spaces(input)?;
+        let (_, input) = literal(input, "]")?;
+
+        Ok((fields, input))
+    }
+
+    /// Parse the arguments of a `@@timestamps` attribute: a possibly empty
+    /// list of timestamps between parentheses.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid list
+    /// of timestamps.
+    fn parse_timestamps(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (_, input) = paren_open(input)?;
+        let (_, mut input) = spaces(input)?;
+        let mut timestamps = Vec::new();
+
+        while let Ok((timestamp, new_input)) = Timestamp::parse(input) {
+            timestamps.push(timestamp);
+
+            let (_, new_input) = spaces(new_input)?;
+
+            input = new_input;
+
+            let Ok((_, new_input)) = comma(input) else {
+                break;
+            };
+
+            let (_, new_input) = spaces(new_input)?;
+
+            input = new_input;
+        }
+
+        let (_, input) = paren_close(input)?;
+
+        Ok((Self::Timestamps(timestamps), input))
+    }
+
+    /// Parse a block attribute from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `ParseError` if the input does not start with a valid block
+    ///   attribute.
+    /// * Returns `ParseError::Custom` if the attribute is unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::model::attribute::Block,
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("@@index([title, createdAt])");
+    ///
+    /// assert_eq!(
+    ///     Block::parse(input),
+    ///     Ok((
+    ///         Block::Index(vec!["title".into(), "createdAt".into()]),
+    ///         input.advance(27)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (_, name_input) = literal(input, "@@")?;
+        let (name, end) = alphabetics(name_input)?;
+
+        if name == "timestamps" {
+            return Self::parse_timestamps(end);
+        }
+
+        let constructor = match name {
+            "id" => Self::Id,
+            "index" => Self::Index,
+            "unique" => Self::Unique,
+            _ => {
+                return Err(ParseError::custom(
+                    format!("Unknown block attribute `@@{name}`."),
+                    input.span_to(end),
+                ));
+            }
+        };
+
+        let (_, end) = paren_open(end)?;
+        let (_, end) = spaces(end)?;
+
+        let (attribute, end) = if name == "id" && literal(end, "[").is_err() {
+            let (strategy, end) = alphabetics(end)?;
+
+            (Self::IdStrategy(strategy.into()), end)
+        } else {
+            let (fields, end) = Self::parse_fields(end)?;
+
+            (constructor(fields), end)
+        };
+
+        let (_, end) = spaces(end)?;
+        let (_, end) = paren_close(end)?;
+
+        Ok((attribute, end))
+    }
+}
+
+impl Display for Block<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let name = match self {
+            Self::Id(_) => "id",
+            Self::IdStrategy(strategy) => return write!(f, "@@id({strategy})"),
+            Self::Timestamps(timestamps) => {
+                write!(f, "@@timestamps(")?;
+
+                for (index, timestamp) in timestamps.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{timestamp}")?;
+                }
+
+                return write!(f, ")");
+            }
+            Self::Index(_) => "index",
+            Self::Unique(_) => "unique",
+        };
+
+        write!(f, "@@{name}([{}])", self.fields().join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        parser::Span,
+    };
+
+    #[test]
+    fn test_parse_unknown_field_attribute() {
+        assert_eq!(
+            Field::parse(Input::new("@map(\"a\")")),
+            Err(ParseError::custom(
+                "Unknown field attribute `@map`.",
+                Span::new(0, 4)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_block_attribute() {
+        assert_eq!(
+            Block::parse(Input::new("@@map(\"a\")")),
+            Err(ParseError::custom(
+                "Unknown block attribute `@@map`.",
+                Span::new(0, 5)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_db_field_attribute() {
+        let input = Input::new("@db.Decimal( 10 ,2 )");
+
+        assert_eq!(
+            Field::parse(input),
+            Ok((
+                Field::Db(NativeType {
+                    name: "Decimal".into(),
+                    arguments: vec!["10".into(), "2".into()],
+                }),
+                input.advance(20)
+            ))
+        );
+
+        let input = Input::new("@db.Text @unique");
+
+        assert_eq!(
+            Field::parse(input),
+            Ok((
+                Field::Db(NativeType {
+                    name: "Text".into(),
+                    arguments: Vec::new(),
+                }),
+                input.advance(8)
+            ))
+        );
+
+        assert!(Field::parse(Input::new("@db.VarChar()")).is_err());
+        assert!(Field::parse(Input::new("@db")).is_err());
+    }
+
+    #[test]
+    fn test_parse_inverse_field_attribute() {
+        let input = Input::new("@inverse( flags )");
+
+        assert_eq!(
+            Field::parse(input),
+            Ok((Field::Inverse("flags".into()), input.advance(17)))
+        );
+
+        assert!(Field::parse(Input::new("@inverse(Flags)")).is_err());
+        assert!(Field::parse(Input::new("@inverse")).is_err());
+    }
+
+    #[test]
+    fn test_parse_referential_action_field_attributes() {
+        let input = Input::new("@onDelete( Cascade )");
+
+        assert_eq!(
+            Field::parse(input),
+            Ok((Field::OnDelete("Cascade".into()), input.advance(20)))
+        );
+
+        let input = Input::new("@onUpdate(SetNull)");
+
+        assert_eq!(
+            Field::parse(input),
+            Ok((Field::OnUpdate("SetNull".into()), input.advance(18)))
+        );
+
+        assert!(Field::parse(Input::new("@onDelete(cascade)")).is_err());
+    }
+
+    #[test]
+    fn test_parse_unique_block() {
+        let input = Input::new("@@unique([ a ,b ])");
+
+        assert_eq!(
+            Block::parse(input),
+            Ok((
+                Block::Unique(vec!["a".into(), "b".into()]),
+                input.advance(18)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_id_block() {
+        let input = Input::new("@@id([ countryCode, number ])");
+
+        assert_eq!(
+            Block::parse(input),
+            Ok((
+                Block::Id(vec!["countryCode".into(), "number".into()]),
+                input.advance(29)
+            ))
+        );
+
+        let input = Input::new("@@id(uuid)");
+
+        assert_eq!(
+            Block::parse(input),
+            Ok((Block::IdStrategy("uuid".into()), input.advance(10)))
+        );
+    }
+
+    #[test]
+    fn test_parse_timestamps_block() {
+        let input =
+            Input::new("@@timestamps(createdAt: insertedAt , updatedAt)");
+
+        assert_eq!(
+            Block::parse(input),
+            Ok((
+                Block::Timestamps(vec![
+                    Timestamp {
+                        kind: "createdAt".into(),
+                        name: Some("insertedAt".into()),
+                    },
+                    Timestamp {
+                        kind: "updatedAt".into(),
+                        name: None,
+                    },
+                ]),
+                input.advance(47)
+            ))
+        );
+
+        let input = Input::new("@@timestamps( )");
+
+        assert_eq!(
+            Block::parse(input),
+            Ok((Block::Timestamps(Vec::new()), input.advance(15)))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Field::Unique.to_string(), "@unique");
+        assert_eq!(Field::Id.to_string(), "@id");
+
+        assert_eq!(
+            Field::Inverse("flags".into()).to_string(),
+            "@inverse(flags)"
+        );
+
+        assert_eq!(
+            Field::OnDelete("Cascade".into()).to_string(),
+            "@onDelete(Cascade)"
+        );
+
+        assert_eq!(
+            Field::OnUpdate("Restrict".into()).to_string(),
+            "@onUpdate(Restrict)"
+        );
+
+        assert_eq!(
+            Field::Db(NativeType {
+                name: "Text".into(),
+                arguments: Vec::new(),
+            })
+            .to_string(),
+            "@db.Text"
+        );
+
+        assert_eq!(
+            Field::Db(NativeType {
+                name: "Decimal".into(),
+                arguments: vec!["10".into(), "2".into()],
+            })
+            .to_string(),
+            "@db.Decimal(10, 2)"
+        );
+        assert_eq!(Block::IdStrategy("cuid".into()).to_string(), "@@id(cuid)");
+
+        assert_eq!(
+            Block::Timestamps(vec![
+                Timestamp {
+                    kind: "createdAt".into(),
+                    name: Some("insertedAt".into()),
+                },
+                Timestamp {
+                    kind: "deletedAt".into(),
+                    name: None,
+                },
+            ])
+            .to_string(),
+            "@@timestamps(createdAt: insertedAt, deletedAt)"
+        );
+
+        assert_eq!(
+            Block::Index(vec!["a".into(), "b".into()]).to_string(),
+            "@@index([a, b])"
+        );
+    }
+}
@@ -1,10 +1,17 @@
 use {
+    super::attribute,
     crate::Type,
     parser::{
+        at,
         camel_case,
         colon,
+        doc_comments,
+        literal,
         spaces,
+        Input,
+        ParseError,
         ParseResult,
+        Span,
     },
     std::{
         borrow::Cow,
@@ -23,6 +30,13 @@ pub struct Field<'a> {
     pub name: Cow<'a, str>,
     /// The type of the field.
     pub r#type: Type<'a>,
+    /// The span of the field, from its name to the end of its type or last
+    /// attribute.
+    pub span: Span,
+    /// The documentation of the field, taken from its doc comments.
+    pub doc: Option<Cow<'a, str>>,
+    /// The attributes of the field.
+    pub attributes: Vec<attribute::Field<'a>>,
 }
 
 impl<'a> Field<'a> {
@@ -34,17 +48,61 @@ impl<'a> Field<'a> {
     ///
     /// # Errors
     ///
-    /// Returns a `ParseError` if the input does not start with a valid field.
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        let (name, input) = camel_case(input)?;
-        let (_, input) = colon(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (r#type, input) = Type::parse(&input)?;
+    /// * Returns a `ParseError` if the input does not start with a valid field.
+    /// * Returns `ParseError::Custom` if the field is named `and`, `not` or
+    ///   `or`, which are reserved for where clauses.
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (doc, start) = doc_comments(input)?;
+        let (name, input) = camel_case(start)?;
+
+        if matches!(name, "and" | "not" | "or") {
+            return Err(ParseError::custom(
+                format!("Field name `{name}` is reserved for where clauses."),
+                start.span_to(input),
+            ));
+        }
+
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+        let (r#type, mut input) = Type::parse(input)?;
+        let mut attributes: Vec<attribute::Field> = Vec::new();
+
+        loop {
+            let (_, attribute_input) = spaces(input)?;
+
+            if literal(attribute_input, "@@").is_ok()
+                || at(attribute_input).is_err()
+            {
+                break;
+            }
+
+            let (attribute, new_input) =
+                attribute::Field::parse(attribute_input)?;
+
+            if attributes
+                .iter()
+                .any(|other| other.name() == attribute.name())
+            {
+                return Err(ParseError::custom(
+                    format!(
+                        "Duplicate attribute `@{}` on field `{name}`.",
+                        attribute.name()
+                    ),
+                    attribute_input.span_to(new_input),
+                ));
+            }
+
+            attributes.push(attribute);
+            input = new_input;
+        }
 
         Ok((
             Self {
                 name: name.into(),
                 r#type,
+                span: start.span_to(input),
+                doc: doc.map(Into::into),
+                attributes,
             },
             input,
         ))
@@ -56,9 +114,20 @@ impl Display for Field<'_> {
         &self,
         f: &mut Formatter<'_>,
     ) -> fmt::Result {
-        let Self { name, r#type } = self;
+        let Self {
+            name,
+            r#type,
+            attributes,
+            ..
+        } = self;
+
+        write!(f, "{name}: {type}")?;
+
+        for attribute in attributes {
+            write!(f, " {attribute}")?;
+        }
 
-        write!(f, "{name}: {type}")
+        Ok(())
     }
 }
 
@@ -78,6 +147,9 @@ mod tests {
         let field = Field {
             name: "foo".into(),
             r#type: Type::Scalar(Scalar::String),
+            span: Span::new(0, 11),
+            doc: None,
+            attributes: Vec::new(),
         };
 
         assert_eq!(field.to_string(), "foo: String");
@@ -85,54 +157,129 @@ mod tests {
 
     #[test]
     fn test_parse() {
-        let input = "foo: String";
+        let input = Input::new("foo: String");
 
         let expected = Field {
             name: "foo".into(),
             r#type: Type::Scalar(Scalar::String),
+            span: Span::new(0, 11),
+            doc: None,
+            attributes: Vec::new(),
         };
 
-        assert_eq!(Field::parse(input), Ok((expected, String::new())));
+        assert_eq!(Field::parse(input), Ok((expected, input.advance(11))));
     }
 
     #[test]
     fn test_parse_camel_case() {
-        let input = "Baz: Int";
+        let input = Input::new("Baz: Int");
 
         assert_eq!(
             Field::parse(input),
             Err(ParseError::unexpected_char(
                 'B',
                 "Expected camelCase identifier to start with lowercase \
-                 character, found 'B'."
+                 character, found 'B'.",
+                Span::new(0, 1),
             ))
         );
     }
 
     #[test]
     fn test_parse_colon() {
-        let input = "baz= Int";
+        let input = Input::new("baz= Int");
 
         assert_eq!(
             Field::parse(input),
             Err(ParseError::UnexpectedChar {
                 message: "Expected character ':', found '='.".into(),
                 actual: '=',
+                span: Span::new(3, 4),
             })
         );
     }
 
     #[test]
     fn test_parse_owned() {
+        let input = Input::new("foo: @Bar");
+
         assert_eq!(
-            Field::parse("foo: @Bar"),
+            Field::parse(input),
             Ok((
                 Field {
                     name: "foo".into(),
-                    r#type: Type::Scalar(Scalar::Owned("Bar".into()))
+                    r#type: Type::Scalar(Scalar::Owned("Bar".into())),
+                    span: Span::new(0, 9),
+                    doc: None,
+                    attributes: Vec::new(),
+                },
+                input.advance(9),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_attributes() {
+        let input = Input::new("slug: String @unique @default(\"untitled\")\n");
+
+        assert_eq!(
+            Field::parse(input),
+            Ok((
+                Field {
+                    name: "slug".into(),
+                    r#type: Type::Scalar(Scalar::String),
+                    span: Span::new(0, 41),
+                    doc: None,
+                    attributes: vec![
+                        attribute::Field::Unique,
+                        attribute::Field::Default(crate::Value::String(
+                            "untitled".into()
+                        )),
+                    ],
                 },
-                String::new(),
+                input.advance(41),
             ))
         );
     }
+
+    #[test]
+    fn test_parse_duplicate_attribute() {
+        let input = Input::new("slug: String @unique @unique");
+
+        assert_eq!(
+            Field::parse(input),
+            Err(ParseError::custom(
+                "Duplicate attribute `@unique` on field `slug`.",
+                Span::new(21, 28),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_reserved_name() {
+        let input = Input::new("or: Boolean");
+
+        assert_eq!(
+            Field::parse(input),
+            Err(ParseError::custom(
+                "Field name `or` is reserved for where clauses.",
+                Span::new(0, 2),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_display_attributes() {
+        let field = Field {
+            name: "count".into(),
+            r#type: Type::Scalar(Scalar::Int),
+            span: Span::new(0, 30),
+            doc: None,
+            attributes: vec![attribute::Field::Default(crate::Value::Int(
+                "0".into(),
+            ))],
+        };
+
+        assert_eq!(field.to_string(), "count: Int @default(0)");
+    }
 }
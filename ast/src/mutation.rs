@@ -0,0 +1,219 @@
+pub use self::{
+    action::Action,
+    assignment::Assignment,
+    operation::Operation,
+};
+use {
+    crate::query::{
+        Argument,
+        Query,
+        Where,
+    },
+    ord_str_map::OrdStrMap,
+    parser::{
+        alphabetics,
+        brace_close,
+        brace_open,
+        colon,
+        doc_comments,
+        literal,
+        option,
+        pascal_case,
+        spaces,
+        Input,
+        ParseResult,
+        Span,
+    },
+    std::borrow::Cow,
+};
+
+/// What a mutation does with a field.
+pub mod action;
+/// Actions on fields.
+pub mod assignment;
+/// The ways in which a mutation changes records.
+pub mod operation;
+
+/// A mutation: creates, updates, deletes or upserts a record of a model.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Mutation<'a> {
+    /// The name of the mutation. Used as the name of the generated function.
+    pub name: Cow<'a, str>,
+    /// The arguments of the mutation.
+    pub arguments: OrdStrMap<Argument<'a>>,
+    /// The name of the mutated model.
+    pub model_name: Cow<'a, str>,
+    /// The operation of the mutation.
+    pub operation: Operation<'a>,
+    /// The where clause that selects the record to update, delete or upsert.
+    pub r#where: Option<Where<'a>>,
+    /// The span of the mutation, from the `mutation` keyword to the closing
+    /// brace.
+    pub span: Span,
+    /// The documentation of the mutation, taken from its doc comments.
+    pub doc: Option<Cow<'a, str>>,
+}
+
+impl<'a> Mutation<'a> {
+    /// Parse a mutation from the given input. The body contains an operation
+    /// followed by an optional where clause.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid
+    /// mutation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::mutation::{
+    ///         Mutation,
+    ///         Operation,
+    ///     },
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = "mutation createTag($name: String): Tag {
+    ///   create {
+    ///     name: $name
+    ///   }
+    /// }";
+    ///
+    /// let (mutation, _) = Mutation::parse(Input::new(input)).unwrap();
+    ///
+    /// assert_eq!(mutation.model_name, "Tag");
+    /// assert!(matches!(mutation.operation, Operation::Create(_)));
+    /// assert!(mutation.r#where.is_none());
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (doc, start) = doc_comments(input)?;
+        let (_, input) = literal(start, "mutation")?;
+        let (_, input) = spaces(input)?;
+        let (name, input) = alphabetics(input)?;
+        let (_, input) = spaces(input)?;
+        let (arguments, input) = Query::parse_arguments(input)?;
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+        let (model_name, input) = pascal_case(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
+        let (operation, input) = Operation::parse(input)?;
+        let (_, input) = spaces(input)?;
+        let (r#where, input) = option(input, Where::parse)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_close(input)?;
+
+        Ok((
+            Self {
+                name: name.into(),
+                arguments,
+                model_name: model_name.into(),
+                operation,
+                r#where,
+                span: start.span_to(input),
+                doc: doc.map(Into::into),
+            },
+            input,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::query::{
+            Filter,
+            Operand,
+        },
+    };
+
+    #[test]
+    fn test_parse() {
+        let input = "
+
+/// Rename a post.
+mutation renamePost($id: Int, $title: String): Post {
+  update {
+    title: $title
+    editor: disconnect
+  }
+  where {
+    post {
+      id {
+        equals: $id
+      }
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let (mutation, rest) = Mutation::parse(Input::new(input)).unwrap();
+
+        assert!(rest.is_empty());
+        assert_eq!(mutation.name, "renamePost");
+        assert_eq!(mutation.doc, Some("Rename a post.".into()));
+        assert_eq!(mutation.model_name, "Post");
+        assert!(mutation.arguments.contains_key("id"));
+        assert!(mutation.arguments.contains_key("title"));
+        assert_eq!(mutation.span, Span::new(19, 200));
+
+        assert_eq!(
+            mutation.operation,
+            Operation::Update(vec![
+                Assignment {
+                    field_name: "title".into(),
+                    action: Action::Set(Operand::Argument("title".into())),
+                    span: Span::new(88, 101),
+                },
+                Assignment {
+                    field_name: "editor".into(),
+                    action: Action::Disconnect,
+                    span: Span::new(106, 124),
+                },
+            ])
+        );
+
+        assert!(matches!(
+            mutation.r#where.unwrap().filters.as_slice(),
+            [Filter::Condition(_)]
+        ));
+    }
+
+    #[test]
+    fn test_parse_delete() {
+        let input = "
+
+mutation deletePost($id: Int): Post {
+  delete
+  where {
+    post {
+      id {
+        equals: $id
+      }
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let (mutation, _) = Mutation::parse(Input::new(input)).unwrap();
+
+        assert_eq!(mutation.operation, Operation::Delete);
+        assert!(mutation.r#where.is_some());
+    }
+
+    #[test]
+    fn test_parse_missing_operation() {
+        assert!(Mutation::parse(Input::new("mutation foo: Post {\n}")).is_err());
+    }
+}
@@ -0,0 +1,133 @@
+use {
+    crate::query::Operand,
+    parser::{
+        literal,
+        spaces,
+        Input,
+        ParseResult,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// What a mutation does with a field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Action<'a> {
+    /// Relate the record to the record of the related model with the given
+    /// id: `connect $authorId`.
+    Connect(Operand<'a>),
+    /// Remove the relation to the related record: `disconnect`.
+    Disconnect,
+    /// Set the field to the given value.
+    Set(Operand<'a>),
+}
+
+impl<'a> Action<'a> {
+    /// Parse an action from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid action.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::{
+    ///         mutation::Action,
+    ///         query::Operand,
+    ///     },
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("connect $authorId");
+    ///
+    /// assert_eq!(
+    ///     Action::parse(input),
+    ///     Ok((
+    ///         Action::Connect(Operand::Argument("authorId".into())),
+    ///         input.advance(17)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        if let Ok((_, input)) = literal(input, "disconnect") {
+            return Ok((Self::Disconnect, input));
+        }
+
+        if let Ok((_, input)) = literal(input, "connect") {
+            let (_, input) = spaces(input)?;
+            let (operand, input) = Operand::parse(input)?;
+
+            return Ok((Self::Connect(operand), input));
+        }
+
+        let (operand, input) = Operand::parse(input)?;
+
+        Ok((Self::Set(operand), input))
+    }
+}
+
+impl Display for Action<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Connect(operand) => write!(f, "connect {operand}"),
+            Self::Disconnect => write!(f, "disconnect"),
+            Self::Set(operand) => write!(f, "{operand}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::Value,
+    };
+
+    #[test]
+    fn test_parse_disconnect() {
+        let input = Input::new("disconnect");
+
+        assert_eq!(
+            Action::parse(input),
+            Ok((Action::Disconnect, input.advance(10)))
+        );
+    }
+
+    #[test]
+    fn test_parse_set() {
+        let input = Input::new("Published");
+
+        assert_eq!(
+            Action::parse(input),
+            Ok((
+                Action::Set(Operand::Value(Value::Identifier(
+                    "Published".into()
+                ))),
+                input.advance(9)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Action::Connect(Operand::Argument("authorId".into())).to_string(),
+            "connect $authorId"
+        );
+
+        assert_eq!(Action::Disconnect.to_string(), "disconnect");
+        assert_eq!(Action::Set(Operand::Null).to_string(), "null");
+    }
+}
@@ -0,0 +1,116 @@
+use {
+    super::Action,
+    parser::{
+        camel_case,
+        colon,
+        spaces,
+        Input,
+        ParseResult,
+        Span,
+    },
+    std::borrow::Cow,
+};
+
+/// An action on a field of the mutated record: `title: $title`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Assignment<'a> {
+    /// The name of the field.
+    pub field_name: Cow<'a, str>,
+    /// What is done with the field.
+    pub action: Action<'a>,
+    /// The span of the assignment.
+    pub span: Span,
+}
+
+impl<'a> Assignment<'a> {
+    /// Parse an assignment from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid
+    /// assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::{
+    ///         mutation::{
+    ///             Action,
+    ///             Assignment,
+    ///         },
+    ///         query::Operand,
+    ///     },
+    ///     parser::{
+    ///         Input,
+    ///         Span,
+    ///     },
+    /// };
+    ///
+    /// let input = Input::new("title: $title");
+    ///
+    /// assert_eq!(
+    ///     Assignment::parse(input),
+    ///     Ok((
+    ///         Assignment {
+    ///             field_name: "title".into(),
+    ///             action: Action::Set(Operand::Argument("title".into())),
+    ///             span: Span::new(0, 13),
+    ///         },
+    ///         input.advance(13)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (field_name, end) = camel_case(input)?;
+        let (_, end) = spaces(end)?;
+        let (_, end) = colon(end)?;
+        let (_, end) = spaces(end)?;
+        let (action, end) = Action::parse(end)?;
+
+        Ok((
+            Self {
+                field_name: field_name.into(),
+                action,
+                span: input.span_to(end),
+            },
+            end,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::query::Operand,
+    };
+
+    #[test]
+    fn test_parse_connect() {
+        let input = Input::new("author: connect $authorId");
+
+        assert_eq!(
+            Assignment::parse(input),
+            Ok((
+                Assignment {
+                    field_name: "author".into(),
+                    action: Action::Connect(Operand::Argument(
+                        "authorId".into()
+                    )),
+                    span: Span::new(0, 25),
+                },
+                input.advance(25)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_colon() {
+        assert!(Assignment::parse(Input::new("title $title")).is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use {
+    super::Assignment,
+    parser::{
+        brace_close,
+        brace_open,
+        literal,
+        spaces,
+        Input,
+        ParseError,
+        ParseResult,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// The way a mutation changes the records of a model.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Operation<'a> {
+    /// Create a record: `create { ... }`.
+    Create(Vec<Assignment<'a>>),
+    /// Delete the matching record: `delete`.
+    Delete,
+    /// Update the matching record: `update { ... }`.
+    Update(Vec<Assignment<'a>>),
+    /// Update the matching record, or create it if it does not exist:
+    /// `upsert { create { ... } update { ... } }`.
+    Upsert {
+        /// The assignments used to create the record.
+        create: Vec<Assignment<'a>>,
+        /// The assignments used to update the record.
+        update: Vec<Assignment<'a>>,
+    },
+}
+
+impl<'a> Operation<'a> {
+    /// Parse a block of assignments from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid block of
+    /// assignments, or if the block assigns a field more than once.
+    fn parse_assignments(
+        input: Input<'a>
+    ) -> ParseResult<'a, Vec<Assignment<'a>>> {
+        let (_, input) = brace_open(input)?;
+        let (_, mut input) = spaces(input)?;
+        let mut assignments: Vec<Assignment<'a>> = Vec::new();
+
+        while let Ok((assignment, new_input)) = Assignment::parse(input) {
+            if assignments
+                .iter()
+                .any(|other| other.field_name == assignment.field_name)
+            {
+                return Err(ParseError::custom(
+                    format!(
+                        "Duplicate assignment to `{}`.",
+                        assignment.field_name
+                    ),
+                    assignment.span,
+                ));
+            }
+
+            assignments.push(assignment);
+            (_, input) = spaces(new_input)?;
+        }
+
+        let (_, input) = brace_close(input)?;
+
+        Ok((assignments, input))
+    }
+
+    /// Parse the block of assignments that follows the given keyword.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    /// * `keyword` - The keyword.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with the keyword
+    /// followed by a valid block of assignments.
+    fn parse_keyword_assignments(
+        input: Input<'a>,
+        keyword: &str,
+    ) -> ParseResult<'a, Vec<Assignment<'a>>> {
+        let (_, input) = literal(input, keyword)?;
+        let (_, input) = spaces(input)?;
+
+        Self::parse_assignments(input)
+    }
+
+    /// Parse an operation from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid
+    /// operation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::mutation::Operation,
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("delete");
+    ///
+    /// assert_eq!(
+    ///     Operation::parse(input),
+    ///     Ok((Operation::Delete, input.advance(6)))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        if let Ok((_, input)) = literal(input, "create") {
+            let (_, input) = spaces(input)?;
+            let (assignments, input) = Self::parse_assignments(input)?;
+
+            return Ok((Self::Create(assignments), input));
+        }
+
+        if let Ok((_, input)) = literal(input, "update") {
+            let (_, input) = spaces(input)?;
+            let (assignments, input) = Self::parse_assignments(input)?;
+
+            return Ok((Self::Update(assignments), input));
+        }
+
+        if let Ok((_, input)) = literal(input, "delete") {
+            return Ok((Self::Delete, input));
+        }
+
+        if let Ok((_, input)) = literal(input, "upsert") {
+            let (_, input) = spaces(input)?;
+            let (_, input) = brace_open(input)?;
+            let (_, input) = spaces(input)?;
+            let (create, input) =
+                Self::parse_keyword_assignments(input, "create")?;
+            let (_, input) = spaces(input)?;
+            let (update, input) =
+                Self::parse_keyword_assignments(input, "update")?;
+            let (_, input) = spaces(input)?;
+            let (_, input) = brace_close(input)?;
+
+            return Ok((Self::Upsert { create, update }, input));
+        }
+
+        Err(ParseError::custom(
+            "Expected `create`, `update`, `delete` or `upsert`.",
+            input.span(),
+        ))
+    }
+
+    /// The assignments of the operation, in source order.
+    #[must_use]
+    pub fn assignments(&self) -> Vec<&Assignment<'a>> {
+        match self {
+            Self::Create(assignments) | Self::Update(assignments) => {
+                assignments.iter().collect()
+            }
+            Self::Delete => Vec::new(),
+            Self::Upsert { create, update } => {
+                create.iter().chain(update.iter()).collect()
+            }
+        }
+    }
+}
+
+impl Display for Operation<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Create(_) => write!(f, "create"),
+            Self::Delete => write!(f, "delete"),
+            Self::Update(_) => write!(f, "update"),
+            Self::Upsert { .. } => write!(f, "upsert"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::{
+            mutation::Action,
+            query::Operand,
+        },
+        parser::Span,
+    };
+
+    #[test]
+    fn test_parse_create() {
+        let input = "
+
+create {
+  title: $title
+  author: connect $authorId
+}
+
+"
+        .trim();
+
+        let input = Input::new(input);
+
+        assert_eq!(
+            Operation::parse(input),
+            Ok((
+                Operation::Create(vec![
+                    Assignment {
+                        field_name: "title".into(),
+                        action: Action::Set(Operand::Argument("title".into())),
+                        span: Span::new(11, 24),
+                    },
+                    Assignment {
+                        field_name: "author".into(),
+                        action: Action::Connect(Operand::Argument(
+                            "authorId".into()
+                        )),
+                        span: Span::new(27, 52),
+                    },
+                ]),
+                input.advance(54)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_upsert() {
+        let input = "
+
+upsert {
+  create {
+    name: $name
+  }
+  update {
+    name: $name
+  }
+}
+
+"
+        .trim();
+
+        let (operation, rest) = Operation::parse(Input::new(input)).unwrap();
+
+        assert!(rest.is_empty());
+
+        let Operation::Upsert { create, update } = &operation else {
+            panic!("Expected an upsert.");
+        };
+
+        assert_eq!(create.len(), 1);
+        assert_eq!(update.len(), 1);
+        assert_eq!(operation.assignments().len(), 2);
+        assert_eq!(operation.to_string(), "upsert");
+    }
+
+    #[test]
+    fn test_parse_duplicate_assignment() {
+        let input = "
+
+update {
+  title: $title
+  title: $subtitle
+}
+
+"
+        .trim();
+
+        assert_eq!(
+            Operation::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Duplicate assignment to `title`.",
+                Span::new(27, 43),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Operation::parse(Input::new("insert {}")),
+            Err(ParseError::custom(
+                "Expected `create`, `update`, `delete` or `upsert`.",
+                Span::at(0),
+            ))
+        );
+    }
+}
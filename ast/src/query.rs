@@ -7,21 +7,28 @@ use {
         brace_open,
         colon,
         comma,
+        doc_comments,
         dollar,
         literal,
-        option,
         paren_close,
         paren_open,
         spaces,
+        Input,
         ParseError,
         ParseResult,
+        Span,
     },
     std::borrow::Cow,
 };
 pub use {
     argument::Argument,
+    cursor::Cursor,
+    order_by::OrderBy,
     r#where::{
         Condition,
+        Filter,
+        Mode,
+        Operand,
         Operator,
         Path,
         Where,
@@ -35,6 +42,10 @@ pub use {
 
 /// Query arguments.
 pub mod argument;
+/// The record after which a paginated query starts.
+pub mod cursor;
+/// The order of the results of a query.
+pub mod order_by;
 /// The return type of a query.
 pub mod return_type;
 /// The structure of the data that the query should return.
@@ -56,10 +67,23 @@ pub struct Query<'a> {
     pub r#type: ReturnType<'a>,
     /// The where clause of the query.
     pub r#where: Option<Where<'a>>,
+    /// The order of the results.
+    pub order_by: Option<OrderBy<'a>>,
+    /// The maximum number of results.
+    pub take: Option<Operand<'a>>,
+    /// The number of results to skip.
+    pub skip: Option<Operand<'a>>,
+    /// The record after which the results start.
+    pub cursor: Option<Cursor<'a>>,
+    /// The span of the query, from the `query` keyword to the closing brace.
+    pub span: Span,
+    /// The documentation of the query, taken from its doc comments.
+    pub doc: Option<Cow<'a, str>>,
 }
 
 impl<'a> Query<'a> {
-    /// Parse query arguments from the given input.
+    /// Parse query arguments from the given input. Returns no arguments if
+    /// the input does not start with an opening parenthesis.
     ///
     /// # Arguments
     ///
@@ -69,37 +93,39 @@ impl<'a> Query<'a> {
     ///
     /// Returns `ParseError` if the input does not start with a valid query
     /// argument.
-    fn parse_arguments(input: &str) -> ParseResult<OrdStrMap<Argument<'a>>> {
+    pub fn parse_arguments(
+        input: Input<'a>
+    ) -> ParseResult<'a, OrdStrMap<Argument<'a>>> {
         if let Ok((_, input)) = paren_open(input) {
             let mut arguments = OrdStrMap::new();
-            let (argument, mut input) = Argument::parse(&input)?;
+            let (argument, mut input) = Argument::parse(input)?;
 
             let _: Option<Argument> =
-                arguments.insert(argument.name.clone(), argument.clone());
-
-            while let Ok((_, new_input)) = comma(&input) {
-                let (_, new_input) = spaces(&new_input)?;
-                let (argument, new_input) = Argument::parse(&new_input)?;
-
-                if arguments
-                    .insert(argument.name.clone(), argument.clone())
-                    .is_some()
-                {
-                    return Err(ParseError::custom(format!(
-                        "duplicate argument `{}`.",
-                        argument.name
-                    )));
+                arguments.insert(argument.name.clone(), argument);
+
+            while let Ok((_, new_input)) = comma(input) {
+                let (_, start) = spaces(new_input)?;
+                let (argument, new_input) = Argument::parse(start)?;
+
+                if arguments.contains_key(&argument.name) {
+                    return Err(ParseError::custom(
+                        format!("duplicate argument `{}`.", argument.name),
+                        start.span_to(new_input),
+                    ));
                 }
 
+                let _: Option<Argument> =
+                    arguments.insert(argument.name.clone(), argument);
+
                 input = new_input;
             }
 
-            let (_, input) = paren_close(&input)?;
+            let (_, input) = paren_close(input)?;
 
             return Ok((arguments, input));
         }
 
-        Ok((OrdStrMap::new(), input.to_owned()))
+        Ok((OrdStrMap::new(), input))
     }
 
     /// Parse a reference from the given input.
@@ -112,13 +138,81 @@ impl<'a> Query<'a> {
     ///
     /// Returns `ParseError` if the input does not start with a valid
     /// reference.
-    fn parse_reference(input: &str) -> ParseResult<String> {
+    fn parse_reference(input: Input<'a>) -> ParseResult<'a, &'a str> {
         let (_, input) = dollar(input)?;
 
-        alphabetics(&input)
+        alphabetics(input)
+    }
+
+    /// Parse a `take` clause from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid `take`
+    /// clause.
+    fn parse_take(input: Input<'a>) -> ParseResult<'a, Operand<'a>> {
+        let (_, input) = literal(input, "take")?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+
+        Operand::parse(input)
+    }
+
+    /// Parse a `skip` clause from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid `skip`
+    /// clause.
+    fn parse_skip(input: Input<'a>) -> ParseResult<'a, Operand<'a>> {
+        let (_, input) = literal(input, "skip")?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+
+        Operand::parse(input)
     }
 
-    /// Parse a query from the given input.
+    /// Return an error if a clause with the given keyword was already parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `parsed` - Whether the clause was already parsed.
+    /// * `keyword` - The keyword of the clause.
+    /// * `input` - The input, starting at the keyword.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the clause was already parsed.
+    fn check_duplicate_clause(
+        parsed: bool,
+        keyword: &str,
+        input: Input<'a>,
+    ) -> Result<(), ParseError> {
+        if parsed {
+            let (_, end) = literal(input, keyword)?;
+
+            return Err(ParseError::custom(
+                format!("Duplicate `{keyword}` clause in query."),
+                input.span_to(end),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Parse a query from the given input. The schema is followed by an
+    /// optional where clause, order by clause, `take`, `skip` and cursor, in
+    /// any order. Once a clause keyword is found, the clause must be valid.
     ///
     /// # Arguments
     ///
@@ -126,24 +220,88 @@ impl<'a> Query<'a> {
     ///
     /// # Errors
     ///
-    /// Returns `ParseError` if the input does not start with a valid query.
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        let (_, input) = literal(input, "query")?;
-        let (_, input) = spaces(&input)?;
-        let (name, input) = alphabetics(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (arguments, input) = Self::parse_arguments(&input)?;
-        let (_, input) = colon(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (r#type, input) = ReturnType::parse(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_open(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (schema, input) = Schema::parse(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (r#where, input) = option(&input, Where::parse)?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_close(&input)?;
+    /// * Returns `ParseError` if the input does not start with a valid query.
+    /// * Returns `ParseError` if a clause is invalid.
+    /// * Returns `ParseError` if a clause occurs more than once.
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (doc, start) = doc_comments(input)?;
+        let (_, input) = literal(start, "query")?;
+        let (_, input) = spaces(input)?;
+        let (name, input) = alphabetics(input)?;
+        let (_, input) = spaces(input)?;
+        let (arguments, input) = Self::parse_arguments(input)?;
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+        let (r#type, input) = ReturnType::parse(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
+        let (schema, input) = Schema::parse(input)?;
+        let (_, mut input) = spaces(input)?;
+        let mut r#where = None;
+        let mut order_by = None;
+        let mut take = None;
+        let mut skip = None;
+        let mut cursor = None;
+
+        loop {
+            if literal(input, "where").is_ok() {
+                Self::check_duplicate_clause(
+                    r#where.is_some(),
+                    "where",
+                    input,
+                )?;
+
+                let (clause, new_input) = Where::parse(input)?;
+
+                r#where = Some(clause);
+                input = new_input;
+            } else if literal(input, "orderBy").is_ok() {
+                Self::check_duplicate_clause(
+                    order_by.is_some(),
+                    "orderBy",
+                    input,
+                )?;
+
+                let (clause, new_input) = OrderBy::parse(input)?;
+
+                order_by = Some(clause);
+                input = new_input;
+            } else if literal(input, "take").is_ok() {
+                Self::check_duplicate_clause(take.is_some(), "take", input)?;
+
+                let (clause, new_input) = Self::parse_take(input)?;
+
+                take = Some(clause);
+                input = new_input;
+            } else if literal(input, "skip").is_ok() {
+                Self::check_duplicate_clause(skip.is_some(), "skip", input)?;
+
+                let (clause, new_input) = Self::parse_skip(input)?;
+
+                skip = Some(clause);
+                input = new_input;
+            } else if literal(input, "cursor").is_ok() {
+                Self::check_duplicate_clause(
+                    cursor.is_some(),
+                    "cursor",
+                    input,
+                )?;
+
+                let (clause, new_input) = Cursor::parse(input)?;
+
+                cursor = Some(clause);
+                input = new_input;
+            } else {
+                break;
+            }
+
+            let (_, new_input) = spaces(input)?;
+
+            input = new_input;
+        }
+
+        let (_, input) = brace_close(input)?;
 
         Ok((
             Self {
@@ -152,6 +310,12 @@ impl<'a> Query<'a> {
                 schema,
                 r#type,
                 r#where,
+                order_by,
+                take,
+                skip,
+                cursor,
+                span: start.span_to(input),
+                doc: doc.map(Into::into),
             },
             input,
         ))
@@ -162,7 +326,10 @@ impl<'a> Query<'a> {
 mod tests {
     use {
         super::*,
-        crate::r#type,
+        crate::{
+            r#type,
+            Value,
+        },
         ord_str_map::OrdStrMap,
     };
 
@@ -190,9 +357,17 @@ mod tests {
             },
             r#type: ReturnType::Array("Image".into()),
             r#where: None,
+            span: Span::new(0, 81),
+            order_by: None,
+            take: None,
+            skip: None,
+            cursor: None,
+            doc: None,
         };
 
-        assert_eq!(Query::parse(input), Ok((expected, String::new())));
+        let input = Input::new(input);
+
+        assert_eq!(Query::parse(input), Ok((expected, input.advance(81))));
     }
 
     #[test]
@@ -250,22 +425,74 @@ mod tests {
             r#type: ReturnType::Array("Image".into()),
             r#where: Some(Where {
                 name: "image".into(),
-                conditions: vec![
-                    Condition {
+                filters: vec![
+                    Filter::Condition(Condition {
                         path: Path::from_iter(["title"]),
                         operator: Operator::Equals,
-                        argument_name: "title".into(),
-                    },
-                    Condition {
+                        rhs: Operand::Argument("title".into()),
+                        mode: Mode::Default,
+                        span: Span::new(178, 192),
+                    }),
+                    Filter::Condition(Condition {
                         path: Path::from_iter(["title", "tags"]),
                         operator: Operator::Contains,
-                        argument_name: "tag".into(),
-                    },
+                        rhs: Operand::Argument("tag".into()),
+                        mode: Mode::Default,
+                        span: Span::new(234, 248),
+                    }),
                 ],
             }),
+            span: Span::new(0, 318),
+            order_by: None,
+            take: None,
+            skip: None,
+            cursor: None,
+            doc: None,
         };
 
-        assert_eq!(Query::parse(input), Ok((expected, String::new())));
+        let input = Input::new(input);
+
+        assert_eq!(Query::parse(input), Ok((expected, input.advance(318))));
+    }
+
+    #[test]
+    fn test_parse_doc_comment() {
+        let input = "
+
+/// All images.
+query images: [Image] {
+  image {
+    title
+  }
+}
+
+        "
+        .trim();
+
+        let (query, _) = Query::parse(Input::new(input)).unwrap();
+
+        assert_eq!(query.doc, Some("All images.".into()));
+        assert_eq!(query.span, Span::new(16, 65));
+    }
+
+    #[test]
+    fn test_parse_stray_doc_comments() {
+        let input = "
+
+query images: [Image] {
+  image {
+    /// Not documentation.
+    title
+    /// Not documentation either.
+  }
+}
+
+        "
+        .trim();
+
+        let (query, _) = Query::parse(Input::new(input)).unwrap();
+
+        assert_eq!(query.doc, None);
     }
 
     #[test]
@@ -281,9 +508,169 @@ mod tests {
         "
         .trim();
 
+        let input = Input::new(input);
+
         assert_eq!(
             Query::parse(input),
-            Err(ParseError::custom("duplicate argument `tag`."))
+            Err(ParseError::custom(
+                "duplicate argument `tag`.",
+                Span::new(27, 39)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_pagination() {
+        let input = "
+
+query images($after: Int): [Image] {
+  image {
+    title
+  }
+  orderBy {
+    createdAt: desc
+  }
+  take: 20
+  skip: 1
+  cursor {
+    id: $after
+  }
+}
+
+        "
+        .trim();
+
+        let (query, _) = Query::parse(Input::new(input)).unwrap();
+
+        assert_eq!(
+            query.order_by.map(|order_by| order_by.orderings.len()),
+            Some(1)
+        );
+
+        assert_eq!(query.take, Some(Operand::Value(Value::Int("20".into()))));
+        assert_eq!(query.skip, Some(Operand::Value(Value::Int("1".into()))));
+
+        assert_eq!(
+            query.cursor.map(|cursor| cursor.rhs),
+            Some(Operand::Argument("after".into()))
+        );
+    }
+
+    #[test]
+    fn test_parse_clauses_in_any_order() {
+        let input = "
+
+query images($title: String): [Image] {
+  image {
+    title
+  }
+  take: 20
+  orderBy {
+    createdAt: desc
+  }
+  where {
+    image {
+      title {
+        equals: $title
+      }
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let (query, _) = Query::parse(Input::new(input)).unwrap();
+
+        assert!(query.r#where.is_some());
+        assert!(query.order_by.is_some());
+        assert_eq!(query.take, Some(Operand::Value(Value::Int("20".into()))));
+    }
+
+    #[test]
+    fn test_parse_duplicate_clause() {
+        let input = "
+
+query images: [Image] {
+  image {
+    title
+  }
+  take: 20
+  take: 10
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Query::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Duplicate `take` clause in query.",
+                Span::new(61, 65),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_where() {
+        let input = "
+
+query images($title: String): [Image] {
+  image {
+    title
+  }
+  where {
+    image {
+      title {
+        equls: $title
+      }
+    }
+  }
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Query::parse(Input::new(input)),
+            Err(ParseError::custom(
+                "Unknown operator `equls`.",
+                Span::new(108, 113)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_order_by() {
+        let input = "
+
+query images: [Image] {
+  image {
+    title
+  }
+  orderBy {
+    createdAt: down
+  }
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Query::parse(Input::new(input)),
+            Err(ParseError::UnmatchedChoice {
+                errors: vec![
+                    ParseError::UnmatchedLiteral {
+                        expected: "asc".into(),
+                        span: Span::at(75),
+                    },
+                    ParseError::UnmatchedLiteral {
+                        expected: "desc".into(),
+                        span: Span::at(75),
+                    },
+                ],
+                span: Span::at(75),
+            })
         );
     }
 }
@@ -6,6 +6,7 @@ use {
     parser::{
         colon,
         spaces,
+        Input,
         ParseResult,
     },
     std::{
@@ -39,11 +40,11 @@ impl<'a> Argument<'a> {
     ///
     /// Returns `ParseError` if the input does not start with a valid
     /// argument.
-    pub fn parse(input: &str) -> ParseResult<Self> {
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
         let (name, input) = Query::parse_reference(input)?;
-        let (_, input) = colon(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (r#type, input) = Type::parse(&input)?;
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+        let (r#type, input) = Type::parse(input)?;
 
         Ok((
             Self {
@@ -75,14 +76,16 @@ mod tests {
 
     #[test]
     fn test_parse() {
+        let input = Input::new("$name: String");
+
         assert_eq!(
-            Argument::parse("$name: String"),
+            Argument::parse(input),
             Ok((
                 Argument {
                     name: "name".into(),
                     r#type: Type::Scalar(r#type::Scalar::String),
                 },
-                String::new()
+                input.advance(13)
             ))
         );
     }
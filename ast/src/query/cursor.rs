@@ -0,0 +1,134 @@
+use {
+    super::r#where::Operand,
+    parser::{
+        brace_close,
+        brace_open,
+        camel_case,
+        colon,
+        literal,
+        spaces,
+        Input,
+        ParseResult,
+        Span,
+    },
+    std::{
+        borrow::Cow,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+    },
+};
+
+/// The record after which a paginated query starts, identified by the value
+/// of a unique field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Cursor<'a> {
+    /// The name of the unique field.
+    pub field_name: Cow<'a, str>,
+    /// The value of the field: an argument or a literal.
+    pub rhs: Operand<'a>,
+    /// The span of the cursor, from the `cursor` keyword to the closing
+    /// brace.
+    pub span: Span,
+}
+
+impl Display for Cursor<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "cursor {{ {}: {} }}", self.field_name, self.rhs)
+    }
+}
+
+impl<'a> Cursor<'a> {
+    /// Parse a cursor from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::query::{
+    ///         Cursor,
+    ///         Operand,
+    ///     },
+    ///     parser::{
+    ///         Input,
+    ///         Span,
+    ///     },
+    /// };
+    ///
+    /// let input = Input::new("cursor {\n  id: $after\n}");
+    ///
+    /// assert_eq!(
+    ///     Cursor::parse(input),
+    ///     Ok((
+    ///         Cursor {
+    ///             field_name: "id".into(),
+    ///             rhs: Operand::Argument("after".into()),
+    ///             span: Span::new(0, 23),
+    ///         },
+    ///         input.advance(23)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let start = input;
+        let (_, input) = literal(input, "cursor")?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
+        let (field_name, input) = camel_case(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = colon(input)?;
+        let (_, input) = spaces(input)?;
+        let (rhs, input) = Operand::parse(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_close(input)?;
+
+        Ok((
+            Self {
+                field_name: field_name.into(),
+                rhs,
+                span: start.span_to(input),
+            },
+            input,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::Value,
+    };
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Cursor {
+                field_name: "slug".into(),
+                rhs: Operand::Value(Value::String("intro".into())),
+                span: Span::new(0, 0),
+            }
+            .to_string(),
+            "cursor { slug: \"intro\" }"
+        );
+    }
+
+    #[test]
+    fn test_parse_missing_field() {
+        assert!(Cursor::parse(Input::new("cursor { $after }")).is_err());
+    }
+}
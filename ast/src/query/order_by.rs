@@ -0,0 +1,249 @@
+pub use self::direction::Direction;
+use {
+    super::r#where::Path,
+    parser::{
+        brace_close,
+        brace_open,
+        camel_case,
+        colon,
+        literal,
+        spaces,
+        Input,
+        ParseError,
+        ParseResult,
+        Span,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// The direction of an ordering.
+pub mod direction;
+
+/// An ordering on a single field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ordering<'a> {
+    /// The path to the field.
+    pub path: Path<'a>,
+    /// The direction of the ordering.
+    pub direction: Direction,
+    /// The span of the ordering, from the field name to the end of the
+    /// direction.
+    pub span: Span,
+}
+
+impl Display for Ordering<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{} {}", self.path, self.direction)
+    }
+}
+
+/// An order by clause. Results are ordered by the first ordering, then by
+/// the second, and so on.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OrderBy<'a> {
+    /// The orderings, in order of precedence.
+    pub orderings: Vec<Ordering<'a>>,
+}
+
+impl<'a> OrderBy<'a> {
+    /// Parse the orderings of a block from the given input, up to the closing
+    /// brace of the block.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    /// * `path` - The path to the relation of the block.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if a field name is not followed by a valid
+    /// direction or a nested block, or if a nested block is not closed.
+    fn parse_orderings(
+        input: Input<'a>,
+        path: &Path<'a>,
+    ) -> ParseResult<'a, Vec<Ordering<'a>>> {
+        let mut input = input;
+        let mut orderings = Vec::new();
+
+        loop {
+            let Ok((segment, after_segment)) = camel_case(input) else {
+                return Ok((orderings, input));
+            };
+
+            let (_, after_segment) = spaces(after_segment)?;
+            let mut segment_path = path.clone();
+
+            segment_path.push(segment);
+
+            if let Ok((_, block_input)) = brace_open(after_segment) {
+                let (_, block_input) = spaces(block_input)?;
+                let (block_orderings, new_input) =
+                    Self::parse_orderings(block_input, &segment_path)?;
+
+                let (_, new_input) = brace_close(new_input)?;
+                let (_, new_input) = spaces(new_input)?;
+
+                orderings.extend(block_orderings);
+                input = new_input;
+            } else {
+                let (_, direction_input) = colon(after_segment)?;
+                let (_, direction_input) = spaces(direction_input)?;
+                let (direction, end) = Direction::parse(direction_input)?;
+                let (_, new_input) = spaces(end)?;
+
+                orderings.push(Ordering {
+                    path: segment_path,
+                    direction,
+                    span: input.span_to(end),
+                });
+
+                input = new_input;
+            }
+        }
+    }
+
+    /// Parse an order by clause from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the input does not start with a valid order by
+    /// clause.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::query::{
+    ///         order_by::{
+    ///             Direction,
+    ///             Ordering,
+    ///         },
+    ///         OrderBy,
+    ///         Path,
+    ///     },
+    ///     parser::{
+    ///         Input,
+    ///         Span,
+    ///     },
+    /// };
+    ///
+    /// let input = Input::new("orderBy {\n  author {\n    name: asc\n  }\n}");
+    ///
+    /// assert_eq!(
+    ///     OrderBy::parse(input),
+    ///     Ok((
+    ///         OrderBy {
+    ///             orderings: vec![Ordering {
+    ///                 path: Path::from_iter(["author", "name"]),
+    ///                 direction: Direction::Asc,
+    ///                 span: Span::new(25, 34),
+    ///             }],
+    ///         },
+    ///         input.advance(40)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (_, start) = literal(input, "orderBy")?;
+        let (_, input) = spaces(start)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
+        let (orderings, input) =
+            Self::parse_orderings(input, &Path::default())?;
+
+        if orderings.is_empty() {
+            return Err(ParseError::Custom {
+                message: "Expected at least one ordering.".into(),
+                span: start.span_to(input),
+            });
+        }
+
+        let (_, input) = brace_close(input)?;
+
+        Ok((Self { orderings }, input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_ordering() {
+        assert_eq!(
+            Ordering {
+                path: Path::from_iter(["author", "name"]),
+                direction: Direction::Desc,
+                span: Span::new(0, 10),
+            }
+            .to_string(),
+            "author.name desc"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "
+
+orderBy {
+  createdAt: desc
+  author {
+    name: asc
+  }
+  title: asc
+}
+
+        "
+        .trim();
+
+        let input = Input::new(input);
+
+        assert_eq!(
+            OrderBy::parse(input),
+            Ok((
+                OrderBy {
+                    orderings: vec![
+                        Ordering {
+                            path: Path::from_iter(["createdAt"]),
+                            direction: Direction::Desc,
+                            span: Span::new(12, 27),
+                        },
+                        Ordering {
+                            path: Path::from_iter(["author", "name"]),
+                            direction: Direction::Asc,
+                            span: Span::new(43, 52),
+                        },
+                        Ordering {
+                            path: Path::from_iter(["title"]),
+                            direction: Direction::Asc,
+                            span: Span::new(59, 69),
+                        },
+                    ],
+                },
+                input.advance(71)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(
+            OrderBy::parse(Input::new("orderBy { }")),
+            Err(ParseError::Custom {
+                message: "Expected at least one ordering.".into(),
+                span: Span::new(7, 10),
+            })
+        );
+    }
+}
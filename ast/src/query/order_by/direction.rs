@@ -0,0 +1,99 @@
+use {
+    parser::{
+        choice,
+        literal,
+        tag,
+        Input,
+        ParseResult,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// The direction in which query results are ordered.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    /// From the lowest to the highest value.
+    Asc,
+    /// From the highest to the lowest value.
+    Desc,
+}
+
+impl Display for Direction {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Asc => write!(f, "asc"),
+            Self::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+impl Direction {
+    /// Parse a direction from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid
+    /// direction.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::query::order_by::Direction,
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("desc");
+    ///
+    /// assert_eq!(
+    ///     Direction::parse(input),
+    ///     Ok((Direction::Desc, input.advance(4)))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'_>) -> ParseResult<'_, Self> {
+        choice(
+            input,
+            &[
+                tag!(literal!("asc"), Self::Asc),
+                tag!(literal!("desc"), Self::Desc),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Direction::Asc.to_string(), "asc");
+        assert_eq!(Direction::Desc.to_string(), "desc");
+    }
+
+    #[test]
+    fn test_parse_asc() {
+        let input = Input::new("asc");
+
+        assert_eq!(
+            Direction::parse(input),
+            Ok((Direction::Asc, input.advance(3)))
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Direction::parse(Input::new("up")).is_err());
+    }
+}
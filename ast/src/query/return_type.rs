@@ -5,6 +5,7 @@ use {
     },
     parser::{
         spaces,
+        Input,
         ParseError,
         ParseResult,
     },
@@ -31,9 +32,10 @@ impl<'a> ReturnType<'a> {
     ///
     /// Returns a `ParseError` if the input does not start with a valid return
     /// type.
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        let (r#type, input) = Type::parse(input)?;
-        let (_, input) = spaces(&input)?;
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (r#type, end) = Type::parse(input)?;
+        let span = input.span_to(end);
+        let (_, input) = spaces(end)?;
 
         match r#type {
             Type::Scalar(r#type::Scalar::Reference(name)) => {
@@ -45,6 +47,7 @@ impl<'a> ReturnType<'a> {
             _ => {
                 Err(ParseError::Custom {
                     message: format!("Expected return type, found `{type}`."),
+                    span,
                 })
             }
         }
@@ -53,30 +56,40 @@ impl<'a> ReturnType<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        parser::Span,
+    };
 
     #[test]
     fn test_parse_model() {
+        let input = Input::new("Foo");
+
         assert_eq!(
-            ReturnType::parse("Foo"),
-            Ok((ReturnType::Model("Foo".into()), String::new()))
+            ReturnType::parse(input),
+            Ok((ReturnType::Model("Foo".into()), input.advance(3)))
         );
     }
 
     #[test]
     fn test_parse_array() {
+        let input = Input::new("[Foo]");
+
         assert_eq!(
-            ReturnType::parse("[Foo]"),
-            Ok((ReturnType::Array("Foo".into()), String::new()))
+            ReturnType::parse(input),
+            Ok((ReturnType::Array("Foo".into()), input.advance(5)))
         );
     }
 
     #[test]
     fn test_parse_error() {
+        let input = Input::new("String");
+
         assert_eq!(
-            ReturnType::parse("String"),
+            ReturnType::parse(input),
             Err(ParseError::Custom {
                 message: "Expected return type, found `String`.".into(),
+                span: Span::new(0, 6),
             })
         );
     }
@@ -8,6 +8,7 @@ use {
         many1,
         map,
         spaces,
+        Input,
         ParseResult,
     },
     std::borrow::Cow,
@@ -40,22 +41,22 @@ impl<'a> Node<'a> {
     /// # Errors
     ///
     /// Returns `ParseError` if the input does not start with a valid node.
-    fn parse_model(input: &str) -> ParseResult<Self> {
+    fn parse_model(input: Input<'a>) -> ParseResult<'a, Self> {
         let (name, input) = alphabetics(input)?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_open(&input)?;
-        let (_, input) = spaces(&input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
 
-        let (nodes, input) = many1(&input, |input| {
+        let (nodes, input) = many1(input, |input| {
+            let (_, input) = spaces(input)?;
+            let (schema, input) = Self::parse(input)?;
             let (_, input) = spaces(input)?;
-            let (schema, input) = Self::parse(&input)?;
-            let (_, input) = spaces(&input)?;
 
             Ok((schema, input))
         })?;
 
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_close(&input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_close(input)?;
 
         Ok((
             Self::Relation {
@@ -75,7 +76,7 @@ impl<'a> Node<'a> {
     /// # Errors
     ///
     /// Returns `ParseError` if the input does not start with a valid field.
-    fn parse_field(input: &str) -> ParseResult<Self> {
+    fn parse_field(input: Input<'a>) -> ParseResult<'a, Self> {
         map(input, camel_case, |name| Self::Field { name: name.into() })
     }
 
@@ -88,8 +89,8 @@ impl<'a> Node<'a> {
     /// # Errors
     ///
     /// Returns a `ParseError` if the input does not start with a valid node.
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        choice(input, vec![Self::parse_model, Self::parse_field])
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        choice(input, &[Self::parse_model, Self::parse_field])
     }
 
     /// Check if the schema node is empty. The schema node is empty if it is a
@@ -122,20 +123,20 @@ impl<'a> Schema<'a> {
     /// # Errors
     ///
     /// Returns a `ParseError` if the input does not start with a valid schema.
-    pub fn parse(input: &str) -> ParseResult<Self> {
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
         let (name, input) = alphabetics(input)?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_open(&input)?;
-        let (_, input) = spaces(&input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
 
-        let (nodes, input) = many1(&input, |input| {
+        let (nodes, input) = many1(input, |input| {
             let (schema, input) = Node::parse(input)?;
-            let (_, input) = spaces(&input)?;
+            let (_, input) = spaces(input)?;
 
             Ok((schema, input))
         })?;
 
-        let (_, input) = brace_close(&input)?;
+        let (_, input) = brace_close(input)?;
 
         Ok((
             Self {
@@ -158,7 +159,10 @@ impl<'a> Schema<'a> {
 mod tests {
     use {
         super::*,
-        parser::ParseError,
+        parser::{
+            ParseError,
+            Span,
+        },
     };
 
     #[test]
@@ -207,21 +211,25 @@ mod tests {
 
     #[test]
     fn test_parse_field_node() {
+        let input = Input::new("name");
+
         assert_eq!(
-            Node::parse("name"),
+            Node::parse(input),
             Ok((
                 Node::Field {
                     name: "name".into()
                 },
-                String::new()
+                input.advance(4)
             ))
         );
     }
 
     #[test]
     fn test_parse_model_node() {
+        let input = Input::new("user { name }");
+
         assert_eq!(
-            Node::parse("user { name }"),
+            Node::parse(input),
             Ok((
                 Node::Relation {
                     name: "user".into(),
@@ -229,15 +237,17 @@ mod tests {
                         name: "name".into()
                     }],
                 },
-                String::new()
+                input.advance(13)
             ))
         );
     }
 
     #[test]
     fn test_parse_schema() {
+        let input = Input::new("user { name }");
+
         assert_eq!(
-            Schema::parse("user { name }"),
+            Schema::parse(input),
             Ok((
                 Schema {
                     name: "user".into(),
@@ -245,26 +255,33 @@ mod tests {
                         name: "name".into()
                     }],
                 },
-                String::new()
+                input.advance(13)
             ))
         );
     }
 
     #[test]
     fn test_parse_schema_with_empty_node() {
+        let input = Input::new("user { }");
+
         assert_eq!(
-            Schema::parse("user { }"),
-            Err(ParseError::unmatched_choice([
-                ParseError::unexpected_char(
-                    '}',
-                    "Expected alphabetic character."
-                ),
-                ParseError::unexpected_char(
-                    '}',
-                    "Expected camelCase identifier to start with lowercase \
-                     character, found '}'."
-                )
-            ]))
+            Schema::parse(input),
+            Err(ParseError::unmatched_choice(
+                [
+                    ParseError::unexpected_char(
+                        '}',
+                        "Expected alphabetic character.",
+                        Span::new(7, 8),
+                    ),
+                    ParseError::unexpected_char(
+                        '}',
+                        "Expected camelCase identifier to start with \
+                         lowercase character, found '}'.",
+                        Span::new(7, 8),
+                    )
+                ],
+                Span::at(7),
+            ))
         );
     }
 }
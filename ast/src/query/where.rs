@@ -1,5 +1,8 @@
 pub use self::{
     condition::Condition,
+    filter::Filter,
+    mode::Mode,
+    operand::Operand,
     operator::Operator,
     path::Path,
 };
@@ -9,9 +12,9 @@ use {
         brace_open,
         camel_case,
         colon,
-        dollar,
         literal,
         spaces,
+        Input,
         ParseError,
         ParseResult,
     },
@@ -20,6 +23,12 @@ use {
 
 /// A condition.
 pub mod condition;
+/// A tree of conditions.
+pub mod filter;
+/// How string values are compared.
+pub mod mode;
+/// The right-hand side of a condition.
+pub mod operand;
 /// A condition operator.
 pub mod operator;
 /// A path to a field.
@@ -30,90 +39,162 @@ pub mod path;
 pub struct Where<'a> {
     /// The name of the root node.
     pub name: Cow<'a, str>,
-    /// The conditions that must be met.
-    pub conditions: Vec<Condition<'a>>,
+    /// The filters that must be met.
+    pub filters: Vec<Filter<'a>>,
 }
 
 impl<'a> Where<'a> {
-    /// Parse conditions from the given input.
+    /// The conditions in this where clause, in source order.
+    #[must_use]
+    pub fn conditions(&self) -> Vec<&Condition<'a>> {
+        self.filters.iter().flat_map(Filter::conditions).collect()
+    }
+
+    /// Set the mode of the conditions on the given path.
+    ///
+    /// # Arguments
+    ///
+    /// * `filters` - The filters to update.
+    /// * `path` - The path of the conditions.
+    /// * `mode` - The mode.
+    fn set_mode(
+        filters: &mut [Filter<'a>],
+        path: &Path<'a>,
+        mode: Mode,
+    ) {
+        for filter in filters {
+            match filter {
+                Filter::And(filters)
+                | Filter::Not(filters)
+                | Filter::Or(filters) => Self::set_mode(filters, path, mode),
+                Filter::Condition(condition) => {
+                    if condition.path == *path {
+                        condition.mode = mode;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parse the filters of a block from the given input, up to the closing
+    /// brace of the block. A name followed by an opening brace starts a
+    /// field block or an `and`, `not` or `or` group. Otherwise, the name is
+    /// `mode` or an operator.
     ///
     /// # Arguments
     ///
     /// * `input` - The input to parse.
+    /// * `path` - The path to the field of the block.
     ///
     /// # Errors
     ///
-    /// Returns `ParseError` if the input does not contain valid conditions.
-    fn parse_conditions(input: &str) -> ParseResult<Vec<Condition<'a>>> {
-        let mut input = input.to_owned();
-        let mut path = Path::default();
-        let mut conditions: Vec<Condition> = Vec::new();
+    /// * Returns `ParseError` if the input does not contain valid filters.
+    /// * Returns `ParseError::Custom` if a block or group is empty.
+    /// * Returns `ParseError::Custom` if a name is not followed by a block and
+    ///   is not `mode` or an operator.
+    fn parse_filters(
+        input: Input<'a>,
+        path: &Path<'a>,
+    ) -> ParseResult<'a, Vec<Filter<'a>>> {
+        let mut input = input;
+        let mut filters = Vec::new();
+        let mut mode = None;
 
         loop {
-            if let Ok((segment, new_input)) = (|input: &str| {
-                let (segment, input) = camel_case(input)?;
-                let (_, input) = spaces(&input)?;
-                let (_, input) = brace_open(&input)?;
-                let (_, input) = spaces(&input)?;
+            let Ok((segment, after_segment)) = camel_case(input) else {
+                if let Some(mode) = mode {
+                    Self::set_mode(&mut filters, path, mode);
+                }
 
-                Ok::<(String, String), ParseError>((segment, input))
-            })(&input)
-            {
-                path.push(segment);
+                return Ok((filters, input));
+            };
 
-                input = new_input;
+            let (_, after_segment) = spaces(after_segment)?;
 
-                continue;
-            }
+            if let Ok((_, block_input)) = brace_open(after_segment) {
+                let (_, block_input) = spaces(block_input)?;
+                let mut block_path = path.clone();
 
-            if let Ok((operator, argument, new_input)) = (|input: &str| {
-                let (operator, input) = Operator::parse(input)?;
-                let (_, input) = spaces(&input)?;
-                let (_, input) = colon(&input)?;
-                let (_, input) = spaces(&input)?;
-                let (_, input) = dollar(&input)?;
-                let (argument, input) = camel_case(&input)?;
-                let (_, input) = spaces(&input)?;
-
-                Ok::<(Operator, String, String), ParseError>((
-                    operator, argument, input,
-                ))
-            })(&input)
-            {
-                if path.is_empty() {
-                    return Err(ParseError::Custom {
-                        message: "A condition must refer to a field.".into(),
-                    });
+                if !matches!(segment, "and" | "not" | "or") {
+                    block_path.push(segment);
                 }
 
-                conditions.push(Condition {
-                    path: path.clone(),
-                    operator,
-                    argument_name: argument.into(),
-                });
+                let (block_filters, end) =
+                    Self::parse_filters(block_input, &block_path)?;
 
-                input = new_input;
+                if block_filters.is_empty() {
+                    return Err(ParseError::custom(
+                        format!(
+                            "Expected at least one condition in `{segment}`."
+                        ),
+                        input.span_to(end),
+                    ));
+                }
 
-                continue;
-            }
+                let (_, new_input) = brace_close(end)?;
+                let (_, new_input) = spaces(new_input)?;
+
+                match segment {
+                    "and" => filters.push(Filter::And(block_filters)),
+                    "not" => filters.push(Filter::Not(block_filters)),
+                    "or" => filters.push(Filter::Or(block_filters)),
+                    _ => filters.extend(block_filters),
+                }
+
+                input = new_input;
+            } else if segment == "mode" {
+                let (_, mode_input) = colon(after_segment)?;
+                let (_, mode_input) = spaces(mode_input)?;
+                let (block_mode, end) = Mode::parse(mode_input)?;
 
-            if !path.is_empty() {
-                if let Ok((_, new_input)) = (|input: &str| {
-                    let (_, input) = brace_close(input)?;
-                    let (_, input) = spaces(&input)?;
+                if path.is_empty() {
+                    return Err(ParseError::custom(
+                        "A mode must refer to a field.",
+                        input.span_to(end),
+                    ));
+                }
 
-                    Ok::<((), String), ParseError>(((), input))
-                })(&input)
-                {
-                    input = new_input;
+                let (_, new_input) = spaces(end)?;
 
-                    path.drop_back();
+                mode = Some(block_mode);
+                input = new_input;
+            } else {
+                let segment_end = input.advance(segment.len());
+
+                let Some((operator, _)) = Operator::parse(input)
+                    .ok()
+                    .filter(|(_, end)| *end == segment_end)
+                else {
+                    return Err(ParseError::custom(
+                        format!("Unknown operator `{segment}`."),
+                        input.span_to(segment_end),
+                    ));
+                };
+
+                let (_, rhs_input) = colon(after_segment)?;
+                let (_, rhs_input) = spaces(rhs_input)?;
+                let (rhs, end) = Operand::parse(rhs_input)?;
+                let span = input.span_to(end);
 
-                    continue;
+                if path.is_empty() {
+                    return Err(ParseError::custom(
+                        "A condition must refer to a field.",
+                        span,
+                    ));
                 }
-            }
 
-            return Ok((conditions, input));
+                filters.push(Filter::Condition(Condition {
+                    path: path.clone(),
+                    operator,
+                    rhs,
+                    mode: Mode::Default,
+                    span,
+                }));
+
+                let (_, new_input) = spaces(end)?;
+
+                input = new_input;
+            }
         }
     }
 
@@ -127,38 +208,39 @@ impl<'a> Where<'a> {
     ///
     /// Returns `ParseError` if the input does not start with a valid where
     /// clause.
-    pub fn parse(input: &str) -> ParseResult<Self> {
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
         let (_, input) = literal(input, "where")?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_open(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (name, input) = camel_case(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = brace_open(&input)?;
-        let (_, input) = spaces(&input)?;
-        let (conditions, input) = Self::parse_conditions(&input)?;
-        let (_, input) = spaces(&input)?;
-
-        let check_closing_brace = |input: &str, name: &str| {
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
+        let (name, input) = camel_case(input)?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = brace_open(input)?;
+        let (_, input) = spaces(input)?;
+        let (filters, input) = Self::parse_filters(input, &Path::default())?;
+        let (_, input) = spaces(input)?;
+
+        let check_closing_brace = |input: Input<'a>, name: &str| {
             match brace_close(input) {
                 Ok((_, input)) => Ok(((), input)),
                 _ => {
                     Err(ParseError::Custom {
                         message: format!("Expected closing brace for {name}."),
+                        span: input.span(),
                     })
                 }
             }
         };
 
         let (_, input) =
-            check_closing_brace(&input, &format!("root node `{name}`"))?;
-        let (_, input) = spaces(&input)?;
-        let (_, input) = check_closing_brace(&input, "where clause")?;
+            check_closing_brace(input, &format!("root node `{name}`"))?;
+        let (_, input) = spaces(input)?;
+        let (_, input) = check_closing_brace(input, "where clause")?;
 
         Ok((
             Self {
                 name: name.into(),
-                conditions,
+                filters,
             },
             input,
         ))
@@ -167,7 +249,10 @@ impl<'a> Where<'a> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        super::*,
+        parser::Span,
+    };
 
     #[test]
     fn test_parse_where() {
@@ -184,18 +269,22 @@ where {
         "
         .trim();
 
+        let input = Input::new(input);
+
         assert_eq!(
             Where::parse(input),
             Ok((
                 Where {
                     name: "foo".into(),
-                    conditions: vec![Condition {
+                    filters: vec![Filter::Condition(Condition {
                         path: Path::from_iter(["bar"]),
                         operator: Operator::Contains,
-                        argument_name: "foo".into(),
-                    }]
+                        rhs: Operand::Argument("foo".into()),
+                        mode: Mode::Default,
+                        span: Span::new(32, 46),
+                    })]
                 },
-                String::new()
+                input.advance(58)
             ))
         );
     }
@@ -218,25 +307,31 @@ where {
         "
         .trim();
 
+        let input = Input::new(input);
+
         assert_eq!(
             Where::parse(input),
             Ok((
                 Where {
                     name: "image".into(),
-                    conditions: vec![
-                        Condition {
+                    filters: vec![
+                        Filter::Condition(Condition {
                             path: Path::from_iter(["title"]),
                             operator: Operator::Equals,
-                            argument_name: "title".into(),
-                        },
-                        Condition {
+                            rhs: Operand::Argument("title".into()),
+                            mode: Mode::Default,
+                            span: Span::new(36, 50),
+                        }),
+                        Filter::Condition(Condition {
                             path: Path::from_iter(["title", "tags"]),
                             operator: Operator::Contains,
-                            argument_name: "tag".into(),
-                        }
+                            rhs: Operand::Argument("tag".into()),
+                            mode: Mode::Default,
+                            span: Span::new(72, 86),
+                        })
                     ]
                 },
-                String::new()
+                input.advance(106)
             ))
         );
     }
@@ -254,10 +349,13 @@ where {
         "
         .trim();
 
+        let input = Input::new(input);
+
         assert_eq!(
             Where::parse(input),
             Err(ParseError::Custom {
                 message: "Expected closing brace for root node `foo`.".into(),
+                span: Span::at(52),
             })
         );
     }
@@ -275,10 +373,13 @@ where {
         "
         .trim();
 
+        let input = Input::new(input);
+
         assert_eq!(
             Where::parse(input),
             Err(ParseError::Custom {
                 message: "A condition must refer to a field.".into(),
+                span: Span::new(20, 34),
             })
         );
     }
@@ -302,31 +403,287 @@ where {
             "
         .trim();
 
+        let input = Input::new(input);
+
         assert_eq!(
             Where::parse(input),
             Ok((
                 Where {
                     name: "foo".into(),
-                    conditions: vec![
-                        Condition {
+                    filters: vec![
+                        Filter::Condition(Condition {
                             path: Path::from_iter(["bar"]),
                             operator: Operator::Contains,
-                            argument_name: "baz".into(),
-                        },
-                        Condition {
+                            rhs: Operand::Argument("baz".into()),
+                            mode: Mode::Default,
+                            span: Span::new(32, 46),
+                        }),
+                        Filter::Condition(Condition {
                             path: Path::from_iter(["bar"]),
                             operator: Operator::Contains,
-                            argument_name: "bar".into(),
-                        },
-                        Condition {
+                            rhs: Operand::Argument("bar".into()),
+                            mode: Mode::Default,
+                            span: Span::new(53, 67),
+                        }),
+                        Filter::Condition(Condition {
                             path: Path::from_iter(["baz"]),
                             operator: Operator::Equals,
-                            argument_name: "baz".into(),
-                        }
+                            rhs: Operand::Argument("baz".into()),
+                            mode: Mode::Default,
+                            span: Span::new(90, 102),
+                        })
                     ]
                 },
-                String::new()
+                input.advance(114)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_parse_where_with_mode() {
+        let input = "
+
+where {
+  user {
+    name {
+      startsWith: $prefix
+      mode: insensitive
+    }
+    email {
+      endsWith: $domain
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let (r#where, _) = Where::parse(Input::new(input)).unwrap();
+
+        assert_eq!(
+            r#where
+                .conditions()
+                .into_iter()
+                .map(|condition| condition.mode)
+                .collect::<Vec<_>>(),
+            vec![Mode::Insensitive, Mode::Default]
+        );
+    }
+
+    #[test]
+    fn test_parse_where_with_stray_mode() {
+        let input = "
+
+where {
+  user {
+    mode: insensitive
+  }
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Where::parse(Input::new(input)),
+            Err(ParseError::Custom {
+                message: "A mode must refer to a field.".into(),
+                span: Span::new(21, 38),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_where_with_groups() {
+        let input = "
+
+where {
+  user {
+    or {
+      country {
+        equals: $country
+      }
+      not {
+        archived {
+          equals: $archived
+        }
+      }
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let input = Input::new(input);
+
+        assert_eq!(
+            Where::parse(input),
+            Ok((
+                Where {
+                    name: "user".into(),
+                    filters: vec![Filter::Or(vec![
+                        Filter::Condition(Condition {
+                            path: Path::from_iter(["country"]),
+                            operator: Operator::Equals,
+                            rhs: Operand::Argument("country".into()),
+                            mode: Mode::Default,
+                            span: Span::new(50, 66),
+                        }),
+                        Filter::Not(vec![Filter::Condition(Condition {
+                            path: Path::from_iter(["archived"]),
+                            operator: Operator::Equals,
+                            rhs: Operand::Argument("archived".into()),
+                            mode: Mode::Default,
+                            span: Span::new(116, 133),
+                        })]),
+                    ])],
+                },
+                input.advance(163)
             ))
         );
     }
+
+    #[test]
+    fn test_parse_where_with_group_in_field() {
+        let input = "
+
+where {
+  user {
+    name {
+      and {
+        startsWith: $prefix
+        endsWith: $suffix
+      }
+      mode: insensitive
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let (r#where, _) = Where::parse(Input::new(input)).unwrap();
+
+        assert!(matches!(
+            r#where.filters.as_slice(),
+            [Filter::And(filters)] if filters.len() == 2
+        ));
+
+        assert!(r#where.conditions().iter().all(|condition| {
+            condition.path == Path::from_iter(["name"])
+                && condition.mode == Mode::Insensitive
+        }));
+    }
+
+    #[test]
+    fn test_parse_where_with_empty_group() {
+        let input = "
+
+where {
+  user {
+    or {
+    }
+  }
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Where::parse(Input::new(input)),
+            Err(ParseError::Custom {
+                message: "Expected at least one condition in `or`.".into(),
+                span: Span::new(21, 30),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_where_with_empty_field_block() {
+        let input = "
+
+where {
+  post {
+    title {}
+  }
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Where::parse(Input::new(input)),
+            Err(ParseError::Custom {
+                message: "Expected at least one condition in `title`.".into(),
+                span: Span::new(21, 28),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_where_with_unknown_operator() {
+        let input = "
+
+where {
+  post {
+    title: $title
+  }
+}
+
+        "
+        .trim();
+
+        assert_eq!(
+            Where::parse(Input::new(input)),
+            Err(ParseError::Custom {
+                message: "Unknown operator `title`.".into(),
+                span: Span::new(21, 26),
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_where_with_literals() {
+        let input = "
+
+where {
+  post {
+    published {
+      equals: true
+    }
+    status {
+      notEquals: Archived
+    }
+    title {
+      startsWith: \"Re: \"
+    }
+    views {
+      gte: 100
+    }
+    deletedAt {
+      equals: null
+    }
+  }
+}
+
+        "
+        .trim();
+
+        let (r#where, _) = Where::parse(Input::new(input)).unwrap();
+
+        assert_eq!(
+            r#where
+                .conditions()
+                .into_iter()
+                .map(|condition| condition.rhs.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                Operand::Value(crate::Value::Boolean(true)),
+                Operand::Value(crate::Value::Identifier("Archived".into())),
+                Operand::Value(crate::Value::String("Re: ".into())),
+                Operand::Value(crate::Value::Int("100".into())),
+                Operand::Null,
+            ]
+        );
+    }
 }
@@ -1,15 +1,15 @@
 use {
     super::{
+        mode::Mode,
+        operand::Operand,
         operator::Operator,
         path::Path,
     },
-    std::{
-        borrow::Cow,
-        fmt::{
-            self,
-            Display,
-            Formatter,
-        },
+    parser::Span,
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
     },
 };
 
@@ -20,8 +20,14 @@ pub struct Condition<'a> {
     pub path: Path<'a>,
     /// The type of the condition.
     pub operator: Operator,
-    /// The right-hand side of the condition.
-    pub argument_name: Cow<'a, str>,
+    /// The right-hand side of the condition: an argument or a literal.
+    pub rhs: Operand<'a>,
+    /// How string values are compared, set by a `mode` line in the block of
+    /// the field.
+    pub mode: Mode,
+    /// The span of the condition, from the operator to the end of the
+    /// right-hand side.
+    pub span: Span,
 }
 
 impl Display for Condition<'_> {
@@ -32,10 +38,18 @@ impl Display for Condition<'_> {
         let Self {
             path,
             operator,
-            argument_name,
+            rhs,
+            mode,
+            ..
         } = self;
 
-        write!(f, "{path} {operator} ${argument_name}")
+        write!(f, "{path} {operator} {rhs}")?;
+
+        if *mode == Mode::Insensitive {
+            write!(f, " ({mode})")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -49,7 +63,9 @@ mod tests {
             Condition {
                 path: Path::from_iter(["foo", "bar"]),
                 operator: Operator::Contains,
-                argument_name: "baz".into(),
+                rhs: Operand::Argument("baz".into()),
+                mode: Mode::Default,
+                span: Span::new(0, 14),
             }
             .to_string(),
             "foo.bar contains $baz"
@@ -59,10 +75,36 @@ mod tests {
             Condition {
                 path: Path::from_iter(["foo", "bar", "baz"]),
                 operator: Operator::Equals,
-                argument_name: "baz".into(),
+                rhs: Operand::Argument("baz".into()),
+                mode: Mode::Default,
+                span: Span::new(0, 12),
             }
             .to_string(),
             "foo.bar.baz equals $baz"
         );
+
+        assert_eq!(
+            Condition {
+                path: Path::from_iter(["name"]),
+                operator: Operator::StartsWith,
+                rhs: Operand::Argument("prefix".into()),
+                mode: Mode::Insensitive,
+                span: Span::new(0, 20),
+            }
+            .to_string(),
+            "name startsWith $prefix (insensitive)"
+        );
+
+        assert_eq!(
+            Condition {
+                path: Path::from_iter(["status"]),
+                operator: Operator::Equals,
+                rhs: Operand::Value(crate::Value::Identifier("Active".into())),
+                mode: Mode::Default,
+                span: Span::new(0, 14),
+            }
+            .to_string(),
+            "status equals Active"
+        );
     }
 }
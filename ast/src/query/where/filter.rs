@@ -0,0 +1,124 @@
+use {
+    super::condition::Condition,
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// A tree of conditions.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Filter<'a> {
+    /// All of the filters must be met: `and { ... }`.
+    And(Vec<Self>),
+    /// A single condition.
+    Condition(Condition<'a>),
+    /// The filters must not all be met: `not { ... }`.
+    Not(Vec<Self>),
+    /// At least one of the filters must be met: `or { ... }`.
+    Or(Vec<Self>),
+}
+
+impl<'a> Filter<'a> {
+    /// The conditions in this filter, in source order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::query::{
+    ///         r#where::Filter,
+    ///         Condition,
+    ///         Mode,
+    ///         Operand,
+    ///         Operator,
+    ///         Path,
+    ///     },
+    ///     parser::Span,
+    /// };
+    ///
+    /// let condition = Condition {
+    ///     path: Path::from_iter(["title"]),
+    ///     operator: Operator::Equals,
+    ///     rhs: Operand::Argument("title".into()),
+    ///     mode: Mode::Default,
+    ///     span: Span::new(0, 14),
+    /// };
+    ///
+    /// let filter = Filter::Or(vec![Filter::Not(vec![Filter::Condition(
+    ///     condition.clone(),
+    /// )])]);
+    ///
+    /// assert_eq!(filter.conditions(), vec![&condition]);
+    /// ```
+    #[must_use]
+    pub fn conditions(&self) -> Vec<&Condition<'a>> {
+        match self {
+            Self::And(filters) | Self::Not(filters) | Self::Or(filters) => {
+                filters.iter().flat_map(Self::conditions).collect()
+            }
+            Self::Condition(condition) => vec![condition],
+        }
+    }
+}
+
+impl Display for Filter<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let (keyword, filters) = match self {
+            Self::And(filters) => ("and", filters),
+            Self::Condition(condition) => return write!(f, "{condition}"),
+            Self::Not(filters) => ("not", filters),
+            Self::Or(filters) => ("or", filters),
+        };
+
+        write!(
+            f,
+            "{keyword} {{ {} }}",
+            filters
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::query::r#where::{
+            Mode,
+            Operand,
+            Operator,
+            Path,
+        },
+        parser::Span,
+    };
+
+    #[test]
+    fn test_display() {
+        let condition = |name: &'static str| {
+            Filter::Condition(Condition {
+                path: Path::from_iter([name]),
+                operator: Operator::Equals,
+                rhs: Operand::Argument(name.into()),
+                mode: Mode::Default,
+                span: Span::new(0, 0),
+            })
+        };
+
+        assert_eq!(
+            Filter::Or(vec![
+                condition("country"),
+                Filter::Not(vec![condition("archived")]),
+            ])
+            .to_string(),
+            "or { country equals $country, not { archived equals $archived } }"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use {
+    parser::{
+        choice,
+        literal,
+        tag,
+        Input,
+        ParseResult,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// How string values are compared in a condition.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Mode {
+    /// Strings are compared as is.
+    #[default]
+    Default,
+    /// Strings are compared regardless of case.
+    Insensitive,
+}
+
+impl Display for Mode {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::Insensitive => write!(f, "insensitive"),
+        }
+    }
+}
+
+impl Mode {
+    /// Parse a mode from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::query::r#where::Mode,
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("insensitive");
+    ///
+    /// assert_eq!(
+    ///     Mode::parse(input),
+    ///     Ok((Mode::Insensitive, input.advance(11)))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'_>) -> ParseResult<'_, Self> {
+        choice(
+            input,
+            &[
+                tag!(literal!("default"), Self::Default),
+                tag!(literal!("insensitive"), Self::Insensitive),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Mode::Default.to_string(), "default");
+        assert_eq!(Mode::Insensitive.to_string(), "insensitive");
+    }
+
+    #[test]
+    fn test_parse_default() {
+        let input = Input::new("default");
+
+        assert_eq!(Mode::parse(input), Ok((Mode::Default, input.advance(7))));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(Mode::parse(Input::new("sensitive")).is_err());
+    }
+}
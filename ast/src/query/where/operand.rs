@@ -0,0 +1,149 @@
+use {
+    crate::Value,
+    parser::{
+        camel_case,
+        dollar,
+        Input,
+        ParseResult,
+    },
+    std::{
+        borrow::Cow,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+    },
+};
+
+/// The right-hand side of a condition.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Operand<'a> {
+    /// A reference to a query argument: `$name`.
+    Argument(Cow<'a, str>),
+    /// The absence of a value: `null`.
+    Null,
+    /// A literal value, such as `true`, `"foo"`, `1` or an enum value.
+    Value(Value<'a>),
+}
+
+impl Display for Operand<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Argument(name) => write!(f, "${name}"),
+            Self::Null => write!(f, "null"),
+            Self::Value(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl<'a> Operand<'a> {
+    /// Parse an operand from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with an argument,
+    /// `null` or a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::{
+    ///         query::r#where::Operand,
+    ///         Value,
+    ///     },
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("$title");
+    ///
+    /// assert_eq!(
+    ///     Operand::parse(input),
+    ///     Ok((Operand::Argument("title".into()), input.advance(6)))
+    /// );
+    ///
+    /// let input = Input::new("null");
+    ///
+    /// assert_eq!(Operand::parse(input), Ok((Operand::Null, input.advance(4))));
+    ///
+    /// let input = Input::new("Active");
+    ///
+    /// assert_eq!(
+    ///     Operand::parse(input),
+    ///     Ok((
+    ///         Operand::Value(Value::Identifier("Active".into())),
+    ///         input.advance(6)
+    ///     ))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        if let Ok((_, input)) = dollar(input) {
+            let (name, input) = camel_case(input)?;
+
+            return Ok((Self::Argument(name.into()), input));
+        }
+
+        let (value, input) = Value::parse(input)?;
+
+        match value {
+            Value::Identifier(name) if name == "null" => {
+                Ok((Self::Null, input))
+            }
+            value => Ok((Self::Value(value), input)),
+        }
+    }
+
+    /// The name of the argument, if the operand refers to one.
+    #[must_use]
+    pub const fn argument_name(&self) -> Option<&Cow<'a, str>> {
+        match self {
+            Self::Argument(name) => Some(name),
+            Self::Null | Self::Value(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Operand::Argument("foo".into()).to_string(), "$foo");
+        assert_eq!(Operand::Null.to_string(), "null");
+        assert_eq!(
+            Operand::Value(Value::String("foo".into())).to_string(),
+            "\"foo\""
+        );
+    }
+
+    #[test]
+    fn test_parse_literals() {
+        let input = Input::new("true");
+
+        assert_eq!(
+            Operand::parse(input),
+            Ok((Operand::Value(Value::Boolean(true)), input.advance(4)))
+        );
+
+        let input = Input::new("-3");
+
+        assert_eq!(
+            Operand::parse(input),
+            Ok((Operand::Value(Value::Int("-3".into())), input.advance(2)))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_argument() {
+        assert!(Operand::parse(Input::new("$")).is_err());
+    }
+}
@@ -3,6 +3,7 @@ use {
         choice,
         literal,
         tag,
+        Input,
         ParseResult,
     },
     std::fmt::{
@@ -17,8 +18,31 @@ use {
 pub enum Operator {
     /// The value of the field must contain the value of the argument.
     Contains,
+    /// The value of the field must end with the value of the argument.
+    EndsWith,
     /// The value of the field must equal the value of the argument.
     Equals,
+    /// The value of the field must be greater than the value of the argument.
+    Gt,
+    /// The value of the field must be greater than or equal to the value of
+    /// the argument.
+    Gte,
+    /// The value of the field must be one of the values of the argument.
+    In,
+    /// The field must be empty if the argument is `true`, and not empty if
+    /// it is `false`.
+    IsNull,
+    /// The value of the field must be less than the value of the argument.
+    Lt,
+    /// The value of the field must be less than or equal to the value of the
+    /// argument.
+    Lte,
+    /// The value of the field must not equal the value of the argument.
+    NotEquals,
+    /// The value of the field must not be one of the values of the argument.
+    NotIn,
+    /// The value of the field must start with the value of the argument.
+    StartsWith,
 }
 
 impl Display for Operator {
@@ -28,7 +52,17 @@ impl Display for Operator {
     ) -> fmt::Result {
         match self {
             Self::Contains => write!(f, "contains"),
+            Self::EndsWith => write!(f, "endsWith"),
             Self::Equals => write!(f, "equals"),
+            Self::Gt => write!(f, "gt"),
+            Self::Gte => write!(f, "gte"),
+            Self::In => write!(f, "in"),
+            Self::IsNull => write!(f, "isNull"),
+            Self::Lt => write!(f, "lt"),
+            Self::Lte => write!(f, "lte"),
+            Self::NotEquals => write!(f, "notEquals"),
+            Self::NotIn => write!(f, "notIn"),
+            Self::StartsWith => write!(f, "startsWith"),
         }
     }
 }
@@ -44,12 +78,23 @@ impl Operator {
     ///
     /// Returns a `ParseError` if the input does not start with a valid
     /// condition type.
-    pub fn parse(input: &str) -> ParseResult<Self> {
+    pub fn parse(input: Input<'_>) -> ParseResult<'_, Self> {
+        // Longer operators come first, so that `lte` is not parsed as `lt`.
         choice(
             input,
-            vec![
+            &[
                 tag!(literal!("contains"), Self::Contains),
+                tag!(literal!("endsWith"), Self::EndsWith),
                 tag!(literal!("equals"), Self::Equals),
+                tag!(literal!("gte"), Self::Gte),
+                tag!(literal!("gt"), Self::Gt),
+                tag!(literal!("isNull"), Self::IsNull),
+                tag!(literal!("in"), Self::In),
+                tag!(literal!("lte"), Self::Lte),
+                tag!(literal!("lt"), Self::Lt),
+                tag!(literal!("notEquals"), Self::NotEquals),
+                tag!(literal!("notIn"), Self::NotIn),
+                tag!(literal!("startsWith"), Self::StartsWith),
             ],
         )
     }
@@ -62,27 +107,67 @@ mod tests {
     #[test]
     fn test_display() {
         assert_eq!(Operator::Contains.to_string(), "contains");
+        assert_eq!(Operator::EndsWith.to_string(), "endsWith");
         assert_eq!(Operator::Equals.to_string(), "equals");
+        assert_eq!(Operator::Gt.to_string(), "gt");
+        assert_eq!(Operator::Gte.to_string(), "gte");
+        assert_eq!(Operator::In.to_string(), "in");
+        assert_eq!(Operator::IsNull.to_string(), "isNull");
+        assert_eq!(Operator::Lt.to_string(), "lt");
+        assert_eq!(Operator::Lte.to_string(), "lte");
+        assert_eq!(Operator::NotEquals.to_string(), "notEquals");
+        assert_eq!(Operator::NotIn.to_string(), "notIn");
+        assert_eq!(Operator::StartsWith.to_string(), "startsWith");
+    }
+
+    #[test]
+    fn test_parse_display_round_trip() {
+        for operator in [
+            Operator::Contains,
+            Operator::EndsWith,
+            Operator::Equals,
+            Operator::Gt,
+            Operator::Gte,
+            Operator::In,
+            Operator::IsNull,
+            Operator::Lt,
+            Operator::Lte,
+            Operator::NotEquals,
+            Operator::NotIn,
+            Operator::StartsWith,
+        ] {
+            let source = format!("{operator}: $foo");
+            let input = Input::new(&source);
+
+            assert_eq!(
+                Operator::parse(input),
+                Ok((operator, input.advance(operator.to_string().len())))
+            );
+        }
     }
 
     #[test]
     fn test_parse_contains() {
+        let input = Input::new("contains: $foo");
+
         assert_eq!(
-            Operator::parse("contains: $foo"),
-            Ok((Operator::Contains, ": $foo".to_owned()))
+            Operator::parse(input),
+            Ok((Operator::Contains, input.advance(8)))
         );
     }
 
     #[test]
     fn test_parse_equals() {
+        let input = Input::new("equals: $bar");
+
         assert_eq!(
-            Operator::parse("equals: $bar"),
-            Ok((Operator::Equals, ": $bar".to_owned()))
+            Operator::parse(input),
+            Ok((Operator::Equals, input.advance(6)))
         );
     }
 
     #[test]
     fn test_parse_error() {
-        assert!(Operator::parse("starts_with: $foo").is_err());
+        assert!(Operator::parse(Input::new("starts_with: $foo")).is_err());
     }
 }
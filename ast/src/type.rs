@@ -3,7 +3,8 @@ use {
     parser::{
         between,
         choice,
-        map,
+        question_mark,
+        Input,
         ParseResult,
     },
     std::fmt::{
@@ -16,11 +17,14 @@ use {
 /// A scalar type.
 pub mod scalar;
 
-/// A type: a scalar, a reference to a model or enum, or an array.
+/// A type: a scalar, a reference to a model or enum, an optional scalar or
+/// reference, or an array.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Type<'a> {
     /// An array of scalars.
     Array(Scalar<'a>),
+    /// An optional scalar, written with a trailing question mark.
+    Optional(Scalar<'a>),
     /// A basic type.
     Scalar(Scalar<'a>),
 }
@@ -32,13 +36,38 @@ impl Display for Type<'_> {
     ) -> fmt::Result {
         match self {
             Self::Array(scalar) => write!(f, "[{scalar}]"),
+            Self::Optional(scalar) => write!(f, "{scalar}?"),
             Self::Scalar(scalar) => write!(f, "{scalar}"),
         }
     }
 }
 
 impl<'a> Type<'a> {
-    /// Parse a scalar type from the given input.
+    /// The scalar type, regardless of whether the type is optional or an
+    /// array.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ast::r#type::{
+    ///     Scalar,
+    ///     Type,
+    /// };
+    ///
+    /// assert_eq!(Type::Optional(Scalar::Int).scalar(), &Scalar::Int);
+    /// assert_eq!(Type::Array(Scalar::Int).scalar(), &Scalar::Int);
+    /// ```
+    #[must_use]
+    pub const fn scalar(&self) -> &Scalar<'a> {
+        match self {
+            Self::Array(scalar)
+            | Self::Optional(scalar)
+            | Self::Scalar(scalar) => scalar,
+        }
+    }
+
+    /// Parse a scalar type, optionally followed by a question mark, from the
+    /// given input.
     ///
     /// # Arguments
     ///
@@ -48,8 +77,14 @@ impl<'a> Type<'a> {
     ///
     /// Returns a `ParseError` if the input does not start with a valid scalar
     /// type.
-    fn parse_scalar(input: &str) -> ParseResult<Self> {
-        map(input, Scalar::parse, Self::Scalar)
+    fn parse_scalar(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (scalar, input) = Scalar::parse(input)?;
+
+        if let Ok((_, input)) = question_mark(input) {
+            return Ok((Self::Optional(scalar), input));
+        }
+
+        Ok((Self::Scalar(scalar), input))
     }
 
     /// Parse an array type from the given input.
@@ -62,7 +97,7 @@ impl<'a> Type<'a> {
     ///
     /// Returns a `ParseError` if the input does not start with a valid array
     /// type.
-    fn parse_array(input: &str) -> ParseResult<Self> {
+    fn parse_array(input: Input<'a>) -> ParseResult<'a, Self> {
         let (scalar, input) = between(input, "[", Scalar::parse, "]")?;
 
         Ok((Self::Array(scalar), input))
@@ -77,8 +112,8 @@ impl<'a> Type<'a> {
     /// # Errors
     ///
     /// Returns a `ParseError` if the input does not start with a valid type.
-    pub fn parse(input: &str) -> ParseResult<Self> {
-        choice::<Self>(input, vec![Self::parse_scalar, Self::parse_array])
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        choice::<Self>(input, &[Self::parse_scalar, Self::parse_array])
     }
 }
 
@@ -88,113 +123,178 @@ mod tests {
 
     #[test]
     fn test_parse_boolean() {
+        let input = Input::new("Boolean");
+
         assert_eq!(
-            Type::parse("Boolean"),
-            Ok((Type::Scalar(Scalar::Boolean), String::new()))
+            Type::parse(input),
+            Ok((Type::Scalar(Scalar::Boolean), input.advance(7)))
         );
     }
 
     #[test]
     fn test_parse_date_time() {
+        let input = Input::new("DateTime");
+
         assert_eq!(
-            Type::parse("DateTime"),
-            Ok((Type::Scalar(Scalar::DateTime), String::new()))
+            Type::parse(input),
+            Ok((Type::Scalar(Scalar::DateTime), input.advance(8)))
         );
     }
 
     #[test]
     fn test_parse_float() {
+        let input = Input::new("Float");
+
         assert_eq!(
-            Type::parse("Float"),
-            Ok((Type::Scalar(Scalar::Float), String::new()))
+            Type::parse(input),
+            Ok((Type::Scalar(Scalar::Float), input.advance(5)))
         );
     }
 
     #[test]
     fn test_parse_int() {
+        let input = Input::new("Int");
+
         assert_eq!(
-            Type::parse("Int"),
-            Ok((Type::Scalar(Scalar::Int), String::new()))
+            Type::parse(input),
+            Ok((Type::Scalar(Scalar::Int), input.advance(3)))
         );
     }
 
     #[test]
     fn test_parse_string() {
+        let input = Input::new("String");
+
         assert_eq!(
-            Type::parse("String"),
-            Ok((Type::Scalar(Scalar::String), String::new()))
+            Type::parse(input),
+            Ok((Type::Scalar(Scalar::String), input.advance(6)))
         );
     }
 
     #[test]
     fn test_parse_reference() {
+        let input = Input::new("Foo");
+
         assert_eq!(
-            Type::parse("Foo"),
-            Ok((Type::Scalar(Scalar::Reference("Foo".into())), String::new()))
+            Type::parse(input),
+            Ok((
+                Type::Scalar(Scalar::Reference("Foo".into())),
+                input.advance(3)
+            ))
         );
     }
 
     #[test]
     fn test_parse_owned() {
+        let input = Input::new("@Foo");
+
         assert_eq!(
-            Type::parse("@Foo"),
-            Ok((Type::Scalar(Scalar::Owned("Foo".into())), String::new()))
+            Type::parse(input),
+            Ok((Type::Scalar(Scalar::Owned("Foo".into())), input.advance(4)))
+        );
+    }
+
+    #[test]
+    fn test_parse_optional_string() {
+        let input = Input::new("String?");
+
+        assert_eq!(
+            Type::parse(input),
+            Ok((Type::Optional(Scalar::String), input.advance(7)))
+        );
+    }
+
+    #[test]
+    fn test_parse_optional_owned() {
+        let input = Input::new("@Profile?");
+
+        assert_eq!(
+            Type::parse(input),
+            Ok((
+                Type::Optional(Scalar::Owned("Profile".into())),
+                input.advance(9)
+            ))
         );
     }
 
     #[test]
     fn test_parse_boolean_array() {
+        let input = Input::new("[Boolean]");
+
         assert_eq!(
-            Type::parse("[Boolean]"),
-            Ok((Type::Array(Scalar::Boolean), String::new()))
+            Type::parse(input),
+            Ok((Type::Array(Scalar::Boolean), input.advance(9)))
         );
     }
 
     #[test]
     fn test_parse_date_time_array() {
+        let input = Input::new("[DateTime]");
+
         assert_eq!(
-            Type::parse("[DateTime]"),
-            Ok((Type::Array(Scalar::DateTime), String::new()))
+            Type::parse(input),
+            Ok((Type::Array(Scalar::DateTime), input.advance(10)))
         );
     }
 
     #[test]
     fn test_parse_float_array() {
+        let input = Input::new("[Float]");
+
         assert_eq!(
-            Type::parse("[Float]"),
-            Ok((Type::Array(Scalar::Float), String::new()))
+            Type::parse(input),
+            Ok((Type::Array(Scalar::Float), input.advance(7)))
         );
     }
 
     #[test]
     fn test_parse_int_array() {
+        let input = Input::new("[Int]");
+
         assert_eq!(
-            Type::parse("[Int]"),
-            Ok((Type::Array(Scalar::Int), String::new()))
+            Type::parse(input),
+            Ok((Type::Array(Scalar::Int), input.advance(5)))
         );
     }
 
     #[test]
     fn test_parse_string_array() {
+        let input = Input::new("[String]");
+
         assert_eq!(
-            Type::parse("[String]"),
-            Ok((Type::Array(Scalar::String), String::new()))
+            Type::parse(input),
+            Ok((Type::Array(Scalar::String), input.advance(8)))
         );
     }
 
     #[test]
     fn test_parse_reference_array() {
+        let input = Input::new("[Foo]");
+
         assert_eq!(
-            Type::parse("[Foo]"),
-            Ok((Type::Array(Scalar::Reference("Foo".into())), String::new()))
+            Type::parse(input),
+            Ok((
+                Type::Array(Scalar::Reference("Foo".into())),
+                input.advance(5)
+            ))
         );
     }
 
     #[test]
     fn test_parse_owned_array() {
+        let input = Input::new("[@Foo]");
+
+        assert_eq!(
+            Type::parse(input),
+            Ok((Type::Array(Scalar::Owned("Foo".into())), input.advance(6)))
+        );
+    }
+
+    #[test]
+    fn test_display_optional() {
         assert_eq!(
-            Type::parse("[@Foo]"),
-            Ok((Type::Array(Scalar::Owned("Foo".into())), String::new()))
+            Type::Optional(Scalar::Reference("Country".into())).to_string(),
+            "Country?"
         );
     }
 
@@ -6,6 +6,7 @@ use {
         literal,
         map,
         tag,
+        Input,
         ParseError,
         ParseResult,
     },
@@ -22,20 +23,30 @@ use {
 /// Scalar types.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Scalar<'a> {
+    /// An integer too large for `Int`.
+    BigInt,
     /// A boolean.
     Boolean,
+    /// A sequence of bytes.
+    Bytes,
     /// A date and time.
     DateTime,
+    /// An exact decimal number.
+    Decimal,
     /// A 64-bit floating point number.
     Float,
     /// A 64-bit integer.
     Int,
+    /// A JSON value.
+    Json,
     /// A one-to-one or one-to-many reference.
     Owned(Cow<'a, str>),
     /// A many-to-one or many-to-many reference.
     Reference(Cow<'a, str>),
     /// A UTF-8 string.
     String,
+    /// A UUID.
+    Uuid,
 }
 
 impl<'a> Scalar<'a> {
@@ -49,19 +60,24 @@ impl<'a> Scalar<'a> {
     ///
     /// Returns `ParseError` if the input does not start with a valid scalar
     /// type.
-    pub fn parse(input: &str) -> ParseResult<Self> {
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
         choice::<Self>(
             input,
-            vec![
+            &[
+                tag!(literal!("BigInt"), Self::BigInt),
                 tag!(literal!("Boolean"), Self::Boolean),
+                tag!(literal!("Bytes"), Self::Bytes),
                 tag!(literal!("DateTime"), Self::DateTime),
+                tag!(literal!("Decimal"), Self::Decimal),
                 tag!(literal!("Float"), Self::Float),
                 tag!(literal!("Int"), Self::Int),
+                tag!(literal!("Json"), Self::Json),
                 tag!(literal!("String"), Self::String),
+                tag!(literal!("Uuid"), Self::Uuid),
                 map!(
                     |input| {
                         let (_, input) = at(input)?;
-                        let (name, input) = capitalized(&input)?;
+                        let (name, input) = capitalized(input)?;
 
                         Ok((name.into(), input))
                     },
@@ -72,8 +88,9 @@ impl<'a> Scalar<'a> {
         )
         .map_err(|_| {
             ParseError::custom(
-                "expected one of: Boolean, DateTime, Float, Int, String, \
-                 @<capitalized>, <capitalized>",
+                "expected one of: BigInt, Boolean, Bytes, DateTime, Decimal, \
+                 Float, Int, Json, String, Uuid, @<capitalized>, <capitalized>",
+                input.span(),
             )
         })
     }
@@ -85,13 +102,18 @@ impl Display for Scalar<'_> {
         f: &mut Formatter<'_>,
     ) -> fmt::Result {
         match self {
+            Self::BigInt => write!(f, "BigInt"),
             Self::Boolean => write!(f, "Boolean"),
+            Self::Bytes => write!(f, "Bytes"),
             Self::DateTime => write!(f, "DateTime"),
+            Self::Decimal => write!(f, "Decimal"),
             Self::Float => write!(f, "Float"),
             Self::Int => write!(f, "Int"),
+            Self::Json => write!(f, "Json"),
             Self::Owned(name) => write!(f, "@{name}"),
             Self::Reference(name) => write!(f, "{name}"),
             Self::String => write!(f, "String"),
+            Self::Uuid => write!(f, "Uuid"),
         }
     }
 }
@@ -100,7 +122,7 @@ impl Display for Scalar<'_> {
 mod tests {
     use {
         super::*,
-        parser::ParseError,
+        parser::Span,
     };
 
     #[test]
@@ -128,6 +150,15 @@ mod tests {
         assert_eq!(Scalar::String.to_string(), "String");
     }
 
+    #[test]
+    fn test_display_additional_scalars() {
+        assert_eq!(Scalar::BigInt.to_string(), "BigInt");
+        assert_eq!(Scalar::Bytes.to_string(), "Bytes");
+        assert_eq!(Scalar::Decimal.to_string(), "Decimal");
+        assert_eq!(Scalar::Json.to_string(), "Json");
+        assert_eq!(Scalar::Uuid.to_string(), "Uuid");
+    }
+
     #[test]
     fn test_display_reference() {
         assert_eq!(Scalar::Reference("Foo".into()).to_string(), "Foo");
@@ -140,61 +171,96 @@ mod tests {
 
     #[test]
     fn test_parse_boolean() {
+        let input = Input::new("Boolean");
+
         assert_eq!(
-            Scalar::parse("Boolean"),
-            Ok((Scalar::Boolean, String::new()))
+            Scalar::parse(input),
+            Ok((Scalar::Boolean, input.advance(7)))
         );
     }
 
     #[test]
     fn test_parse_date_time() {
+        let input = Input::new("DateTime");
+
         assert_eq!(
-            Scalar::parse("DateTime"),
-            Ok((Scalar::DateTime, String::new()))
+            Scalar::parse(input),
+            Ok((Scalar::DateTime, input.advance(8)))
         );
     }
 
     #[test]
     fn test_parse_float() {
-        assert_eq!(Scalar::parse("Float"), Ok((Scalar::Float, String::new())));
+        let input = Input::new("Float");
+
+        assert_eq!(Scalar::parse(input), Ok((Scalar::Float, input.advance(5))));
     }
 
     #[test]
     fn test_parse_int() {
-        assert_eq!(Scalar::parse("Int"), Ok((Scalar::Int, String::new())));
+        let input = Input::new("Int");
+
+        assert_eq!(Scalar::parse(input), Ok((Scalar::Int, input.advance(3))));
     }
 
     #[test]
     fn test_parse_string() {
+        let input = Input::new("String");
+
         assert_eq!(
-            Scalar::parse("String"),
-            Ok((Scalar::String, String::new()))
+            Scalar::parse(input),
+            Ok((Scalar::String, input.advance(6)))
         );
     }
 
+    #[test]
+    fn test_parse_additional_scalars() {
+        for (source, scalar) in [
+            ("BigInt", Scalar::BigInt),
+            ("Bytes", Scalar::Bytes),
+            ("Decimal", Scalar::Decimal),
+            ("Json", Scalar::Json),
+            ("Uuid", Scalar::Uuid),
+        ] {
+            let input = Input::new(source);
+
+            assert_eq!(
+                Scalar::parse(input),
+                Ok((scalar, input.advance(source.len())))
+            );
+        }
+    }
+
     #[test]
     fn test_parse_reference() {
+        let input = Input::new("Foo");
+
         assert_eq!(
-            Scalar::parse("Foo"),
-            Ok((Scalar::Reference("Foo".into()), String::new()))
+            Scalar::parse(input),
+            Ok((Scalar::Reference("Foo".into()), input.advance(3)))
         );
     }
 
     #[test]
     fn test_parse_owned() {
+        let input = Input::new("@Foo");
+
         assert_eq!(
-            Scalar::parse("@Foo"),
-            Ok((Scalar::Owned("Foo".into()), String::new()))
+            Scalar::parse(input),
+            Ok((Scalar::Owned("Foo".into()), input.advance(4)))
         );
     }
 
     #[test]
     fn test_parse_error() {
+        let input = Input::new("!FooBar");
+
         assert_eq!(
-            Scalar::parse("!FooBar"),
+            Scalar::parse(input),
             Err(ParseError::custom(
-                "expected one of: Boolean, DateTime, Float, Int, String, \
-                 @<capitalized>, <capitalized>",
+                "expected one of: BigInt, Boolean, Bytes, DateTime, Decimal, \
+                 Float, Int, Json, String, Uuid, @<capitalized>, <capitalized>",
+                Span::at(0),
             ))
         );
     }
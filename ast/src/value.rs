@@ -0,0 +1,256 @@
+use {
+    parser::{
+        alphabetics,
+        char,
+        chars_if,
+        literal,
+        Input,
+        ParseError,
+        ParseResult,
+    },
+    std::{
+        borrow::Cow,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+    },
+};
+
+/// A literal value, for example the default value of a field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Value<'a> {
+    /// A boolean: `true` or `false`.
+    Boolean(bool),
+    /// A floating point number, as written in the source.
+    Float(Cow<'a, str>),
+    /// A call to a function without arguments, such as `now()`.
+    Function(Cow<'a, str>),
+    /// An identifier, such as an enum value.
+    Identifier(Cow<'a, str>),
+    /// An integer, as written in the source.
+    Int(Cow<'a, str>),
+    /// A string, without the surrounding quotes. Escape sequences are kept
+    /// as written.
+    String(Cow<'a, str>),
+}
+
+impl Display for Value<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Boolean(value) => write!(f, "{value}"),
+            Self::Float(value) | Self::Identifier(value) | Self::Int(value) => {
+                write!(f, "{value}")
+            }
+            Self::Function(name) => write!(f, "{name}()"),
+            Self::String(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
+
+impl<'a> Value<'a> {
+    /// Parse a number from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a number.
+    fn parse_number(input: Input<'a>) -> ParseResult<'a, Self> {
+        let start = input;
+        let input = char(input, '-').map_or(input, |(_, input)| input);
+        let (_, input) =
+            chars_if(input, |char| char.is_ascii_digit(), "Expected digit.")?;
+
+        if let Ok((_, fraction)) = char(input, '.') {
+            let (_, input) = chars_if(
+                fraction,
+                |char| char.is_ascii_digit(),
+                "Expected digit.",
+            )?;
+
+            return Ok((Self::Float(start.slice_to(input).into()), input));
+        }
+
+        Ok((Self::Int(start.slice_to(input).into()), input))
+    }
+
+    /// Parse a string from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `ParseError` if the input does not start with a double
+    ///   quote.
+    /// * Returns `ParseError::Custom` if the string is not closed on the same
+    ///   line.
+    fn parse_string(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (_, body) = char(input, '"')?;
+        let mut end = body;
+
+        loop {
+            match end.peek() {
+                Some('"') => break,
+                Some('\\') => end = end.advance(1),
+                Some('\n') | None => {
+                    return Err(ParseError::custom(
+                        "Unterminated string.",
+                        input.span_to(end),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            end = end.advance(end.peek().map_or(0, char::len_utf8));
+        }
+
+        Ok((Self::String(body.slice_to(end).into()), end.advance(1)))
+    }
+
+    /// Parse a boolean, function call or identifier from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with an alphabetic
+    /// character.
+    fn parse_word(input: Input<'a>) -> ParseResult<'a, Self> {
+        let (word, input) = alphabetics(input)?;
+
+        match word {
+            "true" => Ok((Self::Boolean(true), input)),
+            "false" => Ok((Self::Boolean(false), input)),
+            _ => {
+                if let Ok((_, input)) = literal(input, "()") {
+                    return Ok((Self::Function(word.into()), input));
+                }
+
+                Ok((Self::Identifier(word.into()), input))
+            }
+        }
+    }
+
+    /// Parse a value from the given input.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The input to parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ParseError` if the input does not start with a valid value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     ast::Value,
+    ///     parser::Input,
+    /// };
+    ///
+    /// let input = Input::new("-1.5");
+    ///
+    /// assert_eq!(
+    ///     Value::parse(input),
+    ///     Ok((Value::Float("-1.5".into()), input.advance(4)))
+    /// );
+    ///
+    /// let input = Input::new("\"Untitled\"");
+    ///
+    /// assert_eq!(
+    ///     Value::parse(input),
+    ///     Ok((Value::String("Untitled".into()), input.advance(10)))
+    /// );
+    ///
+    /// let input = Input::new("now()");
+    ///
+    /// assert_eq!(
+    ///     Value::parse(input),
+    ///     Ok((Value::Function("now".into()), input.advance(5)))
+    /// );
+    /// ```
+    pub fn parse(input: Input<'a>) -> ParseResult<'a, Self> {
+        match input.peek() {
+            Some('"') => Self::parse_string(input),
+            Some(char) if char == '-' || char.is_ascii_digit() => {
+                Self::parse_number(input)
+            }
+            _ => Self::parse_word(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        parser::Span,
+    };
+
+    #[test]
+    fn test_parse_int() {
+        let input = Input::new("42 ");
+
+        assert_eq!(
+            Value::parse(input),
+            Ok((Value::Int("42".into()), input.advance(2)))
+        );
+    }
+
+    #[test]
+    fn test_parse_boolean() {
+        let input = Input::new("false");
+
+        assert_eq!(
+            Value::parse(input),
+            Ok((Value::Boolean(false), input.advance(5)))
+        );
+    }
+
+    #[test]
+    fn test_parse_identifier() {
+        let input = Input::new("Photo)");
+
+        assert_eq!(
+            Value::parse(input),
+            Ok((Value::Identifier("Photo".into()), input.advance(5)))
+        );
+    }
+
+    #[test]
+    fn test_parse_escaped_string() {
+        let input = Input::new(r#""a \"b\"""#);
+
+        assert_eq!(
+            Value::parse(input),
+            Ok((Value::String(r#"a \"b\""#.into()), input.advance(9)))
+        );
+    }
+
+    #[test]
+    fn test_parse_unterminated_string() {
+        assert_eq!(
+            Value::parse(Input::new("\"abc\n\"")),
+            Err(ParseError::custom("Unterminated string.", Span::new(0, 4)))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Value::String("a".into()).to_string(), "\"a\"");
+        assert_eq!(Value::Function("now".into()).to_string(), "now()");
+        assert_eq!(Value::Float("1.5".into()).to_string(), "1.5");
+    }
+}
@@ -1,6 +1,7 @@
 use {
     ast::Ast,
     ir::Ir,
+    parser::Location,
     print::Print,
     prisma,
     std::{
@@ -27,6 +28,58 @@ const PRISMA_FILE_EXTENSION: &str = "prisma";
 /// The file extension for TypeScript declaration files.
 const TYPESCRIPT_DECLARATION_FILE_EXTENSION: &str = "d.ts";
 
+/// Parse a source file, reporting every parse error.
+///
+/// # Arguments
+///
+/// * `input` - The contents of the input file.
+///
+/// # Errors
+///
+/// Returns an error listing the location and message of every declaration
+/// that could not be parsed.
+fn parse(input: &str) -> Result<Ast<'_>, String> {
+    let (ast, errors) = Ast::parse_with_recovery(input);
+
+    if errors.is_empty() {
+        return Ok(ast);
+    }
+
+    Err(errors
+        .iter()
+        .map(|error| {
+            let location = Location::from_offset(input, error.span().start);
+
+            format!("Could not parse input file at {location}. {error}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Generate the intermediate representation of an AST, reporting every type
+/// error.
+///
+/// # Arguments
+///
+/// * `ast` - The AST to type check.
+///
+/// # Errors
+///
+/// Returns an error listing every type error in the AST.
+fn type_check(ast: Ast<'_>) -> Result<Ir<'_>, String> {
+    Ir::try_from(ast).map_err(|errors| {
+        errors
+            .iter()
+            .map(|error| {
+                format!(
+                    "Could not generate intermediate representation. {error}"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
 /// Check a source file for errors.
 ///
 /// # Arguments
@@ -43,12 +96,9 @@ where
     let input = read_to_string(input)
         .map_err(|error| format!("Could not read input file. {error}"))?;
 
-    let (ast, _) = Ast::parse(&input)
-        .map_err(|error| format!("Could not parse input file. {error}"))?;
+    let ast = parse(&input)?;
 
-    let _ir = Ir::try_from(ast).map_err(|error| {
-        format!("Could not generate intermediate representation. {error}")
-    })?;
+    let _ir = type_check(ast)?;
 
     Ok(())
 }
@@ -157,6 +207,32 @@ where
             })?;
     }
 
+    for aggregate in ast.aggregates.values() {
+        typescript::Interface::aggregate_input(aggregate)
+            .into_iter()
+            .chain(Some(typescript::Interface::from(aggregate.clone())))
+            .try_for_each(|interface| interface.print(0, &mut file))
+            .map_err(|error| {
+                format!(
+                    "Could not write typescript interface for aggregate `{}`. \
+                     {error}",
+                    aggregate.name
+                )
+            })?;
+    }
+
+    for mutation in ast.mutations.values() {
+        typescript::Interface::from(mutation.clone())
+            .print(0, &mut file)
+            .map_err(|error| {
+                format!(
+                    "Could not write typescript interface for mutation `{}`. \
+                     {error}",
+                    mutation.name
+                )
+            })?;
+    }
+
     Ok(())
 }
 
@@ -182,12 +258,9 @@ where
     let input = read_to_string(input)
         .map_err(|error| format!("Could not read input file. {error}"))?;
 
-    let (ast, _) = Ast::parse(&input)
-        .map_err(|error| format!("Could not parse input file. {error}"))?;
+    let ast = parse(&input)?;
 
-    let ir = Ir::try_from(ast).map_err(|error| {
-        format!("Could not generate intermediate representation. {error}")
-    })?;
+    let ir = type_check(ast)?;
 
     generate_typescript(&ir, &output)?;
     generate_prisma(ir, &output)?;
@@ -1,5 +1,7 @@
 use {
+    crate::print_description,
     ir,
+    ord_str_map::OrdStrMap,
     print::Print,
     std::{
         borrow::Cow,
@@ -15,6 +17,10 @@ pub struct Enum<'a> {
     pub name: Cow<'a, str>,
     /// The values of the enum.
     pub values: TokenSet,
+    /// The description of the enum.
+    pub doc: Option<Cow<'a, str>>,
+    /// The descriptions of the values of the enum, by value.
+    pub value_docs: OrdStrMap<Cow<'a, str>>,
 }
 
 impl Print for Enum<'_> {
@@ -25,13 +31,27 @@ impl Print for Enum<'_> {
         level: usize,
         f: &mut dyn io::Write,
     ) -> io::Result<()> {
-        let Self { name, values } = self;
+        let Self {
+            name,
+            values,
+            doc,
+            value_docs,
+        } = self;
+
         let indent_outer = Self::indent(level);
         let indent_inner = Self::indent(level + 1);
 
+        if let Some(doc) = doc {
+            print_description(doc, &indent_outer, f)?;
+        }
+
         writeln!(f, "{indent_outer}enum {name} {{")?;
 
         for value in values.iter() {
+            if let Some(doc) = value_docs.get(value) {
+                print_description(doc, &indent_inner, f)?;
+            }
+
             writeln!(f, "{indent_inner}{value}")?;
         }
 
@@ -44,6 +64,8 @@ impl<'a> From<ir::Enum<'a>> for Enum<'a> {
         let mut r#enum = Self {
             name: ir_enum.name.clone(),
             values: TokenSet::new(),
+            doc: ir_enum.doc,
+            value_docs: ir_enum.value_docs,
         };
 
         for value in ir_enum.values.iter() {
@@ -63,6 +85,8 @@ mod tests {
         let r#enum = Enum {
             name: "Test".into(),
             values: TokenSet::from_iter(["A", "B"]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         let mut f = Vec::new();
@@ -84,6 +108,8 @@ mod tests {
         let r#enum = Enum {
             name: "Test".into(),
             values: TokenSet::from_iter(["A", "B"]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         let mut f = Vec::new();
@@ -101,16 +127,52 @@ enum Test {
         );
     }
 
+    #[test]
+    fn test_print_descriptions() {
+        let r#enum = Enum {
+            name: "DrivingSide".into(),
+            values: TokenSet::from_iter(["Left", "Right"]),
+            doc: Some("The side of the road.\n\nDiffers per country.".into()),
+            value_docs: OrdStrMap::from_iter([(
+                "Left",
+                "As in the United Kingdom.".into(),
+            )]),
+        };
+
+        let mut f = Vec::new();
+
+        r#enum.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "\"\"\"
+The side of the road.
+
+Differs per country.
+\"\"\"
+enum DrivingSide {
+  \"\"\"As in the United Kingdom.\"\"\"
+  Left
+  Right
+}
+"
+        );
+    }
+
     #[test]
     fn test_from() {
         assert_eq!(
             Enum::from(ir::Enum {
                 name: "Test".into(),
-                values: TokenSet::from_iter(["A", "B"])
+                values: TokenSet::from_iter(["A", "B"]),
+                doc: None,
+                value_docs: OrdStrMap::new(),
             }),
             Enum {
                 name: "Test".into(),
-                values: TokenSet::from_iter(["A", "B"])
+                values: TokenSet::from_iter(["A", "B"]),
+                doc: None,
+                value_docs: OrdStrMap::new(),
             }
         );
     }
@@ -46,8 +46,12 @@ pub mod field;
 pub mod fragment_spread;
 /// Inline fragments.
 pub mod inline_fragment;
+/// Mutations.
+pub mod mutation;
 /// Queries.
 pub mod query;
+/// Scalars.
+pub mod scalar;
 /// Selections.
 pub mod selection;
 /// Types.
@@ -58,6 +62,7 @@ pub mod value;
 /// Tab size.
 pub const TAB_SIZE: usize = 2;
 
+use std::io;
 pub use {
     argument::Argument,
     const_argument::ConstArgument,
@@ -66,12 +71,14 @@ pub use {
     field::Field,
     fragment_spread::FragmentSpread,
     inline_fragment::InlineFragment,
+    mutation::Mutation,
     query::{
         Query,
         Variable,
     },
     r#enum::Enum,
     r#type::Type,
+    scalar::Scalar,
     selection::Selection,
     value::{
         Const,
@@ -80,3 +87,39 @@ pub use {
         Value,
     },
 };
+
+/// Print documentation as a GraphQL description block string. Single-line
+/// documentation is printed on one line.
+///
+/// # Arguments
+///
+/// * `doc` - The documentation to print.
+/// * `indent` - The indentation of the description.
+/// * `f` - The writer to print to.
+///
+/// # Errors
+///
+/// If an error occurs while writing to the stream.
+pub fn print_description(
+    doc: &str,
+    indent: &str,
+    f: &mut dyn io::Write,
+) -> io::Result<()> {
+    let doc = doc.replace(r#"""""#, r#"\""""#);
+
+    if !doc.contains('\n') {
+        return writeln!(f, r#"{indent}"""{doc}""""#);
+    }
+
+    writeln!(f, r#"{indent}""""#)?;
+
+    for line in doc.lines() {
+        if line.is_empty() {
+            writeln!(f)?;
+        } else {
+            writeln!(f, "{indent}{line}")?;
+        }
+    }
+
+    writeln!(f, r#"{indent}""""#)
+}
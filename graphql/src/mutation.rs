@@ -0,0 +1,204 @@
+use {
+    super::{
+        Argument,
+        Directive,
+        Field,
+        Selection,
+        Value,
+        Variable,
+    },
+    print::{
+        Print,
+        PrintInline,
+    },
+    std::{
+        borrow::Cow,
+        io,
+    },
+};
+
+/// A GraphQL mutation.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Mutation<'a> {
+    /// The name of the mutation.
+    pub name: Cow<'a, str>,
+    /// The directives of the mutation.
+    pub directives: Vec<Directive<'a>>,
+    /// The selection set of the mutation.
+    pub selections: Vec<Selection<'a>>,
+    /// The variables of the mutation.
+    pub variables: Vec<Variable<'a>>,
+}
+
+impl<'a> Mutation<'a> {
+    /// Create a mutation from an IR mutation. The mutation passes its
+    /// variables to the field of the same name, and selects the id fields of
+    /// the mutated model.
+    ///
+    /// # Arguments
+    ///
+    /// * `ir_mutation` - The IR mutation.
+    /// * `ir_model` - The mutated model.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     graphql::Mutation,
+    ///     print::Print,
+    /// };
+    ///
+    /// let model = ir::Model::new("Post");
+    ///
+    /// let ir_mutation = ir::Mutation::new(
+    ///     "deletePosts",
+    ///     "Post",
+    ///     ir::mutation::Operation::Delete,
+    /// );
+    ///
+    /// let mut f = Vec::new();
+    ///
+    /// Mutation::from_ir(ir_mutation, &model)
+    ///     .print(0, &mut f)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(f).unwrap(),
+    ///     "mutation deletePosts {
+    ///   deletePosts {
+    ///     id
+    ///   }
+    /// }
+    /// "
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_ir(
+        ir_mutation: ir::Mutation<'a>,
+        ir_model: &ir::Model<'a>,
+    ) -> Self {
+        let arguments = ir_mutation
+            .arguments
+            .iter()
+            .map(|(name, _)| {
+                Argument {
+                    name: name.to_owned().into(),
+                    value: Value::Variable(name.to_owned().into()),
+                }
+            })
+            .collect();
+
+        let selections = ir_model
+            .id_fields()
+            .into_iter()
+            .map(|(name, _)| {
+                Selection::Field(Field {
+                    name,
+                    arguments: vec![],
+                    directives: vec![],
+                    selections: vec![],
+                })
+            })
+            .collect();
+
+        Self {
+            name: ir_mutation.name.clone(),
+            directives: vec![],
+            selections: vec![Selection::Field(Field {
+                name: ir_mutation.name,
+                arguments,
+                directives: vec![],
+                selections,
+            })],
+            variables: ir_mutation
+                .arguments
+                .into_values()
+                .map(Variable::from)
+                .collect(),
+        }
+    }
+}
+
+impl Print for Mutation<'_> {
+    const TAB_SIZE: usize = crate::TAB_SIZE;
+
+    fn print(
+        &self,
+        level: usize,
+        f: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        write!(f, "mutation {}", self.name)?;
+
+        if !self.variables.is_empty() {
+            write!(f, "(")?;
+            PrintInline::intercalate(self.variables.clone(), f, ", ")?;
+            write!(f, ")")?;
+        }
+
+        for directive in &self.directives {
+            directive.print(f)?;
+        }
+
+        if !self.selections.is_empty() {
+            Selection::print_multiple(self.selections.iter(), level, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ir() {
+        let mut model = ir::Model::new("Post");
+
+        model.id = ir::model::Id::Field("slug".into());
+
+        model
+            .insert_field(ir::model::Field {
+                name: "slug".into(),
+                r#type: ir::Type::String,
+                cardinality: ir::Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        let mut mutation = ir::Mutation::new(
+            "createPost",
+            "Post",
+            ir::mutation::Operation::Create(vec![]),
+        );
+
+        for (name, r#type) in
+            [("slug", ir::Type::String), ("authorId", ir::Type::Int)]
+        {
+            let _: Option<ir::query::Argument<'_>> = mutation.arguments.insert(
+                name,
+                ir::query::Argument {
+                    name: name.into(),
+                    r#type: ir::query::ArgumentType::Type(r#type),
+                    cardinality: ir::Cardinality::One,
+                },
+            );
+        }
+
+        let mut f = Vec::new();
+
+        Mutation::from_ir(mutation, &model)
+            .print(0, &mut f)
+            .unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "mutation createPost($slug: String!, $authorId: Int!) {
+  createPost(slug: $slug, authorId: $authorId) {
+    slug
+  }
+}
+"
+        );
+    }
+}
@@ -24,6 +24,17 @@ pub struct Variable<'a> {
     pub directives: Vec<ConstDirective<'a>>,
 }
 
+impl<'a> From<ir::query::Argument<'a>> for Variable<'a> {
+    fn from(argument: ir::query::Argument<'a>) -> Self {
+        Self {
+            name: argument.name.clone(),
+            r#type: argument.into(),
+            default_value: None,
+            directives: Vec::new(),
+        }
+    }
+}
+
 impl PrintInline for Variable<'_> {
     fn print(
         &self,
@@ -67,6 +78,21 @@ mod tests {
         assert_eq!(String::from_utf8(f).unwrap(), "$foo: String");
     }
 
+    #[test]
+    fn test_from_ir_optional_argument() {
+        let variable = Variable::from(ir::query::Argument {
+            name: "kind".into(),
+            r#type: ir::query::ArgumentType::Enum("Kind".into()),
+            cardinality: ir::Cardinality::Optional,
+        });
+
+        let mut f = Vec::new();
+
+        variable.print(&mut f).unwrap();
+
+        assert_eq!(String::from_utf8(f).unwrap(), "$kind: Kind");
+    }
+
     #[test]
     fn test_print_with_default() {
         let variable = Variable {
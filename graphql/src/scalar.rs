@@ -0,0 +1,172 @@
+use {
+    crate::print_description,
+    ir,
+    print::Print,
+    std::{
+        borrow::Cow,
+        io,
+    },
+};
+
+/// A GraphQL custom scalar declaration.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Scalar<'a> {
+    /// The name of the scalar.
+    pub name: Cow<'a, str>,
+    /// The description of the scalar.
+    pub doc: Option<Cow<'a, str>>,
+}
+
+impl Scalar<'_> {
+    /// The GraphQL name of a scalar type.
+    ///
+    /// # Arguments
+    ///
+    /// * `r#type` - The scalar type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graphql::Scalar;
+    ///
+    /// assert_eq!(Scalar::name(ir::Type::Int), "Int");
+    /// assert_eq!(Scalar::name(ir::Type::Json), "JSON");
+    /// ```
+    #[must_use]
+    pub const fn name(r#type: ir::Type) -> &'static str {
+        match r#type {
+            ir::Type::BigInt => "BigInt",
+            ir::Type::Boolean => "Boolean",
+            ir::Type::Bytes => "Bytes",
+            ir::Type::DateTime => "DateTime",
+            ir::Type::Decimal => "Decimal",
+            ir::Type::Float => "Float",
+            ir::Type::Int => "Int",
+            ir::Type::Json => "JSON",
+            ir::Type::String => "String",
+            ir::Type::Uuid => "UUID",
+        }
+    }
+
+    /// The custom scalar declaration a schema needs to use the given type.
+    /// Returns `None` for types that map to a built-in GraphQL scalar.
+    ///
+    /// # Arguments
+    ///
+    /// * `r#type` - The scalar type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use graphql::Scalar;
+    ///
+    /// assert_eq!(Scalar::from_ir_type(ir::Type::String), None);
+    ///
+    /// assert_eq!(
+    ///     Scalar::from_ir_type(ir::Type::Decimal),
+    ///     Some(Scalar {
+    ///         name: "Decimal".into(),
+    ///         doc: None,
+    ///     })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_ir_type(r#type: ir::Type) -> Option<Self> {
+        match r#type {
+            ir::Type::Boolean
+            | ir::Type::Float
+            | ir::Type::Int
+            | ir::Type::String => None,
+            _ => {
+                Some(Self {
+                    name: Self::name(r#type).into(),
+                    doc: None,
+                })
+            }
+        }
+    }
+}
+
+impl Print for Scalar<'_> {
+    const TAB_SIZE: usize = crate::TAB_SIZE;
+
+    fn print(
+        &self,
+        level: usize,
+        f: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        let Self { name, doc } = self;
+        let indent = Self::indent(level);
+
+        if let Some(doc) = doc {
+            print_description(doc, &indent, f)?;
+        }
+
+        writeln!(f, "{indent}scalar {name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print() {
+        let mut f = Vec::new();
+
+        Scalar {
+            name: "BigInt".into(),
+            doc: None,
+        }
+        .print(0, &mut f)
+        .unwrap();
+
+        assert_eq!(String::from_utf8(f).unwrap(), "scalar BigInt\n");
+    }
+
+    #[test]
+    fn test_print_description() {
+        let mut f = Vec::new();
+
+        Scalar {
+            name: "UUID".into(),
+            doc: Some("A universally unique identifier.".into()),
+        }
+        .print(0, &mut f)
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "\"\"\"A universally unique identifier.\"\"\"\nscalar UUID\n"
+        );
+    }
+
+    #[test]
+    fn test_from_ir_type() {
+        for r#type in [
+            ir::Type::Boolean,
+            ir::Type::Float,
+            ir::Type::Int,
+            ir::Type::String,
+        ] {
+            assert_eq!(Scalar::from_ir_type(r#type), None);
+        }
+
+        for (r#type, name) in [
+            (ir::Type::BigInt, "BigInt"),
+            (ir::Type::Bytes, "Bytes"),
+            (ir::Type::DateTime, "DateTime"),
+            (ir::Type::Decimal, "Decimal"),
+            (ir::Type::Json, "JSON"),
+            (ir::Type::Uuid, "UUID"),
+        ] {
+            assert_eq!(
+                Scalar::from_ir_type(r#type),
+                Some(Scalar {
+                    name: name.into(),
+                    doc: None,
+                })
+            );
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use {
+    crate::Scalar,
     print::PrintInline,
     std::{
         borrow::Cow,
@@ -17,6 +18,55 @@ pub enum Type<'a> {
     Name(Cow<'a, str>),
 }
 
+impl<'a> Type<'a> {
+    /// Create a type from a type name and a cardinality. Only optional types
+    /// are nullable; lists are non-null lists of non-null items.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the type.
+    /// * `cardinality` - The cardinality of the type.
+    #[must_use]
+    pub fn from_cardinality<S>(
+        name: S,
+        cardinality: ir::Cardinality,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let name = Self::Name(name.into());
+
+        match cardinality {
+            ir::Cardinality::One => Self::NonNull(Box::new(name)),
+            ir::Cardinality::Optional => name,
+            ir::Cardinality::Many => {
+                Self::NonNull(Box::new(Self::List(Box::new(Self::NonNull(
+                    Box::new(name),
+                )))))
+            }
+        }
+    }
+}
+
+impl<'a> From<ir::query::Argument<'a>> for Type<'a> {
+    fn from(
+        ir::query::Argument {
+            r#type,
+            cardinality,
+            ..
+        }: ir::query::Argument<'a>
+    ) -> Self {
+        let name: Cow<'a, str> = match r#type {
+            ir::query::ArgumentType::Enum(name) => name,
+            ir::query::ArgumentType::Type(r#type) => {
+                Scalar::name(r#type).into()
+            }
+        };
+
+        Self::from_cardinality(name, cardinality)
+    }
+}
+
 impl PrintInline for Type<'_> {
     fn print(
         &self,
@@ -61,6 +111,42 @@ mod tests {
         assert_eq!(String::from_utf8(f).unwrap(), "[String]");
     }
 
+    #[test]
+    fn test_from_ir_argument() {
+        let print = |cardinality| {
+            let mut f = Vec::new();
+
+            Type::from(ir::query::Argument {
+                name: "title".into(),
+                r#type: ir::query::ArgumentType::Type(ir::Type::String),
+                cardinality,
+            })
+            .print(&mut f)
+            .unwrap();
+
+            String::from_utf8(f).unwrap()
+        };
+
+        assert_eq!(print(ir::Cardinality::One), "String!");
+        assert_eq!(print(ir::Cardinality::Optional), "String");
+        assert_eq!(print(ir::Cardinality::Many), "[String!]!");
+    }
+
+    #[test]
+    fn test_from_ir_argument_custom_scalar() {
+        let mut f = Vec::new();
+
+        Type::from(ir::query::Argument {
+            name: "id".into(),
+            r#type: ir::query::ArgumentType::Type(ir::Type::Uuid),
+            cardinality: ir::Cardinality::One,
+        })
+        .print(&mut f)
+        .unwrap();
+
+        assert_eq!(String::from_utf8(f).unwrap(), "UUID!");
+    }
+
     #[test]
     fn test_print_name() {
         let type_ = Type::Name("String".into());
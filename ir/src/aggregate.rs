@@ -0,0 +1,161 @@
+pub use self::function::Function;
+use {
+    crate::{
+        query::{
+            Argument,
+            ArgumentType,
+            Where,
+        },
+        Cardinality,
+        Type,
+    },
+    ord_str_map::OrdStrMap,
+    std::borrow::Cow,
+};
+
+/// Aggregate functions.
+pub mod function;
+
+/// A function applied to a field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Aggregation<'a> {
+    /// The function.
+    pub function: Function,
+    /// The name of the field.
+    pub field_name: Cow<'a, str>,
+    /// The type of the result.
+    pub r#type: Type,
+}
+
+/// A field by which records are grouped.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct GroupBy<'a> {
+    /// The name of the field.
+    pub field_name: Cow<'a, str>,
+    /// The type of the field.
+    pub r#type: ArgumentType<'a>,
+    /// The cardinality of the field.
+    pub cardinality: Cardinality,
+}
+
+/// An aggregate query.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Aggregate<'a> {
+    /// The name of the aggregate.
+    pub name: Cow<'a, str>,
+    /// The name of the aggregated model.
+    pub model_name: Cow<'a, str>,
+    /// The arguments to the aggregate.
+    pub arguments: OrdStrMap<Argument<'a>>,
+    /// Whether the records are counted.
+    pub count: bool,
+    /// The functions applied to fields, in source order.
+    pub aggregations: Vec<Aggregation<'a>>,
+    /// The fields by which records are grouped, in source order.
+    pub group_by: Vec<GroupBy<'a>>,
+    /// The where clause of the aggregate.
+    pub r#where: Option<Where<'a>>,
+    /// The documentation of the aggregate.
+    pub doc: Option<Cow<'a, str>>,
+}
+
+impl<'a> Aggregate<'a> {
+    /// Create a new aggregate.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the aggregate.
+    /// * `model_name` - The name of the aggregated model.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::aggregate::Aggregate;
+    ///
+    /// let aggregate = Aggregate::new("postStats", "Post");
+    ///
+    /// assert_eq!(aggregate.name, "postStats");
+    /// assert_eq!(aggregate.model_name, "Post");
+    /// assert!(!aggregate.count);
+    /// assert!(aggregate.aggregations.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new<S, T>(
+        name: S,
+        model_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            name: name.into(),
+            model_name: model_name.into(),
+            arguments: OrdStrMap::new(),
+            count: false,
+            aggregations: Vec::new(),
+            group_by: Vec::new(),
+            r#where: None,
+            doc: None,
+        }
+    }
+
+    /// The cardinality of the result. An aggregate returns a single result,
+    /// or one result per group if it groups records.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::{
+    ///     aggregate::{
+    ///         Aggregate,
+    ///         GroupBy,
+    ///     },
+    ///     query::ArgumentType,
+    ///     Cardinality,
+    ///     Type,
+    /// };
+    ///
+    /// let mut aggregate = Aggregate::new("postStats", "Post");
+    ///
+    /// assert_eq!(aggregate.cardinality(), Cardinality::One);
+    ///
+    /// aggregate.group_by.push(GroupBy {
+    ///     field_name: "category".into(),
+    ///     r#type: ArgumentType::Type(Type::String),
+    ///     cardinality: Cardinality::One,
+    /// });
+    ///
+    /// assert_eq!(aggregate.cardinality(), Cardinality::Many);
+    /// ```
+    #[must_use]
+    pub const fn cardinality(&self) -> Cardinality {
+        if self.group_by.is_empty() {
+            Cardinality::One
+        } else {
+            Cardinality::Many
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Aggregate::new("postStats", "Post"),
+            Aggregate {
+                name: "postStats".into(),
+                model_name: "Post".into(),
+                arguments: OrdStrMap::new(),
+                count: false,
+                aggregations: Vec::new(),
+                group_by: Vec::new(),
+                r#where: None,
+                doc: None,
+            }
+        );
+    }
+}
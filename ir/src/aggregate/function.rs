@@ -0,0 +1,162 @@
+use {
+    crate::Type,
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// A function that aggregates the values of a field.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Function {
+    /// The average of the values.
+    Avg,
+    /// The highest value.
+    Max,
+    /// The lowest value.
+    Min,
+    /// The sum of the values.
+    Sum,
+}
+
+impl Function {
+    /// The type of the result of applying the function to a field of the
+    /// given type. Returns `None` if the function does not apply to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `r#type` - The type of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::{
+    ///     aggregate::Function,
+    ///     Type,
+    /// };
+    ///
+    /// assert_eq!(Function::Avg.result_type(Type::Int), Some(Type::Float));
+    /// assert_eq!(Function::Sum.result_type(Type::Int), Some(Type::Int));
+    /// assert_eq!(
+    ///     Function::Max.result_type(Type::DateTime),
+    ///     Some(Type::DateTime)
+    /// );
+    /// assert_eq!(Function::Sum.result_type(Type::DateTime), None);
+    /// ```
+    #[must_use]
+    pub const fn result_type(
+        self,
+        r#type: Type,
+    ) -> Option<Type> {
+        match (self, r#type) {
+            (Self::Avg, Type::BigInt | Type::Float | Type::Int) => {
+                Some(Type::Float)
+            }
+            (Self::Avg | Self::Sum, Type::Decimal)
+            | (Self::Sum, Type::BigInt | Type::Float | Type::Int)
+            | (
+                Self::Max | Self::Min,
+                Type::BigInt
+                | Type::DateTime
+                | Type::Decimal
+                | Type::Float
+                | Type::Int,
+            ) => Some(r#type),
+            _ => None,
+        }
+    }
+}
+
+impl From<ast::aggregate::Function> for Function {
+    fn from(value: ast::aggregate::Function) -> Self {
+        match value {
+            ast::aggregate::Function::Avg => Self::Avg,
+            ast::aggregate::Function::Max => Self::Max,
+            ast::aggregate::Function::Min => Self::Min,
+            ast::aggregate::Function::Sum => Self::Sum,
+        }
+    }
+}
+
+impl Display for Function {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Avg => write!(f, "avg"),
+            Self::Max => write!(f, "max"),
+            Self::Min => write!(f, "min"),
+            Self::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ast() {
+        assert_eq!(
+            Function::from(ast::aggregate::Function::Avg),
+            Function::Avg
+        );
+        assert_eq!(
+            Function::from(ast::aggregate::Function::Max),
+            Function::Max
+        );
+        assert_eq!(
+            Function::from(ast::aggregate::Function::Min),
+            Function::Min
+        );
+        assert_eq!(
+            Function::from(ast::aggregate::Function::Sum),
+            Function::Sum
+        );
+    }
+
+    #[test]
+    fn test_result_type() {
+        for function in [Function::Max, Function::Min] {
+            assert_eq!(function.result_type(Type::Int), Some(Type::Int));
+            assert_eq!(function.result_type(Type::Float), Some(Type::Float));
+            assert_eq!(
+                function.result_type(Type::DateTime),
+                Some(Type::DateTime)
+            );
+            assert_eq!(function.result_type(Type::String), None);
+            assert_eq!(function.result_type(Type::Boolean), None);
+        }
+
+        assert_eq!(Function::Sum.result_type(Type::Float), Some(Type::Float));
+        assert_eq!(Function::Avg.result_type(Type::Float), Some(Type::Float));
+        assert_eq!(Function::Avg.result_type(Type::DateTime), None);
+        assert_eq!(Function::Sum.result_type(Type::String), None);
+    }
+
+    #[test]
+    fn test_result_type_big_int_and_decimal() {
+        assert_eq!(Function::Avg.result_type(Type::BigInt), Some(Type::Float));
+        assert_eq!(
+            Function::Avg.result_type(Type::Decimal),
+            Some(Type::Decimal)
+        );
+        assert_eq!(Function::Sum.result_type(Type::BigInt), Some(Type::BigInt));
+        assert_eq!(
+            Function::Max.result_type(Type::Decimal),
+            Some(Type::Decimal)
+        );
+        assert_eq!(Function::Min.result_type(Type::Json), None);
+        assert_eq!(Function::Sum.result_type(Type::Uuid), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Function::Avg.to_string(), "avg");
+        assert_eq!(Function::Max.to_string(), "max");
+        assert_eq!(Function::Min.to_string(), "min");
+        assert_eq!(Function::Sum.to_string(), "sum");
+    }
+}
@@ -3,6 +3,41 @@
 pub enum Cardinality {
     /// One.
     One,
+    /// Zero or one.
+    Optional,
     /// Many.
     Many,
 }
+
+impl From<&ast::Type<'_>> for Cardinality {
+    fn from(ast_type: &ast::Type<'_>) -> Self {
+        match ast_type {
+            ast::Type::Scalar(_) => Self::One,
+            ast::Type::Optional(_) => Self::Optional,
+            ast::Type::Array(_) => Self::Many,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ast_type() {
+        assert_eq!(
+            Cardinality::from(&ast::Type::Scalar(ast::r#type::Scalar::Int)),
+            Cardinality::One
+        );
+
+        assert_eq!(
+            Cardinality::from(&ast::Type::Optional(ast::r#type::Scalar::Int)),
+            Cardinality::Optional
+        );
+
+        assert_eq!(
+            Cardinality::from(&ast::Type::Array(ast::r#type::Scalar::Int)),
+            Cardinality::Many
+        );
+    }
+}
@@ -0,0 +1,543 @@
+use {
+    crate::TypeError,
+    std::{
+        borrow::Cow,
+        collections::BTreeSet,
+    },
+};
+
+/// The kind of database.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Provider {
+    /// A `CockroachDB` database.
+    CockroachDb,
+    /// A `MongoDB` database.
+    MongoDb,
+    /// A `MySQL` database.
+    MySql,
+    /// A `PostgreSQL` database.
+    PostgreSql,
+    /// An SQL Server database.
+    SqlServer,
+    /// A `SQLite` database.
+    Sqlite,
+}
+
+impl Provider {
+    /// Get a provider by its name in the DSL. Returns `None` if the name is
+    /// unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the provider.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::database::Provider;
+    ///
+    /// assert_eq!(Provider::from_name("mysql"), Some(Provider::MySql));
+    /// assert_eq!(Provider::from_name("oracle"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "cockroachdb" => Some(Self::CockroachDb),
+            "mongodb" => Some(Self::MongoDb),
+            "mysql" => Some(Self::MySql),
+            "postgresql" => Some(Self::PostgreSql),
+            "sqlserver" => Some(Self::SqlServer),
+            "sqlite" => Some(Self::Sqlite),
+            _ => None,
+        }
+    }
+
+    /// Check whether the provider supports lists of scalars and enum values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::database::Provider;
+    ///
+    /// assert!(Provider::PostgreSql.supports_scalar_lists());
+    /// assert!(!Provider::Sqlite.supports_scalar_lists());
+    /// ```
+    #[must_use]
+    pub const fn supports_scalar_lists(self) -> bool {
+        matches!(self, Self::CockroachDb | Self::MongoDb | Self::PostgreSql)
+    }
+}
+
+/// How referential integrity is enforced.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RelationMode {
+    /// Foreign keys are enforced by the database.
+    ForeignKeys,
+    /// Foreign keys are emulated in the client.
+    #[default]
+    Prisma,
+}
+
+impl RelationMode {
+    /// Get a relation mode by its name in the DSL. Returns `None` if the name
+    /// is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the relation mode.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::database::RelationMode;
+    ///
+    /// assert_eq!(
+    ///     RelationMode::from_name("foreignKeys"),
+    ///     Some(RelationMode::ForeignKeys)
+    /// );
+    ///
+    /// assert_eq!(RelationMode::from_name("emulated"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "foreignKeys" => Some(Self::ForeignKeys),
+            "prisma" => Some(Self::Prisma),
+            _ => None,
+        }
+    }
+}
+
+/// A feature of the generated client that is not yet generally available.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PreviewFeature {
+    /// `clientExtensions`.
+    ClientExtensions,
+    /// `deno`.
+    Deno,
+    /// `extendedWhereUnique`.
+    ExtendedWhereUnique,
+    /// `fieldReference`.
+    FieldReference,
+    /// `filteredRelationCount`.
+    FilteredRelationCount,
+    /// `fullTextIndex`.
+    FullTextIndex,
+    /// `fullTextSearch`.
+    FullTextSearch,
+    /// `metrics`.
+    Metrics,
+    /// `multiSchema`.
+    MultiSchema,
+    /// `orderByNulls`.
+    OrderByNulls,
+    /// `postgresqlExtensions`.
+    PostgresqlExtensions,
+    /// `tracing`.
+    Tracing,
+    /// `views`.
+    Views,
+}
+
+impl PreviewFeature {
+    /// Get a preview feature by its name in the DSL. Returns `None` if the
+    /// name is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the preview feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::database::PreviewFeature;
+    ///
+    /// assert_eq!(
+    ///     PreviewFeature::from_name("fullTextSearch"),
+    ///     Some(PreviewFeature::FullTextSearch)
+    /// );
+    ///
+    /// assert_eq!(PreviewFeature::from_name("interactiveTransactions"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "clientExtensions" => Some(Self::ClientExtensions),
+            "deno" => Some(Self::Deno),
+            "extendedWhereUnique" => Some(Self::ExtendedWhereUnique),
+            "fieldReference" => Some(Self::FieldReference),
+            "filteredRelationCount" => Some(Self::FilteredRelationCount),
+            "fullTextIndex" => Some(Self::FullTextIndex),
+            "fullTextSearch" => Some(Self::FullTextSearch),
+            "metrics" => Some(Self::Metrics),
+            "multiSchema" => Some(Self::MultiSchema),
+            "orderByNulls" => Some(Self::OrderByNulls),
+            "postgresqlExtensions" => Some(Self::PostgresqlExtensions),
+            "tracing" => Some(Self::Tracing),
+            "views" => Some(Self::Views),
+            _ => None,
+        }
+    }
+}
+
+/// The database of the application, and how the generated code connects to
+/// it.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Database<'a> {
+    /// The kind of database.
+    pub provider: Provider,
+    /// The name of the environment variable that holds the connection URL.
+    pub url: Cow<'a, str>,
+    /// The name of the environment variable that holds the connection URL of
+    /// the shadow database.
+    pub shadow_database_url: Option<Cow<'a, str>>,
+    /// The name of the environment variable that holds the URL for direct
+    /// connections to the database.
+    pub direct_url: Option<Cow<'a, str>>,
+    /// How referential integrity is enforced.
+    pub relation_mode: RelationMode,
+    /// The preview features to enable.
+    pub preview_features: BTreeSet<PreviewFeature>,
+}
+
+impl<'a> Database<'a> {
+    /// The default name of the environment variable that holds the
+    /// connection URL.
+    pub const DEFAULT_URL: &'static str = "DATABASE_URL";
+
+    /// Create a database with the given provider and default settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The kind of database.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::database::{
+    ///     Database,
+    ///     Provider,
+    ///     RelationMode,
+    /// };
+    ///
+    /// let database = Database::new(Provider::Sqlite);
+    ///
+    /// assert_eq!(database.url, "DATABASE_URL");
+    /// assert_eq!(database.relation_mode, RelationMode::Prisma);
+    /// assert!(database.preview_features.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new(provider: Provider) -> Self {
+        Self {
+            provider,
+            url: Cow::Borrowed(Self::DEFAULT_URL),
+            shadow_database_url: None,
+            direct_url: None,
+            relation_mode: RelationMode::Prisma,
+            preview_features: BTreeSet::new(),
+        }
+    }
+
+    /// Check whether the given name is a valid environment variable name:
+    /// upper case letters, digits and underscores, not starting with a
+    /// digit.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to check.
+    fn is_env_var(name: &str) -> bool {
+        name.chars()
+            .next()
+            .is_some_and(|char| !char.is_ascii_digit())
+            && name.chars().all(|char| {
+                char.is_ascii_uppercase()
+                    || char.is_ascii_digit()
+                    || char == '_'
+            })
+    }
+
+    /// Get the name of an environment variable from a setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the setting.
+    /// * `setting` - The value of the setting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TypeError::DatabaseError` if the value is not a string that
+    /// is a valid environment variable name.
+    fn env_var(
+        name: &str,
+        setting: &ast::database::Setting<'a>,
+    ) -> Result<Cow<'a, str>, TypeError<'a>> {
+        match setting {
+            ast::database::Setting::Value(ast::Value::String(value))
+                if Self::is_env_var(value) =>
+            {
+                Ok(value.clone())
+            }
+            _ => {
+                Err(TypeError::invalid_database_setting(
+                    name.to_owned(),
+                    setting.to_string(),
+                ))
+            }
+        }
+    }
+
+    /// Get a named option, such as a provider or relation mode, from a
+    /// setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the setting.
+    /// * `setting` - The value of the setting.
+    /// * `from_name` - Get the option by its name in the DSL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TypeError::DatabaseError` if the value is not an identifier
+    /// naming a known option.
+    fn option<T>(
+        name: &str,
+        setting: &ast::database::Setting<'a>,
+        from_name: fn(&str) -> Option<T>,
+    ) -> Result<T, TypeError<'a>> {
+        match setting {
+            ast::database::Setting::Value(ast::Value::Identifier(value)) => {
+                from_name(value)
+            }
+            _ => None,
+        }
+        .ok_or_else(|| {
+            TypeError::invalid_database_setting(
+                name.to_owned(),
+                setting.to_string(),
+            )
+        })
+    }
+
+    /// Build a database from an AST database, recovering from invalid
+    /// settings.
+    ///
+    /// Every error is returned, together with the database with its valid
+    /// settings applied. The database is `None` if the provider is missing
+    /// or invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The AST database.
+    #[must_use]
+    pub fn from_ast_with_recovery(
+        value: &ast::Database<'a>
+    ) -> (Option<Self>, Vec<TypeError<'a>>) {
+        let mut database = Self::new(Provider::PostgreSql);
+        let mut provider = None;
+        let mut errors = Vec::new();
+
+        for (name, setting) in value.settings.iter() {
+            let result = match name {
+                "provider" => {
+                    Self::option(name, &setting, Provider::from_name)
+                        .map(|value| provider = Some(value))
+                }
+                "url" => {
+                    Self::env_var(name, &setting)
+                        .map(|value| database.url = value)
+                }
+                "shadowDatabaseUrl" => {
+                    Self::env_var(name, &setting)
+                        .map(|value| database.shadow_database_url = Some(value))
+                }
+                "directUrl" => {
+                    Self::env_var(name, &setting)
+                        .map(|value| database.direct_url = Some(value))
+                }
+                "relationMode" => {
+                    Self::option(name, &setting, RelationMode::from_name)
+                        .map(|value| database.relation_mode = value)
+                }
+                "previewFeatures" => {
+                    if let ast::database::Setting::List(values) = &setting {
+                        for value in values {
+                            if let Some(feature) = match value {
+                                ast::Value::Identifier(feature) => {
+                                    PreviewFeature::from_name(feature)
+                                }
+                                _ => None,
+                            } {
+                                let _: bool =
+                                    database.preview_features.insert(feature);
+
+                                continue;
+                            }
+
+                            errors.push(TypeError::invalid_database_setting(
+                                name.to_owned(),
+                                value.to_string(),
+                            ));
+                        }
+
+                        Ok(())
+                    } else {
+                        Err(TypeError::invalid_database_setting(
+                            name.to_owned(),
+                            setting.to_string(),
+                        ))
+                    }
+                }
+                _ => Err(TypeError::unknown_database_setting(name.to_owned())),
+            };
+
+            if let Err(error) = result {
+                errors.push(error);
+            }
+        }
+
+        if !value.settings.contains_key("provider") {
+            errors.push(TypeError::missing_database_provider());
+        }
+
+        let database = provider.map(|provider| {
+            database.provider = provider;
+            database
+        });
+
+        (database, errors)
+    }
+}
+
+impl<'a> TryFrom<&ast::Database<'a>> for Database<'a> {
+    type Error = Vec<TypeError<'a>>;
+
+    fn try_from(value: &ast::Database<'a>) -> Result<Self, Self::Error> {
+        match Self::from_ast_with_recovery(value) {
+            (Some(database), errors) if errors.is_empty() => Ok(database),
+            (_, errors) => Err(errors),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_ast() {
+        let input = "
+
+database {
+  provider: mysql
+  url: \"MYSQL_URL\"
+  shadowDatabaseUrl: \"SHADOW_URL\"
+  relationMode: foreignKeys
+  previewFeatures: [views, fullTextSearch, views]
+}
+
+        "
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(input).unwrap();
+        let ast_database = ast.database.unwrap();
+
+        assert_eq!(
+            Database::try_from(&ast_database),
+            Ok(Database {
+                provider: Provider::MySql,
+                url: "MYSQL_URL".into(),
+                shadow_database_url: Some("SHADOW_URL".into()),
+                direct_url: None,
+                relation_mode: RelationMode::ForeignKeys,
+                preview_features: BTreeSet::from_iter([
+                    PreviewFeature::FullTextSearch,
+                    PreviewFeature::Views,
+                ]),
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_errors() {
+        let input = "
+
+database {
+  url: \"database url\"
+  host: \"localhost\"
+  relationMode: emulated
+  previewFeatures: [views, \"metrics\", tracing]
+}
+
+        "
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(input).unwrap();
+        let ast_database = ast.database.unwrap();
+
+        assert_eq!(
+            Database::try_from(&ast_database),
+            Err(vec![
+                TypeError::invalid_database_setting("url", "\"database url\""),
+                TypeError::unknown_database_setting("host"),
+                TypeError::invalid_database_setting("relationMode", "emulated"),
+                TypeError::invalid_database_setting(
+                    "previewFeatures",
+                    "\"metrics\""
+                ),
+                TypeError::missing_database_provider(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_from_ast_with_recovery() {
+        let input = "
+
+database {
+  provider: sqlite
+  url: \"database url\"
+  relationMode: foreignKeys
+}
+
+        "
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(input).unwrap();
+        let ast_database = ast.database.unwrap();
+        let mut database = Database::new(Provider::Sqlite);
+
+        database.relation_mode = RelationMode::ForeignKeys;
+
+        assert_eq!(
+            Database::from_ast_with_recovery(&ast_database),
+            (
+                Some(database),
+                vec![TypeError::invalid_database_setting(
+                    "url",
+                    "\"database url\""
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_unknown_provider() {
+        let (ast, _) =
+            ast::Ast::parse("database {\n  provider: oracle\n}").unwrap();
+
+        assert_eq!(
+            Database::try_from(&ast.database.unwrap()),
+            Err(vec![TypeError::invalid_database_setting(
+                "provider", "oracle"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_is_env_var() {
+        assert!(Database::is_env_var("DATABASE_URL_2"));
+        assert!(!Database::is_env_var("2_DATABASE_URL"));
+        assert!(!Database::is_env_var("database_url"));
+        assert!(!Database::is_env_var(""));
+    }
+}
@@ -1,4 +1,5 @@
 use {
+    ord_str_map::OrdStrMap,
     std::borrow::Cow,
     token_set::TokenSet,
 };
@@ -10,6 +11,10 @@ pub struct Enum<'a> {
     pub name: Cow<'a, str>,
     /// The values of the enum.
     pub values: TokenSet,
+    /// The documentation of the enum.
+    pub doc: Option<Cow<'a, str>>,
+    /// The documentation of the values of the enum, by value.
+    pub value_docs: OrdStrMap<Cow<'a, str>>,
 }
 
 impl<'a> From<ast::Enum<'a>> for Enum<'a> {
@@ -17,6 +22,8 @@ impl<'a> From<ast::Enum<'a>> for Enum<'a> {
         let mut r#enum = Self {
             name: ast.name.clone(),
             values: TokenSet::new(),
+            doc: ast.doc,
+            value_docs: ast.value_docs,
         };
 
         for value in ast.values.iter() {
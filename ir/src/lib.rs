@@ -32,12 +32,16 @@
 )]
 
 pub use self::{
+    aggregate::Aggregate,
     cardinality::Cardinality,
+    database::Database,
     model::Model,
+    mutation::Mutation,
     query::Query,
     r#enum::Enum,
     r#type::Type,
     type_error::TypeError,
+    value::Value,
 };
 use {
     ord_str_map::OrdStrMap,
@@ -50,32 +54,134 @@ use {
     },
 };
 
+/// Aggregate queries.
+pub mod aggregate;
 /// Cardinality.
 pub mod cardinality;
+/// The database of the application.
+pub mod database;
 /// Enums.
 pub mod r#enum;
 /// Models.
 pub mod model;
+/// Mutations.
+pub mod mutation;
 /// Queries.
 pub mod query;
 /// Types.
 pub mod r#type;
 /// Type errors.
 pub mod type_error;
+/// Literal values.
+pub mod value;
+
+/// The name of a model, and the names of its owned relations and of the
+/// models that they refer to.
+type OwnedRelations<'b, 'a> = (&'b str, Vec<(&'b str, Cow<'a, str>)>);
+
+/// The kind of declaration that a where clause belongs to. Errors in the
+/// conditions of the where clause are reported as errors in the declaration.
+/// Aggregates are reported like queries.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum WhereOwner {
+    /// The where clause of a mutation.
+    Mutation,
+    /// The where clause of a query or aggregate.
+    Query,
+}
+
+impl WhereOwner {
+    /// Create an undefined argument error.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the declaration.
+    /// * `argument_name` - The name of the argument.
+    fn undefined_argument<'a>(
+        self,
+        name: Cow<'a, str>,
+        argument_name: Cow<'a, str>,
+    ) -> TypeError<'a> {
+        match self {
+            Self::Mutation => {
+                TypeError::undefined_mutation_argument(name, argument_name)
+            }
+            Self::Query => {
+                TypeError::undefined_query_argument(name, argument_name)
+            }
+        }
+    }
+
+    /// Create an invalid condition error.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the declaration.
+    /// * `lhs_name` - The path to the field.
+    /// * `rhs_name` - The argument or literal.
+    /// * `operator` - The operator.
+    fn invalid_condition<'a>(
+        self,
+        name: Cow<'a, str>,
+        lhs_name: String,
+        rhs_name: Cow<'a, str>,
+        operator: String,
+    ) -> TypeError<'a> {
+        match self {
+            Self::Mutation => {
+                TypeError::invalid_mutation_condition(
+                    name, lhs_name, rhs_name, operator,
+                )
+            }
+            Self::Query => {
+                TypeError::invalid_query_condition(
+                    name, lhs_name, rhs_name, operator,
+                )
+            }
+        }
+    }
+
+    /// Create an invalid condition mode error.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the declaration.
+    /// * `lhs_name` - The path to the field.
+    fn invalid_condition_mode(
+        self,
+        name: Cow<'_, str>,
+        lhs_name: String,
+    ) -> TypeError<'_> {
+        match self {
+            Self::Mutation => {
+                TypeError::invalid_mutation_condition_mode(name, lhs_name)
+            }
+            Self::Query => {
+                TypeError::invalid_query_condition_mode(name, lhs_name)
+            }
+        }
+    }
+}
 
 /// The intermediate representation (IR) of the AST.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Ir<'a> {
+    /// The database of the application, if declared.
+    pub database: Option<Database<'a>>,
     /// The models in the data model.
     pub models: OrdStrMap<Model<'a>>,
     /// The enums in the data model.
     pub enums: OrdStrMap<Enum<'a>>,
     /// Queries.
     pub queries: OrdStrMap<Query<'a>>,
+    /// Aggregate queries.
+    pub aggregates: OrdStrMap<Aggregate<'a>>,
+    /// Mutations.
+    pub mutations: OrdStrMap<Mutation<'a>>,
 }
 
 impl<'a> Ir<'a> {
-    /// Resolve the type of a model field.
+    /// Resolve the type of a model field, including a generated `id` field.
     ///
     /// # Arguments
     ///
@@ -94,7 +200,10 @@ impl<'a> Ir<'a> {
 
         while let Some(segment) = path.pop_front() {
             if path.is_empty() {
-                return current_model.field(&segment).map(|field| field.r#type);
+                return current_model
+                    .field(&segment)
+                    .map(|field| field.r#type)
+                    .or_else(|| current_model.generated_id_type(&segment));
             }
 
             if let Some(model::ModelRelation {
@@ -217,38 +326,243 @@ impl<'a> Ir<'a> {
         Ok(())
     }
 
-    /// Check compatibility between argument type and field type.
+    /// Resolve the cardinality of a model field, including a generated `id`
+    /// field, or of an enum relation.
     ///
     /// # Arguments
     ///
     /// * `model_name` - The name of the model.
     /// * `path` - The path to the field.
+    #[must_use]
+    pub fn field_cardinality<S>(
+        &self,
+        model_name: S,
+        mut path: VecDeque<Cow<'a, str>>,
+    ) -> Option<Cardinality>
+    where
+        S: AsRef<str>,
+    {
+        let mut current_model = self.models.get(model_name.as_ref())?;
+
+        while let Some(segment) = path.pop_front() {
+            if path.is_empty() {
+                return current_model
+                    .field(&segment)
+                    .map(|field| field.cardinality)
+                    .or_else(|| {
+                        current_model
+                            .enum_relation(&segment)
+                            .map(|relation| relation.cardinality)
+                    })
+                    .or_else(|| {
+                        current_model
+                            .generated_id_type(&segment)
+                            .map(|_| Cardinality::One)
+                    });
+            }
+
+            if let Some(model::ModelRelation {
+                model_name: name, ..
+            }) = current_model.model_relation(&segment)
+            {
+                if let Some(model) = self.models.get(&name) {
+                    current_model = model;
+                    continue;
+                }
+            }
+
+            return None;
+        }
+
+        None
+    }
+
+    /// Check whether every relation along the path to a field relates a
+    /// record to at most one other record, so that the field has a single
+    /// value per record of the model.
     ///
-    /// # Errors
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `path` - The path to the field.
+    #[must_use]
+    pub fn is_to_one_path<S>(
+        &self,
+        model_name: S,
+        path: &VecDeque<Cow<'a, str>>,
+    ) -> bool
+    where
+        S: AsRef<str>,
+    {
+        let Some(mut current_model) = self.models.get(model_name.as_ref())
+        else {
+            return false;
+        };
+
+        for segment in path.iter().take(path.len().saturating_sub(1)) {
+            let Some(model::ModelRelation {
+                model_name: name,
+                r#type:
+                    model::model_relation::Type::ManyToOne
+                    | model::model_relation::Type::OneToOne,
+                ..
+            }) = current_model.model_relation(segment)
+            else {
+                return false;
+            };
+
+            let Some(model) = self.models.get(&name) else {
+                return false;
+            };
+
+            current_model = model;
+        }
+
+        true
+    }
+
+    /// Check compatibility between an argument and the field it is compared
+    /// to with the given operator.
+    ///
+    /// * `equals`, `notEquals` and `contains` require the types to match.
+    /// * `lt`, `lte`, `gt` and `gte` also require an `Int`, `Float` or
+    ///   `DateTime` field.
+    /// * `startsWith` and `endsWith` require a `String` field and argument.
+    /// * `in` and `notIn` require an array argument of the type of the field.
+    /// * `isNull` requires an optional field and a `Boolean` argument.
+    ///
+    /// # Arguments
     ///
-    /// Returns a `TypeError` if the types are incompatible.
+    /// * `model_name` - The name of the model.
+    /// * `path` - The path to the field.
+    /// * `operator` - The operator of the condition.
+    /// * `argument` - The argument.
     #[must_use]
     pub fn check_argument_type<S>(
         &self,
         model_name: S,
         path: VecDeque<Cow<'a, str>>,
-        argument_type: query::ArgumentType<'a>,
+        operator: query::Operator,
+        argument: &query::Argument<'a>,
     ) -> bool
     where
         S: AsRef<str>,
     {
-        match argument_type {
-            query::ArgumentType::Enum(rhs) => {
-                self.enum_type(model_name, path)
-                    .map_or(false, |lhs| lhs == rhs)
+        let model_name = model_name.as_ref();
+
+        let Some(field_cardinality) =
+            self.field_cardinality(model_name, path.clone())
+        else {
+            return false;
+        };
+
+        let field_type = self.field_type(model_name, path.clone());
+        let enum_type = self.enum_type(model_name, path);
+
+        let types_match = || {
+            match &argument.r#type {
+                query::ArgumentType::Enum(rhs) => {
+                    enum_type.as_ref().map_or(false, |lhs| lhs == rhs)
+                }
+                query::ArgumentType::Type(rhs) => {
+                    field_type.map_or(false, |lhs| lhs == *rhs)
+                }
+            }
+        };
+
+        match operator {
+            query::Operator::Contains
+            | query::Operator::Equals
+            | query::Operator::NotEquals => types_match(),
+            query::Operator::Gt
+            | query::Operator::Gte
+            | query::Operator::Lt
+            | query::Operator::Lte => {
+                field_cardinality != Cardinality::Many
+                    && matches!(
+                        field_type,
+                        Some(
+                            Type::BigInt
+                                | Type::DateTime
+                                | Type::Decimal
+                                | Type::Float
+                                | Type::Int
+                        )
+                    )
+                    && types_match()
+            }
+            query::Operator::EndsWith | query::Operator::StartsWith => {
+                field_cardinality != Cardinality::Many
+                    && field_type == Some(Type::String)
+                    && types_match()
             }
-            query::ArgumentType::Type(rhs) => {
-                self.field_type(model_name, path)
-                    .map_or(false, |lhs| lhs == rhs)
+            query::Operator::In | query::Operator::NotIn => {
+                field_cardinality != Cardinality::Many
+                    && argument.cardinality == Cardinality::Many
+                    && types_match()
+            }
+            query::Operator::IsNull => {
+                field_cardinality == Cardinality::Optional
+                    && argument.r#type
+                        == query::ArgumentType::Type(Type::Boolean)
             }
         }
     }
 
+    /// Type check a literal value against the field it is compared to with
+    /// the given operator, following the rules of `check_argument_type`.
+    /// Returns `None` if the value does not fit. Literals are never arrays,
+    /// so `in` and `notIn` do not accept them.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `path` - The path to the field.
+    /// * `operator` - The operator of the condition.
+    /// * `value` - The AST value.
+    #[must_use]
+    pub fn check_value<S>(
+        &self,
+        model_name: S,
+        path: VecDeque<Cow<'a, str>>,
+        operator: query::Operator,
+        value: &ast::Value<'a>,
+    ) -> Option<Value<'a>>
+    where
+        S: AsRef<str>,
+    {
+        let model_name = model_name.as_ref();
+
+        let (r#type, ir_value) = if operator == query::Operator::IsNull {
+            (
+                query::ArgumentType::Type(Type::Boolean),
+                Value::from_ast_value(value, Type::Boolean)?,
+            )
+        } else if let Some(enum_name) = self.enum_type(model_name, path.clone())
+        {
+            let value =
+                Value::from_ast_enum_value(value, self.enums.get(&enum_name)?)?;
+
+            (query::ArgumentType::Enum(enum_name), value)
+        } else {
+            let field_type = self.field_type(model_name, path.clone())?;
+
+            (
+                query::ArgumentType::Type(field_type),
+                Value::from_ast_value(value, field_type)?,
+            )
+        };
+
+        let argument = query::Argument {
+            name: value.to_string().into(),
+            r#type,
+            cardinality: Cardinality::One,
+        };
+
+        self.check_argument_type(model_name, path, operator, &argument)
+            .then_some(ir_value)
+    }
+
     /// Build a schema from an AST schema.
     ///
     /// # Arguments
@@ -259,25 +573,33 @@ impl<'a> Ir<'a> {
     ///
     /// # Errors
     ///
-    /// Returns a `TypeError` if the schema is invalid.
+    /// Returns every `TypeError` found in the nodes of the schema.
     pub fn query_schema<S>(
         &self,
         query_name: &S,
         ast_schema: &ast::query::Schema<'a>,
         model: &Model<'a>,
-    ) -> Result<query::Schema<'a>, TypeError<'a>>
+    ) -> Result<query::Schema<'a>, Vec<TypeError<'a>>>
     where
         S: Into<Cow<'a, str>> + Clone,
     {
         let mut nodes = Vec::new();
+        let mut errors = Vec::new();
 
         for node in ast_schema.nodes.clone() {
-            nodes.push(self.query_schema_node(
+            match self.query_schema_node(
                 query_name.clone(),
                 node,
                 model,
                 VecDeque::new(),
-            )?);
+            ) {
+                Ok(node) => nodes.push(node),
+                Err(node_errors) => errors.extend(node_errors),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
         }
 
         Ok(query::Schema {
@@ -297,62 +619,84 @@ impl<'a> Ir<'a> {
     ///
     /// # Errors
     ///
-    /// Returns a `TypeError` if the schema node is invalid.
+    /// Returns every `TypeError` found in the schema node and the nodes it
+    /// selects.
     pub fn query_schema_node<S>(
         &self,
         query_name: S,
         ast_node: ast::query::schema::Node<'a>,
         model: &Model<'a>,
         mut path: VecDeque<Cow<'a, str>>,
-    ) -> Result<query::schema::Node<'a>, TypeError<'a>>
+    ) -> Result<query::schema::Node<'a>, Vec<TypeError<'a>>>
     where
         S: Into<Cow<'a, str>> + Clone,
     {
-        match ast_node {
-            ast::query::schema::Node::Field { name } => {
-                path.push_back(name.clone());
+        let name = match &ast_node {
+            ast::query::schema::Node::Field { name }
+            | ast::query::schema::Node::Relation { name, .. } => name.clone(),
+        };
 
-                if self.field_type(model.name(), path.clone()).is_some()
-                    || self.enum_type(model.name(), path.clone()).is_some()
-                {
-                    Ok(query::schema::Node::Field { name })
-                } else {
-                    Err(TypeError::undefined_query_field(
-                        query_name,
-                        path.into_iter().collect::<Vec<_>>().join("."),
-                    ))
-                }
+        path.push_back(name.clone());
+
+        let field_name = || path.iter().cloned().collect::<Vec<_>>().join(".");
+        let is_field = model.field(&name).is_some()
+            || model.enum_relation(&name).is_some();
+
+        match (ast_node, model.model_relation(&name)) {
+            (ast::query::schema::Node::Field { .. }, None) if is_field => {
+                Ok(query::schema::Node::Field { name })
             }
-            ast::query::schema::Node::Relation {
-                name: ast_name,
-                nodes: ast_nodes,
-            } => {
+            (ast::query::schema::Node::Field { .. }, Some(_)) => {
+                Err(vec![TypeError::missing_query_selection(
+                    query_name,
+                    field_name(),
+                )])
+            }
+            (ast::query::schema::Node::Relation { .. }, None) if is_field => {
+                Err(vec![TypeError::unexpected_query_selection(
+                    query_name,
+                    field_name(),
+                )])
+            }
+            (
+                ast::query::schema::Node::Relation {
+                    nodes: ast_nodes, ..
+                },
+                Some(model::ModelRelation { model_name, .. }),
+            ) => {
+                let Some(related_model) = self.models.get(&model_name) else {
+                    return Err(vec![TypeError::undefined_query_field(
+                        query_name,
+                        field_name(),
+                    )]);
+                };
+
                 let mut nodes = Vec::new();
+                let mut errors = Vec::new();
 
                 for ast_node in ast_nodes {
-                    let mut path = path.clone();
-
-                    path.push_back(ast_name.clone());
-
-                    if let Some(model) = self.models.get(&model.name()) {
-                        nodes.push(self.query_schema_node(
-                            query_name.clone(),
-                            ast_node,
-                            model,
-                            path,
-                        )?);
-                    } else {
-                        return Err(TypeError::undefined_query_field(
-                            query_name,
-                            path.into_iter().collect::<Vec<_>>().join("."),
-                        ));
+                    match self.query_schema_node(
+                        query_name.clone(),
+                        ast_node,
+                        related_model,
+                        path.clone(),
+                    ) {
+                        Ok(node) => nodes.push(node),
+                        Err(node_errors) => errors.extend(node_errors),
                     }
                 }
 
-                Ok(query::schema::Node::Relation {
-                    name: ast_name,
-                    nodes,
-                })
+                if !errors.is_empty() {
+                    return Err(errors);
+                }
+
+                Ok(query::schema::Node::Relation { name, nodes })
+            }
+            _ => {
+                Err(vec![TypeError::undefined_query_field(
+                    query_name,
+                    field_name(),
+                )])
             }
         }
     }
@@ -391,6 +735,183 @@ impl<'a> Ir<'a> {
         )
     }
 
+    /// Type check the default value of a data field or enum relation.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model that contains the field.
+    /// * `field_name` - The name of the field.
+    /// * `value` - The AST default value.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `TypeError` if the field is not a data field or enum
+    ///   relation, or if it is a list.
+    /// * Returns a `TypeError` if the value does not match the type of the
+    ///   field.
+    fn default_value(
+        &self,
+        model: &Model<'a>,
+        field_name: &Cow<'a, str>,
+        value: &ast::Value<'a>,
+    ) -> Result<Value<'a>, TypeError<'a>> {
+        let default =
+            match (model.fields.get(field_name), model.enums.get(field_name)) {
+                (Some(field), _) if field.cardinality != Cardinality::Many => {
+                    Value::from_ast_value(value, field.r#type)
+                }
+                (_, Some(relation))
+                    if relation.cardinality != Cardinality::Many =>
+                {
+                    self.enums.get(&relation.name).and_then(|r#enum| {
+                        Value::from_ast_enum_value(value, r#enum)
+                    })
+                }
+                _ => {
+                    return Err(TypeError::invalid_model_attribute(
+                        model.name(),
+                        field_name.clone(),
+                        "default",
+                    ));
+                }
+            };
+
+        default.ok_or_else(|| {
+            TypeError::invalid_model_default(
+                model.name(),
+                field_name.clone(),
+                value.to_string(),
+            )
+        })
+    }
+
+    /// Check the native type of a field against the database provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model that the field belongs to.
+    /// * `field_name` - The name of the field.
+    /// * `native_type` - The AST native type.
+    /// * `database_declared` - Whether a database is declared. If it is, but
+    ///   its provider is invalid, the native type is not checked.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `TypeError` if the field is not a data field.
+    /// * Returns a `TypeError` if no database is declared.
+    /// * Returns a `TypeError` if the provider does not support the native type
+    ///   for the type of the field.
+    fn native_type(
+        &self,
+        model: &Model<'a>,
+        field_name: &Cow<'a, str>,
+        native_type: &ast::model::attribute::NativeType<'a>,
+        database_declared: bool,
+    ) -> Result<model::NativeType<'a>, TypeError<'a>> {
+        let Some(field) = model.fields.get(field_name) else {
+            return Err(TypeError::invalid_model_attribute(
+                model.name(),
+                field_name.clone(),
+                "db",
+            ));
+        };
+
+        let native_type = model::NativeType::from(native_type.clone());
+
+        match &self.database {
+            Some(database)
+                if !native_type
+                    .is_supported(database.provider, field.r#type) =>
+            {
+                Err(TypeError::invalid_model_native_type(
+                    model.name(),
+                    field_name.clone(),
+                    native_type.to_string(),
+                ))
+            }
+            Some(_) => Ok(native_type),
+            // An invalid provider has already been reported.
+            None if database_declared => Ok(native_type),
+            None => {
+                Err(TypeError::unexpected_model_native_type(
+                    model.name(),
+                    field_name.clone(),
+                ))
+            }
+        }
+    }
+
+    /// Check a referential action of a relation.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model that the relation belongs to.
+    /// * `field_name` - The name of the relation.
+    /// * `attribute` - The name of the attribute, `onDelete` or `onUpdate`.
+    /// * `action` - The name of the referential action.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `TypeError` if the field is not a relation with foreign
+    ///   keys.
+    /// * Returns a `TypeError` if the action is unknown.
+    /// * Returns a `TypeError` if the action is `SetNull` and the foreign keys
+    ///   of the relation are required, or if the database does not support the
+    ///   action.
+    fn referential_action(
+        &self,
+        model: &Model<'a>,
+        field_name: &Cow<'a, str>,
+        attribute: &str,
+        action: &Cow<'a, str>,
+    ) -> Result<model::ReferentialAction, TypeError<'a>> {
+        let Some(relation) =
+            model.relations.get(field_name).filter(|relation| {
+                relation.r#type != model::model_relation::Type::ManyToMany
+            })
+        else {
+            return Err(TypeError::invalid_model_attribute(
+                model.name(),
+                field_name.clone(),
+                attribute.to_owned(),
+            ));
+        };
+
+        let Some(referential_action) =
+            model::ReferentialAction::from_name(action)
+        else {
+            return Err(TypeError::unknown_model_referential_action(
+                model.name(),
+                field_name.clone(),
+                action.clone(),
+            ));
+        };
+
+        // The foreign keys generated for a one-to-many relation are optional,
+        // those generated for a one-to-one relation are not.
+        let set_null_allowed = match relation.r#type {
+            model::model_relation::Type::ManyToOne => relation.optional,
+            model::model_relation::Type::OneToMany => true,
+            model::model_relation::Type::ManyToMany
+            | model::model_relation::Type::OneToOne => false,
+        };
+
+        if (referential_action == model::ReferentialAction::SetNull
+            && !set_null_allowed)
+            || self.database.as_ref().is_some_and(|database| {
+                !referential_action.is_supported(database)
+            })
+        {
+            return Err(TypeError::invalid_model_referential_action(
+                model.name(),
+                field_name.clone(),
+                action.clone(),
+            ));
+        }
+
+        Ok(referential_action)
+    }
+
     /// Add an AST model to the IR.
     ///
     /// # Arguments
@@ -398,142 +919,858 @@ impl<'a> Ir<'a> {
     /// * `ast_model` - The AST model.
     /// * `enum_names` - The names of the enums.
     /// * `model_names` - The names of the models.
+    /// * `database_declared` - Whether a database is declared, even if it is
+    ///   invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns every `TypeError` found in the model. Fields with errors are
+    /// left out, the rest of the model is still added.
     #[allow(clippy::too_many_lines)]
     fn add_model(
         &mut self,
         ast_model: &ast::Model<'a>,
         enum_names: &BTreeSet<Cow<'a, str>>,
         model_names: &BTreeSet<Cow<'a, str>>,
-    ) -> Result<(), TypeError<'a>> {
-        let ast::Model { fields, .. } = ast_model;
+        database_declared: bool,
+    ) -> Result<(), Vec<TypeError<'a>>> {
+        let ast::Model { fields, doc, .. } = ast_model;
         let mut model = Model::new(ast_model.name.clone());
+        let mut errors = Vec::new();
+        let mut ids = Vec::new();
 
-        for field in fields.values() {
-            let field_name = field.name.clone();
+        model.doc.clone_from(doc);
 
-            match &field.r#type {
-                ast::Type::Scalar(ast::r#type::Scalar::Boolean) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::Boolean,
-                        cardinality: Cardinality::One,
-                    })
-                }
-                ast::Type::Scalar(ast::r#type::Scalar::DateTime) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::DateTime,
-                        cardinality: Cardinality::One,
-                    })
-                }
-                ast::Type::Scalar(ast::r#type::Scalar::Float) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::Float,
-                        cardinality: Cardinality::One,
-                    })
-                }
-                ast::Type::Scalar(ast::r#type::Scalar::Int) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::Int,
-                        cardinality: Cardinality::One,
-                    })
-                }
-                ast::Type::Scalar(ast::r#type::Scalar::String) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::String,
-                        cardinality: Cardinality::One,
-                    })
-                }
-                ast::Type::Scalar(ast::r#type::Scalar::Reference(name)) => {
-                    if enum_names.contains(name) {
-                        model.insert_enum_relation(field_name, name.clone())
-                    } else if model_names.contains(name) {
-                        model.insert_many_to_one(field_name, name.clone())
-                    } else {
-                        Err(TypeError::unknown_model_field_type(
+        if fields.is_empty() {
+            errors.push(TypeError::empty_model(model.name()));
+        }
+
+        let mut timestamps = None;
+
+        for attribute in &ast_model.attributes {
+            let ast::model::attribute::Block::Timestamps(entries) = attribute
+            else {
+                continue;
+            };
+
+            let timestamps =
+                timestamps.get_or_insert_with(model::Timestamps::default);
+
+            for ast::model::attribute::Timestamp { kind, name } in entries {
+                match timestamps.get_mut(kind) {
+                    Some(Some(_)) => {
+                        errors.push(TypeError::duplicate_model_timestamp(
                             model.name(),
-                            field_name,
-                            field.r#type.to_string(),
-                        ))
+                            kind.clone(),
+                        ));
                     }
-                }
-                ast::Type::Scalar(ast::r#type::Scalar::Owned(name)) => {
-                    if model_names.contains(name) {
-                        model.insert_one_to_one(field_name, name.clone())
-                    } else {
-                        Err(TypeError::unknown_model_field_type(
+                    Some(field_name) => {
+                        *field_name =
+                            Some(name.clone().unwrap_or_else(|| kind.clone()));
+                    }
+                    None => {
+                        errors.push(TypeError::unknown_model_timestamp(
                             model.name(),
-                            field_name,
-                            field.r#type.to_string(),
-                        ))
+                            kind.clone(),
+                        ));
                     }
                 }
-                ast::Type::Array(ast::r#type::Scalar::Boolean) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::Boolean,
-                        cardinality: Cardinality::Many,
-                    })
-                }
-                ast::Type::Array(ast::r#type::Scalar::DateTime) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::DateTime,
-                        cardinality: Cardinality::Many,
-                    })
-                }
-                ast::Type::Array(ast::r#type::Scalar::Float) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::Float,
-                        cardinality: Cardinality::Many,
-                    })
+            }
+        }
+
+        if let Err(error) = model.insert_timestamps(
+            timestamps.unwrap_or_else(model::Timestamps::standard),
+        ) {
+            errors.push(error);
+        }
+
+        for field in fields.values() {
+            let field_name = field.name.clone();
+            let cardinality = Cardinality::from(&field.r#type);
+            if let Err(error) = match field.r#type.scalar() {
+                ast::r#type::Scalar::Reference(name)
+                    if enum_names.contains(name) =>
+                {
+                    match cardinality {
+                        Cardinality::One => {
+                            model.insert_enum_relation(field_name, name.clone())
+                        }
+                        Cardinality::Optional => {
+                            model.insert_optional_enum_relation(
+                                field_name,
+                                name.clone(),
+                            )
+                        }
+                        Cardinality::Many => {
+                            model
+                                .insert_enums_relation(field_name, name.clone())
+                        }
+                    }
                 }
-                ast::Type::Array(ast::r#type::Scalar::Int) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::Int,
-                        cardinality: Cardinality::Many,
-                    })
+                ast::r#type::Scalar::Reference(name)
+                    if model_names.contains(name) =>
+                {
+                    match cardinality {
+                        Cardinality::One => {
+                            model.insert_many_to_one(field_name, name.clone())
+                        }
+                        Cardinality::Optional => {
+                            model.insert_optional_many_to_one(
+                                field_name,
+                                name.clone(),
+                            )
+                        }
+                        Cardinality::Many => {
+                            model.insert_many_to_many(field_name, name.clone())
+                        }
+                    }
                 }
-                ast::Type::Array(ast::r#type::Scalar::String) => {
-                    model.insert_field(model::Field {
-                        name: field_name,
-                        r#type: Type::String,
-                        cardinality: Cardinality::Many,
-                    })
+                ast::r#type::Scalar::Owned(name)
+                    if model_names.contains(name) =>
+                {
+                    match cardinality {
+                        Cardinality::One => {
+                            model.insert_one_to_one(field_name, name.clone())
+                        }
+                        Cardinality::Optional => {
+                            model.insert_optional_one_to_one(
+                                field_name,
+                                name.clone(),
+                            )
+                        }
+                        Cardinality::Many => {
+                            model.insert_one_to_many(field_name, name.clone())
+                        }
+                    }
                 }
-                ast::Type::Array(ast::r#type::Scalar::Reference(name)) => {
-                    if enum_names.contains(name) {
-                        model.insert_enums_relation(field_name, name.clone())
-                    } else if model_names.contains(name) {
-                        model.insert_many_to_many(field_name, name.clone())
-                    } else {
-                        Err(TypeError::unknown_model_field_type(
-                            model.name(),
-                            field_name,
-                            field.r#type.to_string(),
-                        ))
+                scalar => {
+                    match Type::from_ast_scalar(scalar) {
+                        Some(r#type) => {
+                            model.insert_field(model::Field {
+                                name: field_name,
+                                r#type,
+                                cardinality,
+                                doc: None,
+                            })
+                        }
+                        None => {
+                            Err(TypeError::unknown_model_field_type(
+                                model.name(),
+                                field_name,
+                                field.r#type.to_string(),
+                            ))
+                        }
                     }
                 }
-                ast::Type::Array(ast::r#type::Scalar::Owned(name)) => {
-                    if model_names.contains(name) {
-                        model.insert_one_to_many(field_name, name.clone())
-                    } else {
-                        Err(TypeError::unknown_model_field_type(
-                            model.name(),
-                            field_name,
-                            field.r#type.to_string(),
+            } {
+                errors.push(error);
+
+                continue;
+            }
+
+            if let Some(doc) = &field.doc {
+                model.insert_field_doc(&field.name, doc.clone());
+            }
+
+            if cardinality == Cardinality::Many
+                && (model.fields.contains_key(&field.name)
+                    || model.enums.contains_key(&field.name))
+                && self.database.as_ref().is_some_and(|database| {
+                    !database.provider.supports_scalar_lists()
+                })
+            {
+                errors.push(TypeError::invalid_model_list(
+                    model.name(),
+                    field.name.clone(),
+                ));
+            }
+
+            for attribute in &field.attributes {
+                if let Err(error) = match attribute {
+                    ast::model::attribute::Field::Default(value) => {
+                        self.default_value(&model, &field.name, value).map(
+                            |value| {
+                                let _: Option<Value<'a>> = model
+                                    .defaults
+                                    .insert(field.name.clone(), value);
+                            },
+                        )
+                    }
+                    ast::model::attribute::Field::Db(native_type) => {
+                        self.native_type(
+                            &model,
+                            &field.name,
+                            native_type,
+                            database_declared,
+                        )
+                        .map(|native_type| {
+                            let _: Option<model::NativeType<'a>> = model
+                                .native_types
+                                .insert(field.name.clone(), native_type);
+                        })
+                    }
+                    ast::model::attribute::Field::Inverse(name) => {
+                        model.relations.get_mut(&field.name).map_or_else(
+                            || {
+                                Err(TypeError::invalid_model_attribute(
+                                    ast_model.name.clone(),
+                                    field.name.clone(),
+                                    "inverse",
+                                ))
+                            },
+                            |relation| {
+                                relation.inverse_name.clone_from(name);
+
+                                Ok(())
+                            },
+                        )
+                    }
+                    ast::model::attribute::Field::OnDelete(action)
+                    | ast::model::attribute::Field::OnUpdate(action) => {
+                        self.referential_action(
+                            &model,
+                            &field.name,
+                            attribute.name(),
+                            action,
+                        )
+                        .map(|action| {
+                            if let Some(relation) =
+                                model.relations.get_mut(&field.name)
+                            {
+                                if let ast::model::attribute::Field::OnDelete(
+                                    _,
+                                ) = attribute
+                                {
+                                    relation.on_delete = Some(action);
+                                } else {
+                                    relation.on_update = Some(action);
+                                }
+                            }
+                        })
+                    }
+                    ast::model::attribute::Field::Id => {
+                        ids.push(model::Id::Field(field.name.clone()));
+
+                        Ok(())
+                    }
+                    ast::model::attribute::Field::Unique => {
+                        model.insert_unique(field.name.clone())
+                    }
+                } {
+                    errors.push(error);
+                }
+            }
+        }
+
+        for attribute in &ast_model.attributes {
+            if let Err(error) = match attribute {
+                ast::model::attribute::Block::Id(fields) => {
+                    ids.push(model::Id::Composite(fields.clone()));
+
+                    Ok(())
+                }
+                ast::model::attribute::Block::IdStrategy(name) => {
+                    model::id::Strategy::from_name(name).map_or_else(
+                        || {
+                            Err(TypeError::unknown_model_id_strategy(
+                                model.name(),
+                                name.clone(),
+                            ))
+                        },
+                        |strategy| {
+                            ids.push(model::Id::Generated(strategy));
+
+                            Ok(())
+                        },
+                    )
+                }
+                ast::model::attribute::Block::Timestamps(_) => Ok(()),
+                ast::model::attribute::Block::Index(fields)
+                | ast::model::attribute::Block::Unique(fields) => {
+                    model.insert_index(model::Index {
+                        fields: fields.clone(),
+                        unique: matches!(
+                            attribute,
+                            ast::model::attribute::Block::Unique(_)
+                        ),
+                    })
+                }
+            } {
+                errors.push(error);
+            }
+        }
+
+        if ids.len() > 1 {
+            errors.push(TypeError::duplicate_model_id(model.name()));
+        } else if let Some(id) = ids.pop() {
+            if let Err(error) = model.insert_id(id) {
+                errors.push(error);
+            }
+        }
+
+        if let Err(error) = self.insert_model(model) {
+            errors.push(error);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Pair every relation with its inverse. A relation is paired with the
+    /// field of the related model that it names as its inverse, if that
+    /// field is a relation back to it. Otherwise the inverse is generated.
+    /// A generated inverse whose default name is already taken by a field of
+    /// the related model, as in models that refer to each other, is named
+    /// after the relation instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `explicit_inverses` - The model and field names of the relations with
+    ///   an `@inverse` attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` for every relation whose inverse is declared but
+    /// does not match it, for every relation that is declared as its own
+    /// inverse, for every generated inverse with the same name as another
+    /// field, and for every referential action declared on the side of a
+    /// pair that does not hold the foreign key.
+    #[allow(clippy::too_many_lines)]
+    fn resolve_inverses(
+        &mut self,
+        explicit_inverses: &BTreeSet<(Cow<'a, str>, Cow<'a, str>)>,
+    ) -> Result<(), Vec<TypeError<'a>>> {
+        let mut named_inverses = explicit_inverses.clone();
+
+        for (model_name, field_name) in explicit_inverses {
+            let Some(relation) = self
+                .models
+                .get(model_name)
+                .and_then(|model| model.relations.get(field_name))
+                .cloned()
+            else {
+                continue;
+            };
+
+            if explicit_inverses.contains(&(
+                relation.model_name.clone(),
+                relation.inverse_name.clone(),
+            )) {
+                continue;
+            }
+
+            if let Some(inverse) =
+                self.models.get_mut(&relation.model_name).and_then(|model| {
+                    model.relations.get_mut(&relation.inverse_name)
+                })
+            {
+                if inverse.model_name == *model_name {
+                    inverse.inverse_name.clone_from(field_name);
+
+                    let _: bool = named_inverses
+                        .insert((relation.model_name, relation.inverse_name));
+                }
+            }
+        }
+
+        let models = self.models.clone();
+        let mut generated = BTreeSet::new();
+        let mut errors = Vec::new();
+
+        for model in models.values() {
+            for (field_name, relation) in model.relations.iter() {
+                let Some(related_model) = models.get(&relation.model_name)
+                else {
+                    continue;
+                };
+
+                let mut inverse_name = relation.inverse_name.clone();
+
+                let named = named_inverses.contains(&(
+                    model.name.clone(),
+                    field_name.to_owned().into(),
+                ));
+
+                if let Some(inverse) = related_model
+                    .relations
+                    .get(&inverse_name)
+                    .filter(|inverse| {
+                        inverse.model_name == model.name
+                            && inverse.inverse_name == field_name
+                    })
+                {
+                    let is_first = (model.name.as_ref(), field_name)
+                        <= (related_model.name.as_ref(), inverse_name.as_ref());
+
+                    let is_self = model.name == related_model.name
+                        && field_name == inverse_name;
+
+                    let is_owner = match (relation.r#type, inverse.r#type) {
+                        _ if is_self => None,
+                        (
+                            model::model_relation::Type::ManyToOne,
+                            model::model_relation::Type::ManyToMany
+                            | model::model_relation::Type::OneToMany
+                            | model::model_relation::Type::OneToOne,
+                        ) => Some(true),
+                        (
+                            model::model_relation::Type::ManyToMany
+                            | model::model_relation::Type::OneToMany
+                            | model::model_relation::Type::OneToOne,
+                            model::model_relation::Type::ManyToOne,
+                        ) => Some(false),
+                        (
+                            model::model_relation::Type::ManyToMany,
+                            model::model_relation::Type::ManyToMany,
+                        ) => Some(is_first),
+                        _ => None,
+                    };
+
+                    if let Some(is_owner) = is_owner {
+                        let name = if is_owner {
+                            format!("{field_name}On{}", model.name)
+                        } else {
+                            format!("{inverse_name}On{}", related_model.name)
+                        };
+
+                        if !is_owner
+                            && (relation.on_delete.is_some()
+                                || relation.on_update.is_some())
+                        {
+                            errors.push(TypeError::invalid_model_attribute(
+                                model.name(),
+                                field_name.to_owned(),
+                                if relation.on_delete.is_some() {
+                                    "onDelete"
+                                } else {
+                                    "onUpdate"
+                                },
+                            ));
+                        }
+
+                        if let Some(relation) =
+                            self.models.get_mut(&model.name).and_then(|model| {
+                                model.relations.get_mut(field_name)
+                            })
+                        {
+                            relation.name = name.into();
+                            relation.inverse_declared = true;
+                        }
+
+                        continue;
+                    }
+
+                    // Two relations that only name each other by default,
+                    // such as two many-to-one relations between models that
+                    // refer to each other, are unrelated.
+                    if named
+                        || named_inverses.contains(&(
+                            related_model.name.clone(),
+                            inverse_name.clone(),
                         ))
+                    {
+                        if is_first {
+                            errors.push(TypeError::invalid_model_inverse(
+                                model.name(),
+                                field_name.to_owned(),
+                                inverse_name,
+                            ));
+                        }
+
+                        continue;
+                    }
+                }
+
+                if !named && related_model.keys.contains(&inverse_name) {
+                    inverse_name = format!("{field_name}Of").into();
+
+                    if let Some(relation) = self
+                        .models
+                        .get_mut(&model.name)
+                        .and_then(|model| model.relations.get_mut(field_name))
+                    {
+                        relation.inverse_name.clone_from(&inverse_name);
+                    }
+                }
+
+                if related_model.keys.contains(&inverse_name) {
+                    errors.push(TypeError::invalid_model_inverse(
+                        model.name(),
+                        field_name.to_owned(),
+                        inverse_name,
+                    ));
+                } else if !generated
+                    .insert((related_model.name.clone(), inverse_name.clone()))
+                {
+                    errors.push(TypeError::duplicate_model_inverse(
+                        model.name(),
+                        field_name.to_owned(),
+                        inverse_name,
+                    ));
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Check the ownership graph, in which every owned relation is an edge
+    /// from the model that declares it to the model that it refers to.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` for every model that is owned through more than
+    /// one relation, and for every cycle of owned relations, reported on the
+    /// first model of the cycle.
+    fn check_ownership(&self) -> Result<(), Vec<TypeError<'a>>> {
+        let owned_relations = self
+            .models
+            .values()
+            .map(|model| {
+                let relations = model
+                    .relations
+                    .iter()
+                    .filter(|(_, relation)| {
+                        matches!(
+                            relation.r#type,
+                            model::model_relation::Type::OneToMany
+                                | model::model_relation::Type::OneToOne
+                        )
+                    })
+                    .map(|(field_name, relation)| {
+                        (field_name, relation.model_name)
+                    })
+                    .collect::<Vec<_>>();
+
+                (model.name.as_ref(), relations)
+            })
+            .collect::<Vec<_>>();
+
+        let mut errors = Vec::new();
+
+        for model in self.models.values() {
+            let owners = owned_relations
+                .iter()
+                .flat_map(|(owner_name, relations)| {
+                    relations
+                        .iter()
+                        .filter(|(_, owned_name)| *owned_name == model.name)
+                        .map(move |(field_name, _)| (*owner_name, field_name))
+                })
+                .collect::<Vec<_>>();
+
+            // A model may own another model through several relations.
+            if owners
+                .iter()
+                .any(|(owner_name, _)| *owner_name != owners[0].0)
+            {
+                let owners = owners.iter().map(|(owner_name, field_name)| {
+                    format!("{owner_name}.{field_name}")
+                });
+
+                errors.push(TypeError::multiple_model_owners(
+                    model.name(),
+                    owners,
+                ));
+            }
+        }
+
+        for (start, (model_name, _)) in owned_relations.iter().enumerate() {
+            Self::ownership_cycles(
+                &owned_relations,
+                start,
+                model_name,
+                &mut Vec::new(),
+                &mut errors,
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Find the cycles of owned relations that pass through the given model
+    /// and otherwise only through models that come after it, so that every
+    /// cycle is found from its first model exactly once.
+    ///
+    /// # Arguments
+    ///
+    /// * `owned_relations` - The owned relations of every model, as field names
+    ///   and names of owned models, in model order.
+    /// * `start` - The index of the first model of the cycles.
+    /// * `current` - The name of the model at the end of the path.
+    /// * `path` - The owned relations from the first model to the current one,
+    ///   as model and field names.
+    /// * `errors` - The errors to report the cycles to.
+    fn ownership_cycles<'b>(
+        owned_relations: &'b [OwnedRelations<'b, 'a>],
+        start: usize,
+        current: &'b str,
+        path: &mut Vec<(&'b str, &'b str)>,
+        errors: &mut Vec<TypeError<'a>>,
+    ) {
+        let Some(&(start_name, _)) = owned_relations.get(start) else {
+            return;
+        };
+
+        let Some((_, relations)) =
+            owned_relations.iter().find(|(name, _)| *name == current)
+        else {
+            return;
+        };
+
+        for (field_name, owned_name) in relations {
+            path.push((current, field_name));
+
+            if owned_name == start_name {
+                errors.push(TypeError::cyclic_model_ownership(
+                    start_name.to_owned(),
+                    path.iter()
+                        .map(|(model_name, field_name)| {
+                            format!("{model_name}.{field_name}")
+                        })
+                        .chain([start_name.to_owned()]),
+                ));
+            } else if owned_relations
+                .iter()
+                .position(|(name, _)| name == owned_name)
+                .is_some_and(|index| index > start)
+                && !path.iter().any(|(model_name, _)| model_name == owned_name)
+            {
+                Self::ownership_cycles(
+                    owned_relations,
+                    start,
+                    owned_name,
+                    path,
+                    errors,
+                );
+            }
+
+            let _: Option<(&str, &str)> = path.pop();
+        }
+    }
+
+    /// Build a query condition from an AST condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The kind of declaration that the condition belongs to.
+    /// * `ast_arguments` - The AST arguments of the query.
+    /// * `query` - The query, with its arguments and return type.
+    /// * `ast_condition` - The AST condition.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the condition refers to an undefined argument,
+    /// if the argument or literal does not match the operator or the field,
+    /// or if the mode does not apply to the field. Returns `Ok(None)` if the
+    /// argument has an invalid type, which has already been reported.
+    fn query_condition(
+        &self,
+        owner: WhereOwner,
+        ast_arguments: &OrdStrMap<ast::query::Argument<'a>>,
+        query: &Query<'a>,
+        ast_condition: &ast::query::Condition<'a>,
+    ) -> Result<Option<query::Condition<'a>>, TypeError<'a>> {
+        let path = ast_condition.path.0.clone();
+        let model_name = query.r#type.model_name.clone();
+        let operator = ast_condition.operator.into();
+
+        let rhs = match &ast_condition.rhs {
+            ast::query::Operand::Argument(argument_name) => {
+                if !ast_arguments.contains_key(argument_name) {
+                    return Err(owner.undefined_argument(
+                        query.name.clone(),
+                        argument_name.clone(),
+                    ));
+                }
+
+                // An argument with an invalid type has already been reported.
+                let Some(argument) = query.arguments.get(argument_name) else {
+                    return Ok(None);
+                };
+
+                self.check_argument_type(
+                    &model_name,
+                    path.clone(),
+                    operator,
+                    argument,
+                )
+                .then(|| query::Operand::Argument(argument_name.clone()))
+            }
+            ast::query::Operand::Null => {
+                (matches!(
+                    operator,
+                    query::Operator::Equals | query::Operator::NotEquals
+                ) && self.field_cardinality(&model_name, path.clone())
+                    == Some(Cardinality::Optional))
+                .then_some(query::Operand::Null)
+            }
+            ast::query::Operand::Value(value) => {
+                self.check_value(&model_name, path.clone(), operator, value)
+                    .map(query::Operand::Value)
+            }
+        };
+
+        let Some(rhs) = rhs else {
+            return Err(owner.invalid_condition(
+                query.name.clone(),
+                ast_condition.path.to_string(),
+                ast_condition.rhs.argument_name().map_or_else(
+                    || ast_condition.rhs.to_string().into(),
+                    Clone::clone,
+                ),
+                ast_condition.operator.to_string(),
+            ));
+        };
+
+        if ast_condition.mode == ast::query::r#where::Mode::Insensitive
+            && self.field_type(&model_name, path.clone()) != Some(Type::String)
+        {
+            return Err(owner.invalid_condition_mode(
+                query.name.clone(),
+                ast_condition.path.to_string(),
+            ));
+        }
+
+        Ok(Some(query::Condition {
+            lhs: path,
+            operator,
+            rhs,
+            mode: ast_condition.mode.into(),
+        }))
+    }
+
+    /// Build query filters from AST filters, type checking every condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The kind of declaration that the filters belong to.
+    /// * `ast_arguments` - The AST arguments of the query.
+    /// * `query` - The query, with its arguments and return type.
+    /// * `ast_filters` - The AST filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns every `TypeError` found in the conditions of the filters.
+    fn query_filters(
+        &self,
+        owner: WhereOwner,
+        ast_arguments: &OrdStrMap<ast::query::Argument<'a>>,
+        query: &Query<'a>,
+        ast_filters: Vec<ast::query::Filter<'a>>,
+    ) -> Result<Vec<query::Filter<'a>>, Vec<TypeError<'a>>> {
+        let mut filters = Vec::new();
+        let mut errors = Vec::new();
+
+        for ast_filter in ast_filters {
+            let group = match ast_filter {
+                ast::query::Filter::And(ast_filters) => {
+                    self.query_filters(owner, ast_arguments, query, ast_filters)
+                        .map(query::Filter::And)
+                }
+                ast::query::Filter::Condition(ast_condition) => {
+                    match self.query_condition(
+                        owner,
+                        ast_arguments,
+                        query,
+                        &ast_condition,
+                    ) {
+                        Ok(Some(condition)) => {
+                            filters.push(query::Filter::Condition(condition));
+                        }
+                        Ok(None) => {}
+                        Err(error) => errors.push(error),
                     }
+
+                    continue;
+                }
+                ast::query::Filter::Not(ast_filters) => {
+                    self.query_filters(owner, ast_arguments, query, ast_filters)
+                        .map(query::Filter::Not)
                 }
-            }?;
+                ast::query::Filter::Or(ast_filters) => {
+                    self.query_filters(owner, ast_arguments, query, ast_filters)
+                        .map(query::Filter::Or)
+                }
+            };
+
+            match group {
+                Ok(filter) => filters.push(filter),
+                Err(group_errors) => errors.extend(group_errors),
+            }
         }
 
-        self.insert_model(model)
+        if errors.is_empty() {
+            Ok(filters)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Type check the value of a pagination clause.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast_query` - The AST query.
+    /// * `query` - The query, with its arguments.
+    /// * `clause` - The name of the clause.
+    /// * `ast_operand` - The AST value of the clause.
+    /// * `r#type` - The type that the value must have.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the value refers to an undefined argument, or
+    /// if it does not have the given type. Returns `Ok(None)` if the argument
+    /// has an invalid type, which has already been reported.
+    fn pagination_operand(
+        ast_query: &ast::Query<'a>,
+        query: &Query<'a>,
+        clause: &'a str,
+        ast_operand: &ast::query::Operand<'a>,
+        r#type: Type,
+    ) -> Result<Option<query::Operand<'a>>, TypeError<'a>> {
+        let operand = match ast_operand {
+            ast::query::Operand::Argument(argument_name) => {
+                if !ast_query.arguments.contains_key(argument_name) {
+                    return Err(TypeError::undefined_query_argument(
+                        ast_query.name.clone(),
+                        argument_name.clone(),
+                    ));
+                }
+
+                // An argument with an invalid type has already been reported.
+                let Some(argument) = query.arguments.get(argument_name) else {
+                    return Ok(None);
+                };
+
+                (argument.r#type == query::ArgumentType::Type(r#type)
+                    && argument.cardinality == Cardinality::One)
+                    .then(|| query::Operand::Argument(argument_name.clone()))
+            }
+            ast::query::Operand::Null => None,
+            ast::query::Operand::Value(value) => {
+                Value::from_ast_value(value, r#type).map(query::Operand::Value)
+            }
+        };
+
+        operand.map(Some).ok_or_else(|| {
+            TypeError::invalid_query_pagination_value(
+                ast_query.name.clone(),
+                clause,
+                ast_operand.to_string(),
+            )
+        })
     }
 
     /// Add an AST query to the IR.
@@ -542,14 +1779,22 @@ impl<'a> Ir<'a> {
     ///
     /// * `ast_query` - The AST query.
     /// * `enum_names` - The names of the enums.
+    ///
+    /// # Errors
+    ///
+    /// Returns every `TypeError` found in the query. The query is only added
+    /// if it has no errors.
+    #[allow(clippy::too_many_lines)]
     fn add_query(
         &mut self,
         ast_query: &ast::Query<'a>,
         enum_names: &BTreeSet<Cow<'a, str>>,
-    ) -> Result<(), TypeError<'a>> {
+    ) -> Result<(), Vec<TypeError<'a>>> {
+        let mut errors = Vec::new();
+
         if let Some(ast_where) = ast_query.r#where.clone() {
             if ast_query.schema.name != ast_where.name {
-                return Err(TypeError::invalid_query_where_name(
+                errors.push(TypeError::invalid_query_where_name(
                     ast_query.name.clone(),
                     ast_query.schema.name.clone(),
                     ast_where.name,
@@ -557,10 +1802,20 @@ impl<'a> Ir<'a> {
             }
         }
 
-        let (return_type, model) = self.query_return_type(
-            ast_query.name.clone(),
-            ast_query.r#type.clone(),
-        )?;
+        if ast_query.schema.nodes.is_empty() {
+            errors.push(TypeError::empty_query_schema(ast_query.name.clone()));
+        }
+
+        let (return_type, model) = match self
+            .query_return_type(ast_query.name.clone(), ast_query.r#type.clone())
+        {
+            Ok(return_type) => return_type,
+            Err(error) => {
+                errors.push(error);
+
+                return Err(errors);
+            }
+        };
 
         let mut query = Query::new(
             ast_query.name.clone(),
@@ -568,82 +1823,841 @@ impl<'a> Ir<'a> {
             ast_query.schema.name.clone(),
         );
 
+        query.doc.clone_from(&ast_query.doc);
+
         for (argument_name, ast_argument) in ast_query.arguments.iter() {
             if let Some(argument) =
                 query::Argument::from_ast_type(&ast_argument, enum_names)
             {
                 let _: Option<query::Argument<'a>> =
                     query.arguments.insert(argument_name, argument);
-            };
+            } else {
+                errors.push(TypeError::invalid_query_argument_type(
+                    ast_query.name.clone(),
+                    argument_name.to_owned(),
+                    ast_argument.r#type.to_string(),
+                ));
+            }
         }
 
-        query.schema =
-            self.query_schema(&ast_query.name, &ast_query.schema, &model)?;
+        match self.query_schema(&ast_query.name, &ast_query.schema, &model) {
+            Ok(schema) => query.schema = schema,
+            Err(schema_errors) => errors.extend(schema_errors),
+        }
 
         if let Some(ast::query::Where {
-            conditions: ast_conditions,
+            filters: ast_filters,
             name: alias,
         }) = ast_query.r#where.clone()
         {
-            let mut conditions = Vec::new();
-
-            for ast_condition in ast_conditions {
-                let path = ast_condition.path.0.clone();
-                let model_name = query.r#type.model_name.clone();
-
-                if !query.arguments.iter().any(|(name, argument)| {
-                    name == ast_condition.argument_name
-                        && self.check_argument_type(
-                            &model_name,
-                            path.clone(),
-                            argument.r#type,
-                        )
-                }) {
-                    return Err(TypeError::invalid_query_condition(
+            match self.query_filters(
+                WhereOwner::Query,
+                &ast_query.arguments,
+                &query,
+                ast_filters,
+            ) {
+                Ok(filters) => {
+                    query.r#where = Some(query::Where { alias, filters });
+                }
+                Err(filter_errors) => errors.extend(filter_errors),
+            }
+        }
+
+        if let Some(ast_order_by) = &ast_query.order_by {
+            let model_name = query.r#type.model_name.clone();
+
+            for ordering in &ast_order_by.orderings {
+                let path = ordering.path.0.clone();
+
+                if (self.field_type(&model_name, path.clone()).is_some()
+                    || self.enum_type(&model_name, path.clone()).is_some())
+                    && self.field_cardinality(&model_name, path.clone())
+                        != Some(Cardinality::Many)
+                    && self.is_to_one_path(&model_name, &path)
+                {
+                    query.order_by.push(query::Ordering {
+                        path,
+                        direction: ordering.direction.into(),
+                    });
+                } else {
+                    errors.push(TypeError::invalid_query_ordering(
                         ast_query.name.clone(),
-                        ast_condition.path.clone().to_string(),
-                        ast_condition.argument_name,
-                        ast_condition.operator.to_string(),
+                        ordering.path.to_string(),
                     ));
                 }
+            }
+        }
 
-                conditions.push(query::Condition {
-                    lhs: path.clone(),
-                    operator: ast_condition.operator.into(),
-                    rhs: ast_condition.argument_name.clone(),
-                });
+        if query.r#type.cardinality != Cardinality::Many {
+            for (clause, is_present) in [
+                ("take", ast_query.take.is_some()),
+                ("skip", ast_query.skip.is_some()),
+                ("cursor", ast_query.cursor.is_some()),
+            ] {
+                if is_present {
+                    errors.push(TypeError::invalid_query_pagination(
+                        ast_query.name.clone(),
+                        clause,
+                    ));
+                }
             }
+        }
 
-            query.r#where = Some(query::Where { alias, conditions });
+        if let Some(ast_take) = &ast_query.take {
+            match Self::pagination_operand(
+                ast_query,
+                &query,
+                "take",
+                ast_take,
+                Type::Int,
+            ) {
+                Ok(take) => query.take = take,
+                Err(error) => errors.push(error),
+            }
         }
 
-        if self.queries.insert(ast_query.name.clone(), query).is_some() {
-            return Err(TypeError::duplicate_query(ast_query.name.clone()));
+        if let Some(ast_skip) = &ast_query.skip {
+            match Self::pagination_operand(
+                ast_query,
+                &query,
+                "skip",
+                ast_skip,
+                Type::Int,
+            ) {
+                Ok(skip) => query.skip = skip,
+                Err(error) => errors.push(error),
+            }
         }
 
-        Ok(())
-    }
-}
+        if let Some(ast_cursor) = &ast_query.cursor {
+            if let Some(r#type) =
+                model.unique_field_type(&ast_cursor.field_name)
+            {
+                match Self::pagination_operand(
+                    ast_query,
+                    &query,
+                    "cursor",
+                    &ast_cursor.rhs,
+                    r#type,
+                ) {
+                    Ok(rhs) => {
+                        query.cursor = rhs.map(|rhs| {
+                            query::Cursor {
+                                field_name: ast_cursor.field_name.clone(),
+                                rhs,
+                            }
+                        });
+                    }
+                    Err(error) => errors.push(error),
+                }
+            } else {
+                errors.push(TypeError::invalid_query_cursor(
+                    ast_query.name.clone(),
+                    ast_cursor.field_name.clone(),
+                ));
+            }
+        }
 
-impl Default for Ir<'_> {
-    fn default() -> Self {
-        Self {
-            enums: OrdStrMap::new(),
-            models: OrdStrMap::new(),
-            queries: OrdStrMap::new(),
+        let used_argument_names = ast_query
+            .r#where
+            .iter()
+            .flat_map(ast::query::Where::conditions)
+            .map(|condition| &condition.rhs)
+            .chain(ast_query.take.iter())
+            .chain(ast_query.skip.iter())
+            .chain(ast_query.cursor.iter().map(|cursor| &cursor.rhs))
+            .filter_map(ast::query::Operand::argument_name)
+            .map(AsRef::as_ref)
+            .collect::<BTreeSet<&str>>();
+
+        for argument_name in ast_query.arguments.iter().map(|(name, _)| name) {
+            if !used_argument_names.contains(argument_name) {
+                errors.push(TypeError::unused_query_argument(
+                    ast_query.name.clone(),
+                    argument_name.to_owned(),
+                ));
+            }
         }
-    }
-}
 
-impl<'a> TryFrom<ast::Ast<'a>> for Ir<'a> {
-    type Error = TypeError<'a>;
+        if !errors.is_empty() {
+            return Err(errors);
+        }
 
-    fn try_from(value: ast::Ast<'a>) -> Result<Self, Self::Error> {
-        let ast::Ast {
-            models: ast_models,
-            enums: ast_enums,
-            queries: ast_queries,
-        } = value;
+        if self.queries.insert(ast_query.name.clone(), query).is_some() {
+            return Err(vec![TypeError::duplicate_query(
+                ast_query.name.clone(),
+            )]);
+        }
+
+        Ok(())
+    }
+
+    /// Type check an AST aggregate and add it to the IR.
+    ///
+    /// The arguments and the where clause of an aggregate are checked like
+    /// those of a query, and errors in them are reported as query errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast_aggregate` - The AST aggregate.
+    /// * `enum_names` - The names of all enums.
+    ///
+    /// # Errors
+    ///
+    /// Returns every `TypeError` found in the aggregate.
+    #[allow(clippy::too_many_lines)]
+    fn add_aggregate(
+        &mut self,
+        ast_aggregate: &ast::Aggregate<'a>,
+        enum_names: &BTreeSet<Cow<'a, str>>,
+    ) -> Result<(), Vec<TypeError<'a>>> {
+        let aggregate_name = ast_aggregate.name.clone();
+        let model_name = ast_aggregate.model_name.clone();
+
+        let Some(model) = self.models.get(&model_name) else {
+            return Err(vec![TypeError::undefined_aggregate_model(
+                aggregate_name,
+                model_name,
+            )]);
+        };
+
+        let mut errors = Vec::new();
+        let mut aggregate =
+            Aggregate::new(aggregate_name.clone(), model_name.clone());
+
+        aggregate.doc.clone_from(&ast_aggregate.doc);
+        aggregate.count = ast_aggregate.count;
+
+        if !ast_aggregate.count && ast_aggregate.selections.is_empty() {
+            errors.push(TypeError::empty_aggregate(aggregate_name.clone()));
+        }
+
+        for selection in &ast_aggregate.selections {
+            let function = aggregate::Function::from(selection.function);
+
+            for field_name in &selection.field_names {
+                match model.field(field_name).and_then(|field| {
+                    (field.cardinality != Cardinality::Many)
+                        .then(|| function.result_type(field.r#type))
+                        .flatten()
+                }) {
+                    Some(r#type) => {
+                        aggregate.aggregations.push(aggregate::Aggregation {
+                            function,
+                            field_name: field_name.clone(),
+                            r#type,
+                        });
+                    }
+                    None => {
+                        errors.push(TypeError::invalid_aggregation(
+                            aggregate_name.clone(),
+                            function.to_string(),
+                            field_name.clone(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        for field_name in &ast_aggregate.group_by {
+            let r#type = model.field(field_name).map_or_else(
+                || {
+                    model.enum_relation(field_name).map(|relation| {
+                        query::ArgumentType::Enum(relation.name)
+                    })
+                },
+                |field| Some(query::ArgumentType::Type(field.r#type)),
+            );
+
+            match (r#type, model.scalar_cardinality(field_name)) {
+                (Some(r#type), Some(cardinality))
+                    if cardinality != Cardinality::Many =>
+                {
+                    aggregate.group_by.push(aggregate::GroupBy {
+                        field_name: field_name.clone(),
+                        r#type,
+                        cardinality,
+                    });
+                }
+                _ => {
+                    errors.push(TypeError::invalid_aggregate_group_by(
+                        aggregate_name.clone(),
+                        field_name.clone(),
+                    ));
+                }
+            }
+        }
+
+        for (argument_name, ast_argument) in ast_aggregate.arguments.iter() {
+            if let Some(argument) =
+                query::Argument::from_ast_type(&ast_argument, enum_names)
+            {
+                let _: Option<query::Argument<'a>> =
+                    aggregate.arguments.insert(argument_name, argument);
+            } else {
+                errors.push(TypeError::invalid_query_argument_type(
+                    aggregate_name.clone(),
+                    argument_name.to_owned(),
+                    ast_argument.r#type.to_string(),
+                ));
+            }
+        }
+
+        if let Some(ast::query::Where {
+            filters: ast_filters,
+            name: alias,
+        }) = ast_aggregate.r#where.clone()
+        {
+            let mut query = Query::new(
+                aggregate_name.clone(),
+                query::ReturnType {
+                    model_name,
+                    cardinality: Cardinality::Many,
+                },
+                alias.clone(),
+            );
+
+            query.arguments = aggregate.arguments.clone();
+
+            match self.query_filters(
+                WhereOwner::Query,
+                &ast_aggregate.arguments,
+                &query,
+                ast_filters,
+            ) {
+                Ok(filters) => {
+                    aggregate.r#where = Some(query::Where { alias, filters });
+                }
+                Err(filter_errors) => errors.extend(filter_errors),
+            }
+        }
+
+        let used_argument_names = ast_aggregate
+            .r#where
+            .iter()
+            .flat_map(ast::query::Where::conditions)
+            .filter_map(|condition| condition.rhs.argument_name())
+            .map(AsRef::as_ref)
+            .collect::<BTreeSet<&str>>();
+
+        for argument_name in
+            ast_aggregate.arguments.iter().map(|(name, _)| name)
+        {
+            if !used_argument_names.contains(argument_name) {
+                errors.push(TypeError::unused_query_argument(
+                    aggregate_name.clone(),
+                    argument_name.to_owned(),
+                ));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        if self
+            .aggregates
+            .insert(aggregate_name.clone(), aggregate)
+            .is_some()
+        {
+            return Err(vec![TypeError::duplicate_aggregate(aggregate_name)]);
+        }
+
+        Ok(())
+    }
+
+    /// Type check the operand of an assignment against the type and the
+    /// cardinality of the assigned field. Returns `None` if the operand does
+    /// not fit.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation` - The mutation, with its arguments.
+    /// * `ast_operand` - The AST operand.
+    /// * `r#type` - The type of the field.
+    /// * `cardinality` - The cardinality of the field.
+    fn mutation_operand(
+        &self,
+        mutation: &Mutation<'a>,
+        ast_operand: &ast::query::Operand<'a>,
+        r#type: &query::ArgumentType<'a>,
+        cardinality: Cardinality,
+    ) -> Option<query::Operand<'a>> {
+        match ast_operand {
+            ast::query::Operand::Argument(argument_name) => {
+                let argument = mutation.arguments.get(argument_name)?;
+
+                (argument.r#type == *r#type
+                    && (argument.cardinality == cardinality
+                        || (argument.cardinality == Cardinality::One
+                            && cardinality == Cardinality::Optional)))
+                    .then(|| query::Operand::Argument(argument_name.clone()))
+            }
+            ast::query::Operand::Null => {
+                (cardinality == Cardinality::Optional)
+                    .then_some(query::Operand::Null)
+            }
+            ast::query::Operand::Value(value) => {
+                if cardinality == Cardinality::Many {
+                    return None;
+                }
+
+                match r#type {
+                    query::ArgumentType::Enum(enum_name) => {
+                        Value::from_ast_enum_value(
+                            value,
+                            self.enums.get(enum_name)?,
+                        )
+                    }
+                    query::ArgumentType::Type(r#type) => {
+                        Value::from_ast_value(value, *r#type)
+                    }
+                }
+                .map(query::Operand::Value)
+            }
+        }
+    }
+
+    /// Build a mutation assignment from an AST assignment.
+    ///
+    /// A field is set to an operand of its own type. A relation is connected
+    /// to the id of a record of the related model, which must have a single
+    /// id field, and only optional relations to a single record can be
+    /// disconnected.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast_arguments` - The AST arguments of the mutation.
+    /// * `mutation` - The mutation, with its arguments.
+    /// * `ast_assignment` - The AST assignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the assignment refers to an undefined
+    /// argument, or if the operand does not fit the field. Returns `Ok(None)`
+    /// if the argument has an invalid type, which has already been reported.
+    fn mutation_assignment(
+        &self,
+        ast_arguments: &OrdStrMap<ast::query::Argument<'a>>,
+        mutation: &Mutation<'a>,
+        ast_assignment: &ast::mutation::Assignment<'a>,
+    ) -> Result<Option<mutation::Assignment<'a>>, TypeError<'a>> {
+        let field_name = ast_assignment.field_name.clone();
+        let model = self.models.get(&mutation.model_name);
+
+        let relation = model
+            .and_then(|model| model.model_relation(&field_name))
+            .map(|relation| (relation.r#type, relation.optional));
+
+        let (ast_operand, expected) = match &ast_assignment.action {
+            ast::mutation::Action::Connect(ast_operand) => {
+                let id_type = model
+                    .and_then(|model| model.model_relation(&field_name))
+                    .and_then(|relation| self.models.get(&relation.model_name))
+                    .and_then(|related_model| {
+                        match related_model.id_fields().as_slice() {
+                            [(_, r#type)] => Some(*r#type),
+                            _ => None,
+                        }
+                    });
+
+                (
+                    ast_operand,
+                    id_type.map(|r#type| {
+                        (query::ArgumentType::Type(r#type), Cardinality::One)
+                    }),
+                )
+            }
+            ast::mutation::Action::Disconnect => {
+                return if matches!(
+                    relation,
+                    Some(
+                        (model::model_relation::Type::OneToOne, _)
+                            | (model::model_relation::Type::ManyToOne, true)
+                    )
+                ) {
+                    Ok(Some(mutation::Assignment {
+                        field_name,
+                        action: mutation::Action::Disconnect,
+                    }))
+                } else {
+                    Err(TypeError::invalid_mutation_disconnect(
+                        mutation.name.clone(),
+                        field_name,
+                    ))
+                };
+            }
+            ast::mutation::Action::Set(ast_operand) => {
+                let field = model.and_then(|model| {
+                    model.field(&field_name).map_or_else(
+                        || {
+                            model.enum_relation(&field_name).map(|relation| {
+                                (
+                                    query::ArgumentType::Enum(relation.name),
+                                    relation.cardinality,
+                                )
+                            })
+                        },
+                        |field| {
+                            Some((
+                                query::ArgumentType::Type(field.r#type),
+                                field.cardinality,
+                            ))
+                        },
+                    )
+                });
+
+                (ast_operand, field)
+            }
+        };
+
+        if let ast::query::Operand::Argument(argument_name) = ast_operand {
+            if !ast_arguments.contains_key(argument_name) {
+                return Err(TypeError::undefined_mutation_argument(
+                    mutation.name.clone(),
+                    argument_name.clone(),
+                ));
+            }
+
+            // An argument with an invalid type has already been reported.
+            if !mutation.arguments.contains_key(argument_name) {
+                return Ok(None);
+            }
+        }
+
+        let Some(operand) = expected.and_then(|(r#type, cardinality)| {
+            self.mutation_operand(mutation, ast_operand, &r#type, cardinality)
+        }) else {
+            return Err(TypeError::invalid_mutation_assignment(
+                mutation.name.clone(),
+                field_name,
+                ast_operand.argument_name().map_or_else(
+                    || ast_operand.to_string().into(),
+                    Clone::clone,
+                ),
+            ));
+        };
+
+        let action = match ast_assignment.action {
+            ast::mutation::Action::Connect(_) => {
+                mutation::Action::Connect(operand)
+            }
+            _ => mutation::Action::Set(operand),
+        };
+
+        Ok(Some(mutation::Assignment { field_name, action }))
+    }
+
+    /// Build mutation assignments from AST assignments.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast_arguments` - The AST arguments of the mutation.
+    /// * `mutation` - The mutation, with its arguments.
+    /// * `ast_assignments` - The AST assignments.
+    ///
+    /// # Errors
+    ///
+    /// Returns every `TypeError` found in the assignments.
+    fn mutation_assignments(
+        &self,
+        ast_arguments: &OrdStrMap<ast::query::Argument<'a>>,
+        mutation: &Mutation<'a>,
+        ast_assignments: &[ast::mutation::Assignment<'a>],
+    ) -> Result<Vec<mutation::Assignment<'a>>, Vec<TypeError<'a>>> {
+        let mut assignments = Vec::new();
+        let mut errors = Vec::new();
+
+        for ast_assignment in ast_assignments {
+            match self.mutation_assignment(
+                ast_arguments,
+                mutation,
+                ast_assignment,
+            ) {
+                Ok(Some(assignment)) => assignments.push(assignment),
+                Ok(None) => {}
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(assignments)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Check whether filters select at most one record of a model: whether
+    /// they require every field of the id, of a unique field or of a unique
+    /// index to equal a value. Only
+    /// conditions that must all hold, at the top level or in `and` groups,
+    /// are taken into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The model.
+    /// * `filters` - The filters of the where clause.
+    fn selects_unique_record(
+        model: &Model<'a>,
+        filters: &[query::Filter<'a>],
+    ) -> bool {
+        let mut field_names = BTreeSet::new();
+        let mut filters = filters.iter().collect::<Vec<_>>();
+
+        while let Some(filter) = filters.pop() {
+            match filter {
+                query::Filter::And(group) => filters.extend(group),
+                query::Filter::Condition(query::Condition {
+                    lhs,
+                    operator: query::Operator::Equals,
+                    rhs,
+                    ..
+                }) if lhs.len() == 1
+                    && !matches!(rhs, query::Operand::Null) =>
+                {
+                    let _: bool = field_names.insert(lhs[0].as_ref());
+                }
+                _ => {}
+            }
+        }
+
+        model.unique_keys().iter().any(|fields| {
+            fields
+                .iter()
+                .all(|name| field_names.contains(name.as_ref()))
+        })
+    }
+
+    /// Type check an AST mutation and add it to the IR.
+    ///
+    /// The where clause of a mutation is checked like that of a query, and
+    /// must select a single record by its id or a unique field.
+    ///
+    /// # Arguments
+    ///
+    /// * `ast_mutation` - The AST mutation.
+    /// * `enum_names` - The names of all enums.
+    ///
+    /// # Errors
+    ///
+    /// Returns every `TypeError` found in the mutation.
+    #[allow(clippy::too_many_lines)]
+    fn add_mutation(
+        &mut self,
+        ast_mutation: &ast::Mutation<'a>,
+        enum_names: &BTreeSet<Cow<'a, str>>,
+    ) -> Result<(), Vec<TypeError<'a>>> {
+        let mutation_name = ast_mutation.name.clone();
+        let model_name = ast_mutation.model_name.clone();
+
+        let Some(model) = self.models.get(&model_name) else {
+            return Err(vec![TypeError::undefined_mutation_model(
+                mutation_name,
+                model_name,
+            )]);
+        };
+
+        let mut errors = Vec::new();
+        let mut mutation = Mutation::new(
+            mutation_name.clone(),
+            model_name.clone(),
+            mutation::Operation::Delete,
+        );
+
+        mutation.doc.clone_from(&ast_mutation.doc);
+
+        for (argument_name, ast_argument) in ast_mutation.arguments.iter() {
+            if let Some(argument) =
+                query::Argument::from_ast_type(&ast_argument, enum_names)
+            {
+                let _: Option<query::Argument<'a>> =
+                    mutation.arguments.insert(argument_name, argument);
+            } else {
+                errors.push(TypeError::invalid_mutation_argument_type(
+                    mutation_name.clone(),
+                    argument_name.to_owned(),
+                    ast_argument.r#type.to_string(),
+                ));
+            }
+        }
+
+        if let ast::mutation::Operation::Create(ast_assignments)
+        | ast::mutation::Operation::Upsert {
+            create: ast_assignments,
+            ..
+        } = &ast_mutation.operation
+        {
+            for field_name in model.required_fields() {
+                if !ast_assignments.iter().any(|assignment| {
+                    assignment.field_name == field_name
+                        && assignment.action
+                            != ast::mutation::Action::Disconnect
+                }) {
+                    errors.push(TypeError::missing_mutation_field(
+                        mutation_name.clone(),
+                        field_name,
+                    ));
+                }
+            }
+        }
+
+        let arguments = &ast_mutation.arguments;
+
+        let operation = match &ast_mutation.operation {
+            ast::mutation::Operation::Create(create) => {
+                self.mutation_assignments(arguments, &mutation, create)
+                    .map(mutation::Operation::Create)
+            }
+            ast::mutation::Operation::Delete => Ok(mutation::Operation::Delete),
+            ast::mutation::Operation::Update(update) => {
+                self.mutation_assignments(arguments, &mutation, update)
+                    .map(mutation::Operation::Update)
+            }
+            ast::mutation::Operation::Upsert { create, update } => {
+                match (
+                    self.mutation_assignments(arguments, &mutation, create),
+                    self.mutation_assignments(arguments, &mutation, update),
+                ) {
+                    (Ok(create), Ok(update)) => {
+                        Ok(mutation::Operation::Upsert { create, update })
+                    }
+                    (create, update) => {
+                        Err(create
+                            .err()
+                            .into_iter()
+                            .chain(update.err())
+                            .flatten()
+                            .collect())
+                    }
+                }
+            }
+        };
+
+        match operation {
+            Ok(operation) => mutation.operation = operation,
+            Err(assignment_errors) => errors.extend(assignment_errors),
+        }
+
+        match (&ast_mutation.operation, ast_mutation.r#where.clone()) {
+            (ast::mutation::Operation::Create(_), Some(_)) => {
+                errors.push(TypeError::unexpected_mutation_where(
+                    mutation_name.clone(),
+                ));
+            }
+            (ast::mutation::Operation::Create(_), None) => {}
+            (_, None) => {
+                errors.push(TypeError::missing_mutation_where(
+                    mutation_name.clone(),
+                ));
+            }
+            (
+                _,
+                Some(ast::query::Where {
+                    filters: ast_filters,
+                    name: alias,
+                }),
+            ) => {
+                let mut query = Query::new(
+                    mutation_name.clone(),
+                    query::ReturnType {
+                        model_name,
+                        cardinality: Cardinality::One,
+                    },
+                    alias.clone(),
+                );
+
+                query.arguments = mutation.arguments.clone();
+
+                match self.query_filters(
+                    WhereOwner::Mutation,
+                    arguments,
+                    &query,
+                    ast_filters,
+                ) {
+                    Ok(filters) => {
+                        if !Self::selects_unique_record(model, &filters) {
+                            errors.push(TypeError::invalid_mutation_where(
+                                mutation_name.clone(),
+                            ));
+                        }
+
+                        mutation.r#where =
+                            Some(query::Where { alias, filters });
+                    }
+                    Err(filter_errors) => errors.extend(filter_errors),
+                }
+            }
+        }
+
+        let used_argument_names = ast_mutation
+            .r#where
+            .iter()
+            .flat_map(ast::query::Where::conditions)
+            .map(|condition| &condition.rhs)
+            .chain(ast_mutation.operation.assignments().into_iter().filter_map(
+                |assignment| {
+                    match &assignment.action {
+                        ast::mutation::Action::Connect(operand)
+                        | ast::mutation::Action::Set(operand) => Some(operand),
+                        ast::mutation::Action::Disconnect => None,
+                    }
+                },
+            ))
+            .filter_map(ast::query::Operand::argument_name)
+            .map(AsRef::as_ref)
+            .collect::<BTreeSet<&str>>();
+
+        for argument_name in ast_mutation.arguments.iter().map(|(name, _)| name)
+        {
+            if !used_argument_names.contains(argument_name) {
+                errors.push(TypeError::unused_mutation_argument(
+                    mutation_name.clone(),
+                    argument_name.to_owned(),
+                ));
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        if self
+            .mutations
+            .insert(mutation_name.clone(), mutation)
+            .is_some()
+        {
+            return Err(vec![TypeError::duplicate_mutation(mutation_name)]);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Ir<'_> {
+    fn default() -> Self {
+        Self {
+            database: None,
+            enums: OrdStrMap::new(),
+            models: OrdStrMap::new(),
+            queries: OrdStrMap::new(),
+            aggregates: OrdStrMap::new(),
+            mutations: OrdStrMap::new(),
+        }
+    }
+}
+
+impl<'a> TryFrom<ast::Ast<'a>> for Ir<'a> {
+    type Error = Vec<TypeError<'a>>;
+
+    fn try_from(value: ast::Ast<'a>) -> Result<Self, Self::Error> {
+        let ast::Ast {
+            aggregates: ast_aggregates,
+            database: ast_database,
+            models: ast_models,
+            mutations: ast_mutations,
+            enums: ast_enums,
+            queries: ast_queries,
+        } = value;
 
         let enum_names = ast_enums
             .values()
@@ -655,21 +2669,87 @@ impl<'a> TryFrom<ast::Ast<'a>> for Ir<'a> {
             .map(|m| m.name.clone())
             .collect::<BTreeSet<_>>();
 
+        let explicit_inverses = ast_models
+            .values()
+            .flat_map(|model| {
+                model
+                    .fields
+                    .values()
+                    .filter(|field| {
+                        field.attributes.iter().any(|attribute| {
+                            matches!(
+                                attribute,
+                                ast::model::attribute::Field::Inverse(_)
+                            )
+                        })
+                    })
+                    .map(|field| (model.name.clone(), field.name.clone()))
+            })
+            .collect::<BTreeSet<_>>();
+
         let mut ir = Self::default();
+        let mut errors = Vec::new();
 
-        for model in ast_models.values() {
-            ir.add_model(model, &enum_names, &model_names)?;
+        if let Some(ast_database) = &ast_database {
+            let (database, database_errors) =
+                Database::from_ast_with_recovery(ast_database);
+
+            ir.database = database;
+            errors.extend(database_errors);
         }
 
         for ast_enum in ast_enums.into_values() {
-            ir.insert_enum(ast_enum.into())?;
+            if let Err(error) = ir.insert_enum(ast_enum.into()) {
+                errors.push(error);
+            }
+        }
+
+        for model in ast_models.values() {
+            if let Err(model_errors) = ir.add_model(
+                model,
+                &enum_names,
+                &model_names,
+                ast_database.is_some(),
+            ) {
+                errors.extend(model_errors);
+            }
+        }
+
+        if let Err(inverse_errors) = ir.resolve_inverses(&explicit_inverses) {
+            errors.extend(inverse_errors);
+        }
+
+        if let Err(ownership_errors) = ir.check_ownership() {
+            errors.extend(ownership_errors);
         }
 
         for ast_query in ast_queries.values() {
-            ir.add_query(ast_query, &enum_names)?;
+            if let Err(query_errors) = ir.add_query(ast_query, &enum_names) {
+                errors.extend(query_errors);
+            }
+        }
+
+        for ast_aggregate in ast_aggregates.values() {
+            if let Err(aggregate_errors) =
+                ir.add_aggregate(ast_aggregate, &enum_names)
+            {
+                errors.extend(aggregate_errors);
+            }
         }
 
-        Ok(ir)
+        for ast_mutation in ast_mutations.values() {
+            if let Err(mutation_errors) =
+                ir.add_mutation(ast_mutation, &enum_names)
+            {
+                errors.extend(mutation_errors);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(ir)
+        } else {
+            Err(errors)
+        }
     }
 }
 
@@ -701,18 +2781,21 @@ mod tests {
             name: "name".into(),
             r#type: Type::String,
             cardinality: Cardinality::One,
+            doc: None,
         })?;
 
         address_model.insert_field(model::Field {
             name: "street".into(),
             r#type: Type::String,
             cardinality: Cardinality::One,
+            doc: None,
         })?;
 
         postbox_model.insert_field(model::Field {
             name: "number".into(),
             r#type: Type::Int,
             cardinality: Cardinality::One,
+            doc: None,
         })?;
 
         address_model.insert_one_to_one("postbox", "Postbox")?;
@@ -770,12 +2853,15 @@ mod tests {
         let address_type = Enum {
             name: "AddressType".into(),
             values: TokenSet::from_iter(["home", "work"]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         user_model.insert_field(model::Field {
             name: "name".into(),
             r#type: Type::String,
             cardinality: Cardinality::One,
+            doc: None,
         })?;
 
         user_model.insert_many_to_one("address", "Address")?;
@@ -829,12 +2915,15 @@ mod tests {
         let address_type = Enum {
             name: "AddressType".into(),
             values: TokenSet::from_iter(["home", "work"]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         user_model.insert_field(model::Field {
             name: "name".into(),
             r#type: Type::String,
             cardinality: Cardinality::One,
+            doc: None,
         })?;
 
         user_model.insert_many_to_one("address", "Address")?;
@@ -844,22 +2933,33 @@ mod tests {
         ir.insert_model(address_model)?;
         ir.insert_enum(address_type)?;
 
+        let argument = |r#type| {
+            query::Argument {
+                name: "argument".into(),
+                r#type,
+                cardinality: Cardinality::One,
+            }
+        };
+
         assert!(ir.check_argument_type(
             "User",
             once("name").map(Into::into).collect(),
-            query::ArgumentType::Type(Type::String)
+            query::Operator::Equals,
+            &argument(query::ArgumentType::Type(Type::String))
         ));
 
         assert!(ir.check_argument_type(
             "User",
             ["address", "type"].into_iter().map(Into::into).collect(),
-            query::ArgumentType::Enum("AddressType".into())
+            query::Operator::Equals,
+            &argument(query::ArgumentType::Enum("AddressType".into()))
         ));
 
         assert!(!ir.check_argument_type(
             "User",
             ["address", "street"].into_iter().map(Into::into).collect(),
-            query::ArgumentType::Type(Type::String)
+            query::Operator::Equals,
+            &argument(query::ArgumentType::Type(Type::String))
         ));
 
         assert!(!ir.check_argument_type(
@@ -868,7 +2968,8 @@ mod tests {
                 .into_iter()
                 .map(Into::into)
                 .collect(),
-            query::ArgumentType::Type(Type::String)
+            query::Operator::Equals,
+            &argument(query::ArgumentType::Type(Type::String))
         ));
 
         Ok(())
@@ -1063,76 +3164,99 @@ query myQuery($booleanArgument: Boolean): [Alpha] {
                         },
                         r#where: Some(query::Where {
                             alias: "alpha".into(),
-                            conditions: vec![query::Condition {
-                                lhs: once("myBoolean".into()).collect(),
-                                operator: query::Operator::Equals,
-                                rhs: "booleanArgument".into(),
-                            }]
+                            filters: vec![query::Filter::Condition(
+                                query::Condition {
+                                    lhs: once("myBoolean".into()).collect(),
+                                    operator: query::Operator::Equals,
+                                    rhs: query::Operand::Argument(
+                                        "booleanArgument".into()
+                                    ),
+                                    mode: query::Mode::Default,
+                                }
+                            )]
                         }),
+                        order_by: Vec::new(),
+                        take: None,
+                        skip: None,
+                        cursor: None,
+                        doc: None,
                     }
                 )]),
                 models: OrdStrMap::from_iter([
                     ("Alpha", {
                         let mut model = Model::new("Alpha");
 
+                        model
+                            .insert_timestamps(model::Timestamps::standard())?;
+
                         model.insert_field(model::Field {
                             name: "myBoolean".into(),
                             r#type: Type::Boolean,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myDateTime".into(),
                             r#type: Type::DateTime,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myFloat".into(),
                             r#type: Type::Float,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myInt".into(),
                             r#type: Type::Int,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myString".into(),
                             r#type: Type::String,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myBooleans".into(),
                             r#type: Type::Boolean,
                             cardinality: Cardinality::Many,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myDateTimes".into(),
                             r#type: Type::DateTime,
                             cardinality: Cardinality::Many,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myFloats".into(),
                             r#type: Type::Float,
                             cardinality: Cardinality::Many,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myInts".into(),
                             r#type: Type::Int,
                             cardinality: Cardinality::Many,
+                            doc: None,
                         })?;
 
                         model.insert_field(model::Field {
                             name: "myStrings".into(),
                             r#type: Type::String,
                             cardinality: Cardinality::Many,
+                            doc: None,
                         })?;
 
                         model.insert_enum_relation("myZeta", "Zeta")?;
@@ -1147,10 +3271,14 @@ query myQuery($booleanArgument: Boolean): [Alpha] {
                     ("Beta", {
                         let mut model = Model::new("Beta");
 
+                        model
+                            .insert_timestamps(model::Timestamps::standard())?;
+
                         model.insert_field(model::Field {
                             name: "foo".into(),
                             r#type: Type::String,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model.insert_many_to_one("myGamma", "Gamma")?;
@@ -1160,10 +3288,14 @@ query myQuery($booleanArgument: Boolean): [Alpha] {
                     ("Gamma", {
                         let mut model = Model::new("Gamma");
 
+                        model
+                            .insert_timestamps(model::Timestamps::standard())?;
+
                         model.insert_field(model::Field {
                             name: "foo".into(),
                             r#type: Type::String,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model
@@ -1171,10 +3303,14 @@ query myQuery($booleanArgument: Boolean): [Alpha] {
                     ("Delta", {
                         let mut model = Model::new("Delta");
 
+                        model
+                            .insert_timestamps(model::Timestamps::standard())?;
+
                         model.insert_field(model::Field {
                             name: "foo".into(),
                             r#type: Type::String,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model
@@ -1182,10 +3318,14 @@ query myQuery($booleanArgument: Boolean): [Alpha] {
                     ("Epsilon", {
                         let mut model = Model::new("Epsilon");
 
+                        model
+                            .insert_timestamps(model::Timestamps::standard())?;
+
                         model.insert_field(model::Field {
                             name: "foo".into(),
                             r#type: Type::String,
                             cardinality: Cardinality::One,
+                            doc: None,
                         })?;
 
                         model
@@ -1195,116 +3335,2548 @@ query myQuery($booleanArgument: Boolean): [Alpha] {
                     "Zeta",
                     Enum {
                         name: "Zeta".into(),
-                        values: TokenSet::from_iter(["Theta", "Iota"])
+                        values: TokenSet::from_iter(["Theta", "Iota"]),
+                        doc: None,
+                        value_docs: OrdStrMap::new(),
                     }
-                )])
+                )]),
+                database: None,
+                aggregates: OrdStrMap::new(),
+                mutations: OrdStrMap::new(),
             }
         );
 
-        Ok(())
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_ast_doc_comments() {
+        let input = "
+
+/// The side of the road that traffic drives on.
+enum DrivingSide {
+  /// As in the United Kingdom.
+  Left
+  Right
+}
+
+/// A country.
+model Country {
+  /// The top-level domain.
+  domain: String
+  /// The side of the road.
+  drivingSide: DrivingSide
+  /// The capital.
+  capital: City
+  population: Int
+}
+
+model City {
+  name: String
+}
+
+/// All countries.
+query countries: [Country] {
+  country {
+    domain
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(input).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let country = ir.models.get("Country").unwrap();
+        let driving_side = ir.enums.get("DrivingSide").unwrap();
+
+        assert_eq!(country.doc, Some("A country.".into()));
+
+        assert_eq!(
+            country.field("domain").unwrap().doc,
+            Some("The top-level domain.".into())
+        );
+
+        assert_eq!(
+            country.enum_relation("drivingSide").unwrap().doc,
+            Some("The side of the road.".into())
+        );
+
+        assert_eq!(
+            country.model_relation("capital").unwrap().doc,
+            Some("The capital.".into())
+        );
+
+        assert_eq!(country.field("population").unwrap().doc, None);
+
+        assert_eq!(ir.models.get("City").unwrap().doc, None);
+
+        assert_eq!(
+            driving_side.doc,
+            Some("The side of the road that traffic drives on.".into())
+        );
+
+        assert_eq!(
+            driving_side.value_docs,
+            OrdStrMap::from_iter([(
+                "Left",
+                "As in the United Kingdom.".into()
+            )])
+        );
+
+        assert_eq!(
+            ir.queries.get("countries").unwrap().doc,
+            Some("All countries.".into())
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_optional() -> Result<(), TypeError<'static>> {
+        let source = "
+
+enum Kind {
+  Photo
+}
+
+model Profile {
+  bio: String
+}
+
+model Country {
+  name: String
+}
+
+model Image {
+  caption: String?
+  kind: Kind?
+  country: Country?
+  profile: @Profile?
+}
+
+query images($caption: String?): [Image] {
+  image {
+    caption
+  }
+  where {
+    image {
+      caption {
+        equals: $caption
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let mut image = Model::new("Image");
+
+        image.insert_timestamps(model::Timestamps::standard())?;
+
+        image.insert_field(model::Field {
+            name: "caption".into(),
+            r#type: Type::String,
+            cardinality: Cardinality::Optional,
+            doc: None,
+        })?;
+
+        image.insert_optional_enum_relation("kind", "Kind")?;
+        image.insert_optional_many_to_one("country", "Country")?;
+        image.insert_optional_one_to_one("profile", "Profile")?;
+
+        assert_eq!(ir.models.get("Image"), Some(&image));
+
+        assert_eq!(
+            ir.queries
+                .get("images")
+                .unwrap()
+                .arguments
+                .get("caption")
+                .map(|argument| argument.cardinality),
+            Some(Cardinality::Optional)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_ast_attributes() {
+        let source = "
+
+enum Role {
+  Admin
+  Member
+}
+
+model User {
+  email: String @unique
+  visits: Int @default(0)
+  score: Float @default(1)
+  active: Boolean @default(true)
+  role: Role @default(Member)
+  joinedAt: DateTime @default(now())
+  @@index([email, role])
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let user = ir.models.get("User").unwrap();
+
+        assert_eq!(
+            user.defaults,
+            OrdStrMap::from_iter([
+                ("visits", Value::Int("0".into())),
+                ("score", Value::Float("1".into())),
+                ("active", Value::Boolean(true)),
+                ("role", Value::EnumValue("Member".into())),
+                ("joinedAt", Value::Now),
+            ])
+        );
+
+        assert_eq!(user.unique_fields, BTreeSet::from(["email".into()]));
+
+        assert_eq!(
+            user.indexes,
+            vec![model::Index {
+                fields: vec!["email".into(), "role".into()],
+                unique: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_attributes() {
+        let source = "
+
+enum Role {
+  Admin
+}
+
+model Address {
+  street: String
+}
+
+model User {
+  visits: Int @default(\"0\")
+  role: Role @default(Guest)
+  tags: [String] @unique
+  address: Address @default(1)
+  @@unique([email])
+  @@index([address])
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_model_default("User", "visits", "\"0\""),
+                TypeError::invalid_model_default("User", "role", "Guest"),
+                TypeError::invalid_model_attribute("User", "tags", "unique"),
+                TypeError::invalid_model_attribute(
+                    "User", "address", "default"
+                ),
+                TypeError::undefined_model_index_field("User", "email"),
+                TypeError::invalid_model_index_field("User", "address"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_native_types() {
+        let source = "
+
+database {
+  provider: postgresql
+}
+
+model User {
+  email: String @unique @db.VarChar(320)
+  balance: Decimal @db.Decimal(10, 2)
+  joinedAt: DateTime @db.Timestamptz(6)
+  externalId: Uuid @db.Uuid
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let user = ir.models.get("User").unwrap();
+
+        assert_eq!(
+            user.native_types,
+            OrdStrMap::from_iter([
+                (
+                    "email",
+                    model::NativeType {
+                        name: "VarChar".into(),
+                        arguments: vec!["320".into()],
+                    }
+                ),
+                (
+                    "balance",
+                    model::NativeType {
+                        name: "Decimal".into(),
+                        arguments: vec!["10".into(), "2".into()],
+                    }
+                ),
+                (
+                    "joinedAt",
+                    model::NativeType {
+                        name: "Timestamptz".into(),
+                        arguments: vec!["6".into()],
+                    }
+                ),
+                (
+                    "externalId",
+                    model::NativeType {
+                        name: "Uuid".into(),
+                        arguments: Vec::new(),
+                    }
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_native_types() {
+        let source = "
+
+database {
+  provider: mysql
+}
+
+enum Role {
+  Admin
+}
+
+model User {
+  email: String @db.Text(255)
+  visits: Int @db.VarChar(10)
+  joinedAt: DateTime @db.Timestamptz
+  role: Role @db.Text
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_model_native_type(
+                    "User",
+                    "email",
+                    "@db.Text(255)"
+                ),
+                TypeError::invalid_model_native_type(
+                    "User",
+                    "visits",
+                    "@db.VarChar(10)"
+                ),
+                TypeError::invalid_model_native_type(
+                    "User",
+                    "joinedAt",
+                    "@db.Timestamptz"
+                ),
+                TypeError::invalid_model_attribute("User", "role", "db"),
+            ])
+        );
+
+        let source = "
+
+model User {
+  email: String @db.Text
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::unexpected_model_native_type(
+                "User", "email"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_invalid_database_native_types() {
+        let source = "
+
+database {
+  provider: mysql
+  url: \"database url\"
+}
+
+model User {
+  email: String @db.VarChar(320)
+  visits: Int @db.VarChar(10)
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_database_setting("url", "\"database url\""),
+                TypeError::invalid_model_native_type(
+                    "User",
+                    "visits",
+                    "@db.VarChar(10)"
+                ),
+            ])
+        );
+
+        let source = "
+
+database {
+  provider: oracle
+}
+
+model User {
+  email: String @db.VarChar(320)
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::invalid_database_setting(
+                "provider", "oracle"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_invalid_lists() {
+        let source = "
+
+database {
+  provider: sqlite
+}
+
+enum Role {
+  Admin
+}
+
+model User {
+  tags: [String]
+  roles: [Role]
+  friends: [User]
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_model_list("User", "tags"),
+                TypeError::invalid_model_list("User", "roles"),
+            ])
+        );
+
+        let source = "
+
+database {
+  provider: postgresql
+}
+
+enum Role {
+  Admin
+}
+
+model User {
+  tags: [String]
+  roles: [Role]
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert!(Ir::try_from(ast).is_ok());
+    }
+
+    #[test]
+    fn test_try_from_ast_referential_actions() {
+        let source = "
+
+database {
+  provider: mysql
+}
+
+model Country {
+  name: String
+  provinces: [Province]
+}
+
+model Province {
+  country: Country @inverse(provinces) @onDelete(Cascade) @onUpdate(NoAction)
+  capitalOf: Country? @onDelete(SetNull)
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let province = ir.models.get("Province").unwrap();
+        let country = province.relations.get("country").unwrap();
+        let capital_of = province.relations.get("capitalOf").unwrap();
+
+        assert_eq!(country.on_delete, Some(model::ReferentialAction::Cascade));
+        assert_eq!(country.on_update, Some(model::ReferentialAction::NoAction));
+        assert_eq!(
+            capital_of.on_delete,
+            Some(model::ReferentialAction::SetNull)
+        );
+        assert_eq!(capital_of.on_update, None);
+    }
+
+    #[test]
+    fn test_invalid_referential_actions() {
+        let source = "
+
+database {
+  provider: postgresql
+}
+
+model Country {
+  name: String @onDelete(Cascade)
+  provinces: [Province] @onUpdate(Cascade)
+}
+
+model Province {
+  country: Country @inverse(provinces) @onDelete(SetNull)
+  region: Country? @onDelete(Ignore) @onUpdate(NoAction)
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_model_attribute(
+                    "Country", "name", "onDelete"
+                ),
+                TypeError::invalid_model_attribute(
+                    "Country",
+                    "provinces",
+                    "onUpdate"
+                ),
+                TypeError::invalid_model_referential_action(
+                    "Province", "country", "SetNull"
+                ),
+                TypeError::unknown_model_referential_action(
+                    "Province", "region", "Ignore"
+                ),
+                TypeError::invalid_model_referential_action(
+                    "Province", "region", "NoAction"
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_inverses() {
+        let source = "
+
+model Country {
+  name: String
+  images: [Image]
+}
+
+model Image {
+  country: Country @inverse(images)
+  flagOf: Country? @inverse(flags)
+  tags: [Tag]
+}
+
+model Tag {
+  name: String
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let country = ir.models.get("Country").unwrap();
+        let image = ir.models.get("Image").unwrap();
+
+        assert_eq!(
+            country.relations.get("images"),
+            Some(&model::ModelRelation {
+                model_name: "Image".into(),
+                r#type: model::model_relation::Type::ManyToMany,
+                optional: false,
+                name: "countryOnImage".into(),
+                inverse_name: "country".into(),
+                inverse_declared: true,
+                on_delete: None,
+                on_update: None,
+                doc: None,
+            })
+        );
+
+        assert_eq!(
+            image.relations.get("country"),
+            Some(&model::ModelRelation {
+                model_name: "Country".into(),
+                r#type: model::model_relation::Type::ManyToOne,
+                optional: false,
+                name: "countryOnImage".into(),
+                inverse_name: "images".into(),
+                inverse_declared: true,
+                on_delete: None,
+                on_update: None,
+                doc: None,
+            })
+        );
+
+        assert_eq!(
+            image.relations.get("flagOf"),
+            Some(&model::ModelRelation {
+                model_name: "Country".into(),
+                r#type: model::model_relation::Type::ManyToOne,
+                optional: true,
+                name: "flagOfOnImage".into(),
+                inverse_name: "flags".into(),
+                inverse_declared: false,
+                on_delete: None,
+                on_update: None,
+                doc: None,
+            })
+        );
+
+        assert_eq!(
+            image.relations.get("tags"),
+            Some(&model::ModelRelation {
+                model_name: "Tag".into(),
+                r#type: model::model_relation::Type::ManyToMany,
+                optional: false,
+                name: "tagsOnImage".into(),
+                inverse_name: "image".into(),
+                inverse_declared: false,
+                on_delete: None,
+                on_update: None,
+                doc: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_inverses() {
+        let source = "
+
+model Country {
+  name: String
+  capital: City
+}
+
+model City {
+  name: String @inverse(cities)
+  country: Country @inverse(capital)
+}
+
+model Image {
+  country: Country
+  flagOf: Country
+  region: Country @inverse(name)
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_model_attribute("City", "name", "inverse"),
+                TypeError::invalid_model_inverse("City", "country", "capital"),
+                TypeError::duplicate_model_inverse("Image", "flagOf", "image"),
+                TypeError::invalid_model_inverse("Image", "region", "name"),
+            ])
+        );
+
+        let source = "
+
+model User {
+  friends: [User] @inverse(friends)
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::invalid_model_inverse(
+                "User", "friends", "friends"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_self_relations() {
+        let source = "
+
+model Employee {
+  manager: Employee?
+  mentors: [Employee]
+}
+
+model Category {
+  parent: Category? @inverse(children)
+  children: [Category]
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let employee = ir.models.get("Employee").unwrap();
+        let category = ir.models.get("Category").unwrap();
+
+        assert_eq!(
+            employee.relations.get("manager"),
+            Some(&model::ModelRelation {
+                model_name: "Employee".into(),
+                r#type: model::model_relation::Type::ManyToOne,
+                optional: true,
+                name: "managerOnEmployee".into(),
+                inverse_name: "managerOf".into(),
+                inverse_declared: false,
+                on_delete: None,
+                on_update: None,
+                doc: None,
+            })
+        );
+
+        assert_eq!(
+            employee.relations.get("mentors").unwrap().inverse_name,
+            "mentorsOf"
+        );
+
+        assert_eq!(
+            category.relations.get("parent"),
+            Some(&model::ModelRelation {
+                model_name: "Category".into(),
+                r#type: model::model_relation::Type::ManyToOne,
+                optional: true,
+                name: "parentOnCategory".into(),
+                inverse_name: "children".into(),
+                inverse_declared: true,
+                on_delete: None,
+                on_update: None,
+                doc: None,
+            })
+        );
+
+        assert_eq!(
+            category.relations.get("children"),
+            Some(&model::ModelRelation {
+                model_name: "Category".into(),
+                r#type: model::model_relation::Type::ManyToMany,
+                optional: false,
+                name: "parentOnCategory".into(),
+                inverse_name: "parent".into(),
+                inverse_declared: true,
+                on_delete: None,
+                on_update: None,
+                doc: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_cyclic_relations() {
+        let source = "
+
+model Country {
+  capital: City
+  city: City
+}
+
+model City {
+  country: Country
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let country = ir.models.get("Country").unwrap();
+        let city = ir.models.get("City").unwrap();
+
+        assert_eq!(
+            country.relations.get("capital").unwrap().inverse_name,
+            "capitalOf"
+        );
+
+        assert_eq!(
+            country.relations.get("city").unwrap().inverse_name,
+            "cityOf"
+        );
+
+        assert_eq!(
+            city.relations.get("country").unwrap().inverse_name,
+            "countryOf"
+        );
+
+        assert!(country
+            .relations
+            .values()
+            .chain(city.relations.values())
+            .all(|relation| !relation.inverse_declared));
+    }
+
+    #[test]
+    fn test_try_from_ast_ownership() {
+        let source = "
+
+model Country {
+  capital: @City
+  regions: [@Region]
+}
+
+model Region {
+  cities: [@City]
+}
+
+model City {
+  name: String
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::multiple_model_owners(
+                "City",
+                ["Country.capital", "Region.cities"]
+            )])
+        );
+
+        let source = "
+
+model Country {
+  regions: [@Region]
+}
+
+model Region {
+  country: @Country
+}
+
+model Node {
+  next: @Node
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::cyclic_model_ownership(
+                    "Country",
+                    ["Country.regions", "Region.country", "Country"]
+                ),
+                TypeError::cyclic_model_ownership(
+                    "Node",
+                    ["Node.next", "Node"]
+                ),
+            ])
+        );
+
+        let source = "
+
+model Post {
+  cover: @Image @inverse(coverOf)
+  gallery: [@Image] @inverse(galleryOf)
+}
+
+model Image {
+  url: String
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert!(Ir::try_from(ast).is_ok());
+
+        let source = "
+
+model Country {
+  regions: [@Region]
+}
+
+model Region {
+  capital: @City
+}
+
+model City {
+  name: String
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert!(Ir::try_from(ast).is_ok());
+    }
+
+    #[test]
+    fn test_try_from_ast_ids() {
+        let source = "
+
+model Session {
+  token: String
+  @@id(uuid)
+}
+
+model Country {
+  code: String @id
+}
+
+model Passport {
+  countryCode: String
+  number: Int
+  @@id([countryCode, number])
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+
+        assert_eq!(
+            ir.models.get("Session").unwrap().id,
+            model::Id::Generated(model::id::Strategy::Uuid)
+        );
+
+        assert_eq!(
+            ir.models.get("Country").unwrap().id,
+            model::Id::Field("code".into())
+        );
+
+        assert_eq!(
+            ir.models.get("Passport").unwrap().id,
+            model::Id::Composite(vec!["countryCode".into(), "number".into()])
+        );
+    }
+
+    #[test]
+    fn test_invalid_ids() {
+        let source = "
+
+model Session {
+  token: String @id
+  @@id(serial)
+}
+
+model Country {
+  code: String? @id
+}
+
+model Passport {
+  number: Int @id
+  @@id([countryCode, number])
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::unknown_model_id_strategy("Session", "serial"),
+                TypeError::invalid_model_id_field("Country", "code"),
+                TypeError::duplicate_model_id("Passport"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_timestamps() {
+        let source = "
+
+model Post {
+  title: String
+  @@timestamps(createdAt: publishedAt, updatedAt, deletedAt)
+}
+
+model Tag {
+  name: String
+  @@timestamps()
+}
+
+query posts: [Post] {
+  post {
+    title
+    publishedAt
+    deletedAt
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let post = ir.models.get("Post").unwrap();
+        let tag = ir.models.get("Tag").unwrap();
+
+        assert_eq!(
+            post.timestamps,
+            model::Timestamps {
+                created_at: Some("publishedAt".into()),
+                updated_at: Some("updatedAt".into()),
+                deleted_at: Some("deletedAt".into()),
+            }
+        );
+
+        assert_eq!(
+            post.field("deletedAt"),
+            Some(model::Field {
+                name: "deletedAt".into(),
+                r#type: Type::DateTime,
+                cardinality: Cardinality::Optional,
+                doc: None,
+            })
+        );
+
+        assert_eq!(tag.timestamps, model::Timestamps::default());
+        assert_eq!(tag.field("createdAt"), None);
+    }
+
+    #[test]
+    fn test_invalid_timestamps() {
+        let source = "
+
+model Post {
+  title: String
+  createdAt: DateTime
+}
+
+model Tag {
+  name: String
+  @@timestamps(createdAt, archivedAt)
+  @@timestamps(createdAt: insertedAt)
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::duplicate_model_field("Post", "createdAt"),
+                TypeError::unknown_model_timestamp("Tag", "archivedAt"),
+                TypeError::duplicate_model_timestamp("Tag", "createdAt"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_duplicate_model() {
+        let mut ir = Ir::default();
+
+        ir.insert_model(Model::new("User")).unwrap();
+
+        assert_eq!(
+            ir.insert_model(Model::new("User")),
+            Err(TypeError::duplicate_model("User"))
+        );
+    }
+
+    #[test]
+    fn test_duplicate_enum() {
+        let mut ir = Ir::default();
+
+        ir.insert_enum(Enum {
+            name: "AddressType".into(),
+            values: TokenSet::from_iter(["Home", "Work", "Other"]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
+        })
+        .unwrap();
+
+        assert_eq!(
+            ir.insert_enum(Enum {
+                name: "AddressType".into(),
+                values: TokenSet::new(),
+                doc: None,
+                value_docs: OrdStrMap::new(),
+            }),
+            Err(TypeError::duplicate_enum("AddressType"))
+        );
+    }
+
+    #[test]
+    fn test_duplicate_query() {
+        let mut ir = Ir::default();
+
+        let query = Query {
+            name: "users".into(),
+            arguments: OrdStrMap::new(),
+            schema: query::Schema {
+                alias: "user".into(),
+                nodes: Vec::new(),
+            },
+            r#where: None,
+            r#type: query::ReturnType {
+                model_name: "User".into(),
+                cardinality: Cardinality::Many,
+            },
+            order_by: Vec::new(),
+            take: None,
+            skip: None,
+            cursor: None,
+            doc: None,
+        };
+
+        ir.insert_query(query.clone()).unwrap();
+
+        assert_eq!(
+            ir.insert_query(query),
+            Err(TypeError::duplicate_query("users"))
+        );
+    }
+
+    #[test]
+    fn test_undefined_query_field() {
+        let source = "
+
+model User {
+  name: String
+  age: Int
+}
+
+query users: [User] {
+  user {
+    name
+    age
+    address
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast);
+
+        assert_eq!(
+            ir,
+            Err(vec![TypeError::undefined_query_field("users", "address")])
+        );
+    }
+
+    #[test]
+    fn test_undefined_query_field_relation() {
+        let source = "
+
+model User {
+  name: String
+  age: Int
+}
+
+query users: [User] {
+  user {
+    name
+    age
+    address {
+      street
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast);
+
+        assert_eq!(
+            ir,
+            Err(vec![TypeError::undefined_query_field("users", "address")])
+        );
+    }
+
+    #[test]
+    fn test_undefined_query_fields() {
+        let source = "
+
+model Address {
+  street: String
+}
+
+model User {
+  name: String
+  address: Address
+}
+
+query users: [User] {
+  user {
+    age
+    name
+    height
+    address {
+      number
+      street
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::undefined_query_field("users", "age"),
+                TypeError::undefined_query_field("users", "height"),
+                TypeError::undefined_query_field("users", "address.number"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_nested_query_selection() {
+        let source = "
+
+model Country {
+  name: String
+  continent: Continent
+}
+
+model Continent {
+  name: String
+}
+
+enum Kind {
+  Photo
+}
+
+model Image {
+  title: String
+  kind: Kind
+  country: Country
+}
+
+query images: [Image] {
+  image {
+    title
+    kind
+    country {
+      name
+      continent {
+        name
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(Ir::try_from(ast).map(|_| ()), Ok(()));
+    }
+
+    #[test]
+    fn test_undefined_nested_query_field() {
+        let source = "
+
+model Country {
+  name: String
+  continent: Continent
+}
+
+model Continent {
+  name: String
+}
+
+enum Kind {
+  Photo
+}
+
+model Image {
+  title: String
+  kind: Kind
+  country: Country
+}
+
+query images: [Image] {
+  image {
+    country {
+      name
+      continent {
+        title
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::undefined_query_field(
+                "images",
+                "country.continent.title"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_unexpected_query_selection() {
+        let source = "
+
+model Country {
+  name: String
+  continent: Continent
+}
+
+model Continent {
+  name: String
+}
+
+enum Kind {
+  Photo
+}
+
+model Image {
+  title: String
+  kind: Kind
+  country: Country
+}
+
+query images: [Image] {
+  image {
+    title {
+      name
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::unexpected_query_selection(
+                "images", "title"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_unexpected_query_selection_enum() {
+        let source = "
+
+model Country {
+  name: String
+  continent: Continent
+}
+
+model Continent {
+  name: String
+}
+
+enum Kind {
+  Photo
+}
+
+model Image {
+  title: String
+  kind: Kind
+  country: Country
+}
+
+query images: [Image] {
+  image {
+    country {
+      name
+    }
+    kind {
+      name
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::unexpected_query_selection(
+                "images", "kind"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_missing_query_selection() {
+        let source = "
+
+model Country {
+  name: String
+  continent: Continent
+}
+
+model Continent {
+  name: String
+}
+
+enum Kind {
+  Photo
+}
+
+model Image {
+  title: String
+  kind: Kind
+  country: Country
+}
+
+query images: [Image] {
+  image {
+    country {
+      continent
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::missing_query_selection(
+                "images",
+                "country.continent"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_undefined_query_return_type() {
+        let source = "
+
+query users: [User] {
+  user {
+    name
+    age
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast);
+
+        assert_eq!(
+            ir,
+            Err(vec![TypeError::undefined_query_return_type(
+                "users", "User"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_try_from_ast_all_errors() {
+        let source = "
+
+model User {
+  name: String
+  address: Address
+  tags: [Tag]
+}
+
+enum Role {
+  Admin
+}
+
+query users: [User] {
+  user {
+    name
+    age
+  }
+}
+
+query posts: [Post] {
+  post {
+    title
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::unknown_model_field_type(
+                    "User", "address", "Address"
+                ),
+                TypeError::unknown_model_field_type("User", "tags", "[Tag]"),
+                TypeError::undefined_query_field("users", "age"),
+                TypeError::undefined_query_return_type("posts", "Post"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_unused_query_argument() {
+        let source = "
+
+model User {
+  name: String
+  age: Int
+}
+
+query users($name: String, $age: Int): [User] {
+  user {
+    name
+  }
+  where {
+    user {
+      name {
+        equals: $name
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::unused_query_argument("users", "age")])
+        );
+    }
+
+    #[test]
+    fn test_unused_query_argument_without_where() {
+        let source = "
+
+model User {
+  name: String
+}
+
+query users($name: String): [User] {
+  user {
+    name
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::unused_query_argument("users", "name")])
+        );
+    }
+
+    #[test]
+    fn test_undefined_query_argument() {
+        let source = "
+
+model User {
+  name: String
+}
+
+query users: [User] {
+  user {
+    name
+  }
+  where {
+    user {
+      name {
+        equals: $name
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::undefined_query_argument("users", "name")])
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_argument_type() {
+        let source = "
+
+model Address {
+  street: String
+}
+
+model User {
+  name: String
+  address: Address
+}
+
+query users($address: Address): [User] {
+  user {
+    name
+  }
+  where {
+    user {
+      address {
+        equals: $address
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::invalid_query_argument_type(
+                "users", "address", "Address"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_query_operators() {
+        let source = "
+
+enum Status {
+  Draft
+  Published
+}
+
+model Post {
+  title: String
+  views: Int
+  publishedAt: DateTime?
+  status: Status
+}
+
+query posts($prefix: String, $minViews: Int, $statuses: [Status], \
+                      $unpublished: Boolean): [Post] {
+  post {
+    title
+  }
+  where {
+    post {
+      title {
+        startsWith: $prefix
+        mode: insensitive
+      }
+      views {
+        gte: $minViews
+      }
+      status {
+        notIn: $statuses
+      }
+      publishedAt {
+        isNull: $unpublished
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+
+        let r#where = ir.queries.get("posts").unwrap().r#where.clone().unwrap();
+
+        assert_eq!(
+            r#where
+                .conditions()
+                .into_iter()
+                .map(|condition| (condition.operator, condition.mode))
+                .collect::<Vec<_>>(),
+            vec![
+                (query::Operator::StartsWith, query::Mode::Insensitive),
+                (query::Operator::Gte, query::Mode::Default),
+                (query::Operator::NotIn, query::Mode::Default),
+                (query::Operator::IsNull, query::Mode::Default),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_operators() {
+        let source = "
+
+model Post {
+  title: String
+  featured: Boolean
+  views: Int
+}
+
+query posts($featured: Boolean, $views: Int, $suffix: String, $deleted: \
+                      Boolean): [Post] {
+  post {
+    title
+  }
+  where {
+    post {
+      featured {
+        gt: $featured
+      }
+      views {
+        in: $views
+        mode: insensitive
+      }
+      title {
+        endsWith: $suffix
+        isNull: $deleted
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_query_condition(
+                    "posts", "featured", "featured", "gt"
+                ),
+                TypeError::invalid_query_condition(
+                    "posts", "views", "views", "in"
+                ),
+                TypeError::invalid_query_condition(
+                    "posts", "title", "deleted", "isNull"
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_condition_mode() {
+        let source = "
+
+model Post {
+  views: Int
+}
+
+query posts($views: Int): [Post] {
+  post {
+    views
+  }
+  where {
+    post {
+      views {
+        equals: $views
+        mode: insensitive
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::invalid_query_condition_mode(
+                "posts", "views"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_empty_query_schema() {
+        let source = "
+
+model User {
+  name: String
+}
+
+query users: [User] {
+  user {
+    name
+  }
+}
+
+"
+        .trim();
+
+        let (mut ast, _) = ast::Ast::parse(source).unwrap();
+
+        ast.queries.get_mut("users").unwrap().schema.nodes.clear();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::empty_query_schema("users")])
+        );
+    }
+
+    #[test]
+    fn test_empty_model() {
+        let source = "
+
+model User {
+  name: String
+}
+
+"
+        .trim();
+
+        let (mut ast, _) = ast::Ast::parse(source).unwrap();
+
+        ast.models.get_mut("User").unwrap().fields = OrdStrMap::new();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![TypeError::empty_model("User")])
+        );
+    }
+
+    #[test]
+    fn test_enum_type_none() {
+        let mut ir = Ir::default();
+
+        let _: Option<Model<'static>> =
+            ir.models.insert("Foo", Model::new("Foo"));
+
+        assert_eq!(
+            ir.enum_type("Foo", VecDeque::from_iter(["bar".into()])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_enum_type_empty() {
+        let mut ir = Ir::default();
+
+        let _: Option<Model<'static>> =
+            ir.models.insert("Foo", Model::new("Foo"));
+
+        assert_eq!(ir.enum_type("Foo", VecDeque::from_iter([])), None);
+    }
+
+    #[test]
+    fn test_query_filters() {
+        let source = "
+
+model User {
+  country: String
+  archived: Boolean
+  age: Int
+}
+
+query users($country: String, $archived: Boolean, $age: Int): [User] {
+  user {
+    country
+  }
+  where {
+    user {
+      or {
+        country {
+          equals: $country
+        }
+        not {
+          archived {
+            equals: $archived
+          }
+        }
+      }
+      age {
+        gte: $age
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+
+        let condition = |name: &'static str, operator| {
+            query::Filter::Condition(query::Condition {
+                lhs: once(name.into()).collect(),
+                operator,
+                rhs: query::Operand::Argument(name.into()),
+                mode: query::Mode::Default,
+            })
+        };
+
+        assert_eq!(
+            ir.queries.get("users").unwrap().r#where,
+            Some(query::Where {
+                alias: "user".into(),
+                filters: vec![
+                    query::Filter::Or(vec![
+                        condition("country", query::Operator::Equals),
+                        query::Filter::Not(vec![condition(
+                            "archived",
+                            query::Operator::Equals
+                        )]),
+                    ]),
+                    condition("age", query::Operator::Gte),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_filters() {
+        let source = "
+
+model User {
+  country: String
+  age: Int
+}
+
+query users($country: String, $age: Int): [User] {
+  user {
+    country
+  }
+  where {
+    user {
+      or {
+        country {
+          equals: $country
+        }
+        and {
+          age {
+            startsWith: $age
+          }
+          country {
+            equals: $city
+          }
+        }
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_query_condition(
+                    "users",
+                    "age",
+                    "age",
+                    "startsWith"
+                ),
+                TypeError::undefined_query_argument("users", "city"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_query_literals() {
+        let source = "
+
+enum Status {
+  Active
+  Archived
+}
+
+model Post {
+  title: String
+  published: Boolean
+  rating: Float
+  status: Status
+  deletedAt: DateTime?
+}
+
+query posts: [Post] {
+  post {
+    title
+  }
+  where {
+    post {
+      title {
+        startsWith: \"Re: \"
+      }
+      published {
+        equals: true
+      }
+      rating {
+        gte: 4
+      }
+      status {
+        notEquals: Archived
+      }
+      deletedAt {
+        equals: null
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let r#where = ir.queries.get("posts").unwrap().r#where.clone().unwrap();
+
+        assert_eq!(
+            r#where
+                .conditions()
+                .into_iter()
+                .map(|condition| condition.rhs.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                query::Operand::Value(Value::String("Re: ".into())),
+                query::Operand::Value(Value::Boolean(true)),
+                query::Operand::Value(Value::Float("4".into())),
+                query::Operand::Value(Value::EnumValue("Archived".into())),
+                query::Operand::Null,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_literals() {
+        let source = "
+
+enum Status {
+  Active
+  Archived
+}
+
+model Post {
+  title: String
+  views: Int
+  status: Status
+}
+
+query posts: [Post] {
+  post {
+    title
+  }
+  where {
+    post {
+      title {
+        equals: null
+      }
+      views {
+        equals: 1.5
+      }
+      status {
+        equals: Deleted
+      }
+      views {
+        in: 1
+      }
+    }
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_query_condition(
+                    "posts", "title", "null", "equals"
+                ),
+                TypeError::invalid_query_condition(
+                    "posts", "views", "1.5", "equals"
+                ),
+                TypeError::invalid_query_condition(
+                    "posts", "status", "Deleted", "equals"
+                ),
+                TypeError::invalid_query_condition("posts", "views", "1", "in"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_query_pagination() {
+        let source = "
+
+model Author {
+  name: String
+}
+
+model Post {
+  slug: String @unique
+  title: String
+  publishedAt: DateTime
+  author: Author
+}
+
+query posts($count: Int, $after: String): [Post] {
+  post {
+    title
+  }
+  orderBy {
+    publishedAt: desc
+    author {
+      name: asc
+    }
+  }
+  take: $count
+  skip: 1
+  cursor {
+    slug: $after
+  }
+}
+
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+        let query = ir.queries.get("posts").unwrap();
+
+        assert_eq!(
+            query.order_by,
+            vec![
+                query::Ordering {
+                    path: once("publishedAt".into()).collect(),
+                    direction: query::Direction::Desc,
+                },
+                query::Ordering {
+                    path: ["author".into(), "name".into()].into(),
+                    direction: query::Direction::Asc,
+                },
+            ]
+        );
+
+        assert_eq!(query.take, Some(query::Operand::Argument("count".into())));
+
+        assert_eq!(
+            query.skip,
+            Some(query::Operand::Value(Value::Int("1".into())))
+        );
+
+        assert_eq!(
+            query.cursor,
+            Some(query::Cursor {
+                field_name: "slug".into(),
+                rhs: query::Operand::Argument("after".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_pagination() {
+        let source = "
+
+model Author {
+  name: String
+}
+
+model Post {
+  title: String
+  tags: [String]
+  author: Author
+  categories: [Category]
+}
+
+model Category {
+  name: String
+}
+
+query post($count: Int): Post {
+  post {
+    title
+  }
+  orderBy {
+    tags: asc
+    author: desc
+    categories {
+      name: asc
     }
+  }
+  take: $count
+}
 
-    #[test]
-    fn test_duplicate_model() {
-        let mut ir = Ir::default();
+query posts($after: String): [Post] {
+  post {
+    title
+  }
+  skip: \"1\"
+  cursor {
+    title: $after
+  }
+}
 
-        ir.insert_model(Model::new("User")).unwrap();
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
 
         assert_eq!(
-            ir.insert_model(Model::new("User")),
-            Err(TypeError::duplicate_model("User"))
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_query_ordering("post", "tags"),
+                TypeError::invalid_query_ordering("post", "author"),
+                TypeError::invalid_query_ordering("post", "categories.name"),
+                TypeError::invalid_query_pagination("post", "take"),
+                TypeError::invalid_query_pagination_value(
+                    "posts", "skip", "\"1\""
+                ),
+                TypeError::invalid_query_cursor("posts", "title"),
+            ])
         );
     }
 
     #[test]
-    fn test_duplicate_enum() {
-        let mut ir = Ir::default();
+    fn test_aggregates() {
+        let source = "
 
-        ir.insert_enum(Enum {
-            name: "AddressType".into(),
-            values: TokenSet::from_iter(["Home", "Work", "Other"]),
-        })
-        .unwrap();
+enum Status {
+  Draft
+  Published
+}
 
-        assert_eq!(
-            ir.insert_enum(Enum {
-                name: "AddressType".into(),
-                values: TokenSet::new()
-            }),
-            Err(TypeError::duplicate_enum("AddressType"))
-        );
+model Post {
+  title: String
+  views: Int
+  rating: Float
+  publishedAt: DateTime
+  status: Status
+}
+
+/// Post statistics per status.
+aggregate postStats($minViews: Int): Post {
+  count
+  sum {
+    views
+  }
+  avg {
+    views
+    rating
+  }
+  max {
+    publishedAt
+  }
+  groupBy {
+    status
+  }
+  where {
+    post {
+      views {
+        gte: $minViews
+      }
     }
+  }
+}
 
-    #[test]
-    fn test_duplicate_query() {
-        let mut ir = Ir::default();
+aggregate postCount: Post {
+  count
+}
 
-        let query = Query {
-            name: "users".into(),
-            arguments: OrdStrMap::new(),
-            schema: query::Schema {
-                alias: "user".into(),
-                nodes: Vec::new(),
-            },
-            r#where: None,
-            r#type: query::ReturnType {
-                model_name: "User".into(),
-                cardinality: Cardinality::Many,
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+        let ir = Ir::try_from(ast).unwrap();
+
+        let mut post_stats = Aggregate::new("postStats", "Post");
+
+        post_stats.doc = Some("Post statistics per status.".into());
+        post_stats.count = true;
+
+        post_stats.arguments = OrdStrMap::from_iter([(
+            "minViews",
+            query::Argument {
+                name: "minViews".into(),
+                r#type: query::ArgumentType::Type(Type::Int),
+                cardinality: Cardinality::One,
             },
-        };
+        )]);
 
-        ir.insert_query(query.clone()).unwrap();
+        post_stats.aggregations = vec![
+            aggregate::Aggregation {
+                function: aggregate::Function::Sum,
+                field_name: "views".into(),
+                r#type: Type::Int,
+            },
+            aggregate::Aggregation {
+                function: aggregate::Function::Avg,
+                field_name: "views".into(),
+                r#type: Type::Float,
+            },
+            aggregate::Aggregation {
+                function: aggregate::Function::Avg,
+                field_name: "rating".into(),
+                r#type: Type::Float,
+            },
+            aggregate::Aggregation {
+                function: aggregate::Function::Max,
+                field_name: "publishedAt".into(),
+                r#type: Type::DateTime,
+            },
+        ];
 
-        assert_eq!(
-            ir.insert_query(query),
-            Err(TypeError::duplicate_query("users"))
-        );
+        post_stats.group_by = vec![aggregate::GroupBy {
+            field_name: "status".into(),
+            r#type: query::ArgumentType::Enum("Status".into()),
+            cardinality: Cardinality::One,
+        }];
+
+        post_stats.r#where = Some(query::Where {
+            alias: "post".into(),
+            filters: vec![query::Filter::Condition(query::Condition {
+                lhs: VecDeque::from_iter(["views".into()]),
+                operator: query::Operator::Gte,
+                rhs: query::Operand::Argument("minViews".into()),
+                mode: query::Mode::Default,
+            })],
+        });
+
+        let mut post_count = Aggregate::new("postCount", "Post");
+
+        post_count.count = true;
+
+        assert_eq!(ir.aggregates.get("postStats"), Some(&post_stats));
+        assert_eq!(ir.aggregates.get("postCount"), Some(&post_count));
+        assert_eq!(post_stats.cardinality(), Cardinality::Many);
+        assert_eq!(post_count.cardinality(), Cardinality::One);
     }
 
     #[test]
-    fn test_undefined_query_field() {
+    fn test_invalid_aggregates() {
         let source = "
 
-model User {
+model Author {
   name: String
-  age: Int
 }
 
-query users: [User] {
-  user {
-    name
-    age
-    address
+model Post {
+  title: String
+  views: Int
+  scores: [Int]
+  publishedAt: DateTime
+  author: Author
+}
+
+aggregate postStats($title: String, $unused: Int): Post {
+  sum {
+    title
+    scores
+  }
+  avg {
+    publishedAt
   }
+  min {
+    publishedAt
+  }
+  groupBy {
+    author
+    scores
+  }
+  where {
+    post {
+      views {
+        equals: $title
+      }
+    }
+  }
+}
+
+aggregate emptyStats: Post {
+  groupBy {
+    title
+  }
+}
+
+aggregate commentStats: Comment {
+  count
 }
 
 "
         .trim();
 
         let (ast, _) = ast::Ast::parse(source).unwrap();
-        let ir = Ir::try_from(ast);
 
         assert_eq!(
-            ir,
-            Err(TypeError::undefined_query_field("users", "address"))
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_aggregation("postStats", "sum", "title"),
+                TypeError::invalid_aggregation("postStats", "sum", "scores"),
+                TypeError::invalid_aggregation(
+                    "postStats",
+                    "avg",
+                    "publishedAt"
+                ),
+                TypeError::invalid_aggregate_group_by("postStats", "author"),
+                TypeError::invalid_aggregate_group_by("postStats", "scores"),
+                TypeError::invalid_query_condition(
+                    "postStats",
+                    "views",
+                    "title",
+                    "equals"
+                ),
+                TypeError::unused_query_argument("postStats", "unused"),
+                TypeError::empty_aggregate("emptyStats"),
+                TypeError::undefined_aggregate_model("commentStats", "Comment"),
+            ])
         );
     }
 
     #[test]
-    fn test_undefined_query_field_relation() {
+    #[allow(clippy::too_many_lines)]
+    fn test_mutations() {
         let source = "
 
+enum Status {
+  Draft
+  Published
+}
+
 model User {
-  name: String
-  age: Int
+  name: String @unique
 }
 
-query users: [User] {
-  user {
-    name
-    age
-    address {
-      street
+model Post {
+  title: String
+  slug: String @unique
+  subtitle: String?
+  status: Status
+  author: User
+  editor: User? @inverse(editedPosts)
+}
+
+/// Create a post.
+mutation createPost($title: String, $slug: String, $authorId: Int): Post {
+  create {
+    title: $title
+    slug: $slug
+    status: Draft
+    author: connect $authorId
+  }
+}
+
+mutation updatePost($id: Int, $title: String): Post {
+  update {
+    title: $title
+    subtitle: null
+    editor: disconnect
+  }
+  where {
+    post {
+      id {
+        equals: $id
+      }
+    }
+  }
+}
+
+mutation deletePost($slug: String): Post {
+  delete
+  where {
+    post {
+      slug {
+        equals: $slug
+      }
+    }
+  }
+}
+
+mutation upsertUser($name: String): User {
+  upsert {
+    create {
+      name: $name
+    }
+    update {
+      name: $name
+    }
+  }
+  where {
+    user {
+      name {
+        equals: $name
+      }
     }
   }
 }
@@ -1313,22 +5885,208 @@ query users: [User] {
         .trim();
 
         let (ast, _) = ast::Ast::parse(source).unwrap();
-        let ir = Ir::try_from(ast);
+        let ir = Ir::try_from(ast).unwrap();
 
-        assert_eq!(
-            ir,
-            Err(TypeError::undefined_query_field("users", "address.street"))
+        let argument = |name: &'static str, r#type: Type| {
+            (
+                name,
+                query::Argument {
+                    name: name.into(),
+                    r#type: query::ArgumentType::Type(r#type),
+                    cardinality: Cardinality::One,
+                },
+            )
+        };
+
+        let equals = |alias: &'static str,
+                      field: &'static str,
+                      argument: &'static str| {
+            query::Where {
+                alias: alias.into(),
+                filters: vec![query::Filter::Condition(query::Condition {
+                    lhs: VecDeque::from_iter([field.into()]),
+                    operator: query::Operator::Equals,
+                    rhs: query::Operand::Argument(argument.into()),
+                    mode: query::Mode::Default,
+                })],
+            }
+        };
+
+        let mut create_post = Mutation::new(
+            "createPost",
+            "Post",
+            mutation::Operation::Create(vec![
+                mutation::Assignment {
+                    field_name: "title".into(),
+                    action: mutation::Action::Set(query::Operand::Argument(
+                        "title".into(),
+                    )),
+                },
+                mutation::Assignment {
+                    field_name: "slug".into(),
+                    action: mutation::Action::Set(query::Operand::Argument(
+                        "slug".into(),
+                    )),
+                },
+                mutation::Assignment {
+                    field_name: "status".into(),
+                    action: mutation::Action::Set(query::Operand::Value(
+                        Value::EnumValue("Draft".into()),
+                    )),
+                },
+                mutation::Assignment {
+                    field_name: "author".into(),
+                    action: mutation::Action::Connect(
+                        query::Operand::Argument("authorId".into()),
+                    ),
+                },
+            ]),
+        );
+
+        create_post.doc = Some("Create a post.".into());
+
+        create_post.arguments = OrdStrMap::from_iter([
+            argument("title", Type::String),
+            argument("slug", Type::String),
+            argument("authorId", Type::Int),
+        ]);
+
+        let mut update_post = Mutation::new(
+            "updatePost",
+            "Post",
+            mutation::Operation::Update(vec![
+                mutation::Assignment {
+                    field_name: "title".into(),
+                    action: mutation::Action::Set(query::Operand::Argument(
+                        "title".into(),
+                    )),
+                },
+                mutation::Assignment {
+                    field_name: "subtitle".into(),
+                    action: mutation::Action::Set(query::Operand::Null),
+                },
+                mutation::Assignment {
+                    field_name: "editor".into(),
+                    action: mutation::Action::Disconnect,
+                },
+            ]),
+        );
+
+        update_post.arguments = OrdStrMap::from_iter([
+            argument("id", Type::Int),
+            argument("title", Type::String),
+        ]);
+
+        update_post.r#where = Some(equals("post", "id", "id"));
+
+        let mut delete_post =
+            Mutation::new("deletePost", "Post", mutation::Operation::Delete);
+
+        delete_post.arguments =
+            OrdStrMap::from_iter([argument("slug", Type::String)]);
+
+        delete_post.r#where = Some(equals("post", "slug", "slug"));
+
+        let name = || {
+            vec![mutation::Assignment {
+                field_name: "name".into(),
+                action: mutation::Action::Set(query::Operand::Argument(
+                    "name".into(),
+                )),
+            }]
+        };
+
+        let mut upsert_user = Mutation::new(
+            "upsertUser",
+            "User",
+            mutation::Operation::Upsert {
+                create: name(),
+                update: name(),
+            },
         );
+
+        upsert_user.arguments =
+            OrdStrMap::from_iter([argument("name", Type::String)]);
+
+        upsert_user.r#where = Some(equals("user", "name", "name"));
+
+        assert_eq!(ir.mutations.get("createPost"), Some(&create_post));
+        assert_eq!(ir.mutations.get("updatePost"), Some(&update_post));
+        assert_eq!(ir.mutations.get("deletePost"), Some(&delete_post));
+        assert_eq!(ir.mutations.get("upsertUser"), Some(&upsert_user));
     }
 
     #[test]
-    fn test_undefined_query_return_type() {
+    #[allow(clippy::too_many_lines)]
+    fn test_invalid_mutations() {
         let source = "
 
-query users: [User] {
-  user {
-    name
-    age
+model User {
+  name: String
+}
+
+model Post {
+  title: String
+  views: Int
+  author: User
+}
+
+mutation createPost($title: String, $unused: Int): Post {
+  create {
+    title: $title
+    views: \"many\"
+    author: disconnect
+  }
+  where {
+    post {
+      title {
+        equals: $title
+      }
+    }
+  }
+}
+
+mutation updatePost($title: Int): Post {
+  update {
+    title: $title
+    author: connect $missing
+  }
+}
+
+mutation createComment: Comment {
+  delete
+}
+
+mutation deletePost($title: String, $views: String): Post {
+  delete
+  where {
+    post {
+      title {
+        equals: $title
+      }
+      views {
+        equals: $views
+      }
+      author {
+        name {
+          equals: $name
+        }
+      }
+    }
+  }
+}
+
+mutation deletePosts($title: String, $views: User): Post {
+  delete
+  where {
+    post {
+      title {
+        equals: $title
+      }
+      views {
+        equals: $views
+      }
+    }
   }
 }
 
@@ -1336,34 +6094,124 @@ query users: [User] {
         .trim();
 
         let (ast, _) = ast::Ast::parse(source).unwrap();
-        let ir = Ir::try_from(ast);
 
         assert_eq!(
-            ir,
-            Err(TypeError::undefined_query_return_type("users", "User"))
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::missing_mutation_field("createPost", "author"),
+                TypeError::invalid_mutation_assignment(
+                    "createPost",
+                    "views",
+                    "\"many\""
+                ),
+                TypeError::invalid_mutation_disconnect("createPost", "author"),
+                TypeError::unexpected_mutation_where("createPost"),
+                TypeError::unused_mutation_argument("createPost", "unused"),
+                TypeError::invalid_mutation_assignment(
+                    "updatePost",
+                    "title",
+                    "title"
+                ),
+                TypeError::undefined_mutation_argument("updatePost", "missing"),
+                TypeError::missing_mutation_where("updatePost"),
+                TypeError::undefined_mutation_model("createComment", "Comment"),
+                TypeError::invalid_mutation_condition(
+                    "deletePost",
+                    "views",
+                    "views",
+                    "equals"
+                ),
+                TypeError::undefined_mutation_argument("deletePost", "name"),
+                TypeError::invalid_mutation_argument_type(
+                    "deletePosts",
+                    "views",
+                    "User"
+                ),
+                TypeError::invalid_mutation_where("deletePosts"),
+            ])
         );
     }
 
     #[test]
-    fn test_enum_type_none() {
-        let mut ir = Ir::default();
+    fn test_mutation_composite_unique_keys() {
+        let source = "
 
-        let _: Option<Model<'static>> =
-            ir.models.insert("Foo", Model::new("Foo"));
+model Membership {
+  userName: String
+  teamName: String
+  role: String
+  @@id([userName, teamName])
+}
 
-        assert_eq!(
-            ir.enum_type("Foo", VecDeque::from_iter(["bar".into()])),
-            None
-        );
+model Vote {
+  userName: String
+  postTitle: String
+  @@unique([userName, postTitle])
+}
+
+mutation leaveTeam($user: String, $team: String): Membership {
+  delete
+  where {
+    membership {
+      userName {
+        equals: $user
+      }
+      teamName {
+        equals: $team
+      }
+    }
+  }
+}
+
+mutation retractVote($user: String, $title: String): Vote {
+  delete
+  where {
+    vote {
+      and {
+        userName {
+          equals: $user
+        }
+        postTitle {
+          equals: $title
+        }
+      }
     }
+  }
+}
 
-    #[test]
-    fn test_enum_type_empty() {
-        let mut ir = Ir::default();
+mutation leaveTeams($user: String): Membership {
+  delete
+  where {
+    membership {
+      userName {
+        equals: $user
+      }
+    }
+  }
+}
 
-        let _: Option<Model<'static>> =
-            ir.models.insert("Foo", Model::new("Foo"));
+mutation retractVotes($title: String): Vote {
+  delete
+  where {
+    vote {
+      postTitle {
+        equals: $title
+      }
+    }
+  }
+}
 
-        assert_eq!(ir.enum_type("Foo", VecDeque::from_iter([])), None);
+"
+        .trim();
+
+        let (ast, _) = ast::Ast::parse(source).unwrap();
+
+        assert_eq!(
+            Ir::try_from(ast),
+            Err(vec![
+                TypeError::invalid_mutation_where("leaveTeams"),
+                TypeError::invalid_mutation_where("retractVotes"),
+            ])
+        );
     }
 }
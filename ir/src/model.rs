@@ -2,7 +2,9 @@
 use {
     crate::{
         Cardinality,
+        Type,
         TypeError,
+        Value,
     },
     ord_str_map::OrdStrMap,
     std::{
@@ -13,15 +15,30 @@ use {
 pub use {
     enum_relation::EnumRelation,
     field::Field,
+    id::Id,
+    index::Index,
     model_relation::ModelRelation,
+    native_type::NativeType,
+    referential_action::ReferentialAction,
+    timestamps::Timestamps,
 };
 
 /// Enum relations.
 pub mod enum_relation;
 /// Model fields.
 pub mod field;
+/// Model identities.
+pub mod id;
+/// Model indexes.
+pub mod index;
 /// Model relations.
 pub mod model_relation;
+/// Native database types.
+pub mod native_type;
+/// Referential actions.
+pub mod referential_action;
+/// Automatic timestamp fields.
+pub mod timestamps;
 
 /// A model.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -36,6 +53,20 @@ pub struct Model<'a> {
     pub relations: OrdStrMap<ModelRelation<'a>>,
     /// Relations to enum values.
     pub enums: OrdStrMap<EnumRelation<'a>>,
+    /// The documentation of the model.
+    pub doc: Option<Cow<'a, str>>,
+    /// The default values of fields and enum relations, by name.
+    pub defaults: OrdStrMap<Value<'a>>,
+    /// The native database types of data fields, by name.
+    pub native_types: OrdStrMap<NativeType<'a>>,
+    /// Names of fields and enum relations whose values must be unique.
+    pub unique_fields: BTreeSet<Cow<'a, str>>,
+    /// Indexes on one or more fields.
+    pub indexes: Vec<Index<'a>>,
+    /// The way the records of the model are identified.
+    pub id: Id<'a>,
+    /// The automatic timestamp fields of the model.
+    pub timestamps: Timestamps<'a>,
 }
 
 impl<'a> Model<'a> {
@@ -55,6 +86,13 @@ impl<'a> Model<'a> {
             fields: OrdStrMap::new(),
             relations: OrdStrMap::new(),
             enums: OrdStrMap::new(),
+            doc: None,
+            defaults: OrdStrMap::new(),
+            native_types: OrdStrMap::new(),
+            unique_fields: BTreeSet::new(),
+            indexes: Vec::new(),
+            id: Id::default(),
+            timestamps: Timestamps::default(),
         }
     }
 
@@ -141,6 +179,48 @@ impl<'a> Model<'a> {
                 EnumRelation {
                     name: enum_name.clone().into(),
                     cardinality: Cardinality::One,
+                    doc: None,
+                },
+            )
+            .is_some()
+        {
+            return Err(TypeError::duplicate_model_field(
+                enum_name, field_name,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Insert an optional enum relation into the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The enum relation to insert.
+    /// * `enum_name` - The name of the enum.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the field name is already registered.
+    pub fn insert_optional_enum_relation<S, T>(
+        &mut self,
+        field_name: S,
+        enum_name: T,
+    ) -> Result<(), TypeError<'a>>
+    where
+        S: Into<Cow<'a, str>> + Clone,
+        T: Into<Cow<'a, str>> + Clone,
+    {
+        self.insert_key(field_name.clone())?;
+
+        if self
+            .enums
+            .insert(
+                field_name.clone().into().into_owned(),
+                EnumRelation {
+                    name: enum_name.clone().into(),
+                    cardinality: Cardinality::Optional,
+                    doc: None,
                 },
             )
             .is_some()
@@ -187,6 +267,49 @@ impl<'a> Model<'a> {
         Ok(())
     }
 
+    /// A relation from a field of this model, with a relation name and
+    /// inverse field name derived from the names of the field and this
+    /// model. The inverse of a relation to this model itself is named after
+    /// the field instead, so that it does not clash with the inverses of
+    /// other relations to this model.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    /// * `model_name` - The name of the related model.
+    /// * `r#type` - The type of the relation.
+    /// * `optional` - Whether the related model may be absent.
+    fn default_relation<T>(
+        &self,
+        field_name: &str,
+        model_name: T,
+        r#type: model_relation::Type,
+        optional: bool,
+    ) -> ModelRelation<'a>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let model_name = model_name.into();
+
+        let inverse_name = if model_name == self.name {
+            format!("{field_name}Of")
+        } else {
+            self.name.to_ascii_lowercase()
+        };
+
+        ModelRelation {
+            model_name,
+            r#type,
+            optional,
+            name: format!("{field_name}On{}", self.name).into(),
+            inverse_name: inverse_name.into(),
+            inverse_declared: false,
+            on_delete: None,
+            on_update: None,
+            doc: None,
+        }
+    }
+
     /// Insert a one-to-one relation into the model.
     ///
     /// # Arguments
@@ -206,13 +329,43 @@ impl<'a> Model<'a> {
         S: Into<Cow<'a, str>> + Clone,
         T: Into<Cow<'a, str>>,
     {
-        self.insert_relation(
-            field_name,
-            ModelRelation {
-                model_name: model_name.into(),
-                r#type: model_relation::Type::OneToOne,
-            },
-        )
+        let relation = self.default_relation(
+            &field_name.clone().into(),
+            model_name,
+            model_relation::Type::OneToOne,
+            false,
+        );
+
+        self.insert_relation(field_name, relation)
+    }
+
+    /// Insert an optional one-to-one relation into the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    /// * `model_name` - The name of the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the field name is already registered.
+    pub fn insert_optional_one_to_one<S, T>(
+        &mut self,
+        field_name: S,
+        model_name: T,
+    ) -> Result<(), TypeError<'a>>
+    where
+        S: Into<Cow<'a, str>> + Clone,
+        T: Into<Cow<'a, str>>,
+    {
+        let relation = self.default_relation(
+            &field_name.clone().into(),
+            model_name,
+            model_relation::Type::OneToOne,
+            true,
+        );
+
+        self.insert_relation(field_name, relation)
     }
 
     /// Insert a one-to-many relation into the model.
@@ -234,13 +387,14 @@ impl<'a> Model<'a> {
         S: Into<Cow<'a, str>> + Clone,
         T: Into<Cow<'a, str>>,
     {
-        self.insert_relation(
-            field_name,
-            ModelRelation {
-                model_name: model_name.into(),
-                r#type: model_relation::Type::OneToMany,
-            },
-        )
+        let relation = self.default_relation(
+            &field_name.clone().into(),
+            model_name,
+            model_relation::Type::OneToMany,
+            false,
+        );
+
+        self.insert_relation(field_name, relation)
     }
 
     /// Insert a many-to-one relation into the model.
@@ -262,13 +416,43 @@ impl<'a> Model<'a> {
         S: Into<Cow<'a, str>> + Clone,
         T: Into<Cow<'a, str>>,
     {
-        self.insert_relation(
-            field_name,
-            ModelRelation {
-                model_name: model_name.into(),
-                r#type: model_relation::Type::ManyToOne,
-            },
-        )
+        let relation = self.default_relation(
+            &field_name.clone().into(),
+            model_name,
+            model_relation::Type::ManyToOne,
+            false,
+        );
+
+        self.insert_relation(field_name, relation)
+    }
+
+    /// Insert an optional many-to-one relation into the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    /// * `model_name` - The name of the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the field name is already registered.
+    pub fn insert_optional_many_to_one<S, T>(
+        &mut self,
+        field_name: S,
+        model_name: T,
+    ) -> Result<(), TypeError<'a>>
+    where
+        S: Into<Cow<'a, str>> + Clone,
+        T: Into<Cow<'a, str>>,
+    {
+        let relation = self.default_relation(
+            &field_name.clone().into(),
+            model_name,
+            model_relation::Type::ManyToOne,
+            true,
+        );
+
+        self.insert_relation(field_name, relation)
     }
 
     /// Insert a many-to-many relation into the model.
@@ -290,13 +474,14 @@ impl<'a> Model<'a> {
         S: Into<Cow<'a, str>> + Clone,
         T: Into<Cow<'a, str>>,
     {
-        self.insert_relation(
-            field_name,
-            ModelRelation {
-                model_name: model_name.into(),
-                r#type: model_relation::Type::ManyToMany,
-            },
-        )
+        let relation = self.default_relation(
+            &field_name.clone().into(),
+            model_name,
+            model_relation::Type::ManyToMany,
+            false,
+        );
+
+        self.insert_relation(field_name, relation)
     }
 
     /// Insert an enum array relation into the model.
@@ -327,6 +512,7 @@ impl<'a> Model<'a> {
                 EnumRelation {
                     name: enum_name.into(),
                     cardinality: Cardinality::Many,
+                    doc: None,
                 },
             )
             .is_some()
@@ -340,6 +526,318 @@ impl<'a> Model<'a> {
         Ok(())
     }
 
+    /// Mark a data field or enum relation as unique.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the field is not a data field or enum
+    /// relation, or if it is a list.
+    pub fn insert_unique<S>(
+        &mut self,
+        field_name: S,
+    ) -> Result<(), TypeError<'a>>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let field_name = field_name.into();
+
+        if !matches!(
+            self.scalar_cardinality(&field_name),
+            Some(Cardinality::One | Cardinality::Optional)
+        ) {
+            return Err(TypeError::invalid_model_attribute(
+                self.name(),
+                field_name,
+                "unique",
+            ));
+        }
+
+        let _: bool = self.unique_fields.insert(field_name);
+
+        Ok(())
+    }
+
+    /// Document a data field, enum relation or model relation of the model.
+    /// Does nothing if the model has no field or relation with the given
+    /// name.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_name` - The name of the field or relation.
+    /// * `doc` - The documentation.
+    pub fn insert_field_doc<S>(
+        &mut self,
+        field_name: S,
+        doc: Cow<'a, str>,
+    ) where
+        S: AsRef<str>,
+    {
+        let field_name = field_name.as_ref();
+
+        if let Some(field) = self.fields.get_mut(field_name) {
+            field.doc = Some(doc);
+        } else if let Some(relation) = self.enums.get_mut(field_name) {
+            relation.doc = Some(doc);
+        } else if let Some(relation) = self.relations.get_mut(field_name) {
+            relation.doc = Some(doc);
+        }
+    }
+
+    /// Insert an index into the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index to insert.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if a field of the index is undefined or a
+    /// relation to another model.
+    pub fn insert_index(
+        &mut self,
+        index: Index<'a>,
+    ) -> Result<(), TypeError<'a>> {
+        for field_name in &index.fields {
+            if !self.keys.contains(field_name) {
+                return Err(TypeError::undefined_model_index_field(
+                    self.name(),
+                    field_name.clone(),
+                ));
+            }
+
+            if self.relations.contains_key(field_name) {
+                return Err(TypeError::invalid_model_index_field(
+                    self.name(),
+                    field_name.clone(),
+                ));
+            }
+        }
+
+        self.indexes.push(index);
+
+        Ok(())
+    }
+
+    /// Set the way the records of the model are identified.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The id of the model.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if a field of the id is undefined, or if it is
+    /// not a required data field.
+    pub fn insert_id(
+        &mut self,
+        id: Id<'a>,
+    ) -> Result<(), TypeError<'a>> {
+        let field_names = match &id {
+            Id::Composite(field_names) => field_names.as_slice(),
+            Id::Field(field_name) => std::slice::from_ref(field_name),
+            Id::Generated(_) => &[],
+        };
+
+        for field_name in field_names {
+            if !self.keys.contains(field_name) {
+                return Err(TypeError::undefined_model_id_field(
+                    self.name(),
+                    field_name.clone(),
+                ));
+            }
+
+            if !matches!(
+                self.fields.get(field_name),
+                Some(Field {
+                    cardinality: Cardinality::One,
+                    ..
+                })
+            ) {
+                return Err(TypeError::invalid_model_id_field(
+                    self.name(),
+                    field_name.clone(),
+                ));
+            }
+        }
+
+        self.id = id;
+
+        Ok(())
+    }
+
+    /// Get the names and types of the fields that identify a record.
+    #[must_use]
+    pub fn id_fields(&self) -> Vec<(Cow<'a, str>, Type)> {
+        match &self.id {
+            Id::Composite(field_names) => {
+                field_names
+                    .iter()
+                    .filter_map(|field_name| {
+                        self.fields
+                            .get(field_name)
+                            .map(|field| (field_name.clone(), field.r#type))
+                    })
+                    .collect()
+            }
+            Id::Field(field_name) => {
+                self.fields
+                    .get(field_name)
+                    .map(|field| (field_name.clone(), field.r#type))
+                    .into_iter()
+                    .collect()
+            }
+            Id::Generated(strategy) => vec![("id".into(), strategy.r#type())],
+        }
+    }
+
+    /// Get the type of the generated `id` field. Returns `None` if the id of
+    /// the model is not generated, or if the name is not `id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field.
+    #[must_use]
+    pub fn generated_id_type<S>(
+        &self,
+        name: S,
+    ) -> Option<Type>
+    where
+        S: AsRef<str>,
+    {
+        match &self.id {
+            Id::Generated(strategy) if name.as_ref() == "id" => {
+                Some(strategy.r#type())
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the type of a field that identifies a single record on its own:
+    /// the id field, if the id is not composite, or a unique data field.
+    /// Returns `None` for any other field.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field.
+    #[must_use]
+    pub fn unique_field_type<S>(
+        &self,
+        name: S,
+    ) -> Option<Type>
+    where
+        S: AsRef<str>,
+    {
+        let name = name.as_ref();
+
+        if let [(id_name, r#type)] = self.id_fields().as_slice() {
+            if id_name == name {
+                return Some(*r#type);
+            }
+        }
+
+        self.unique_fields
+            .contains(name)
+            .then(|| self.fields.get(name).map(|field| field.r#type))
+            .flatten()
+    }
+
+    /// Get the sets of fields that identify a single record: the id fields,
+    /// every unique field and the fields of every unique index.
+    #[must_use]
+    pub fn unique_keys(&self) -> Vec<Vec<Cow<'a, str>>> {
+        let id_fields = self
+            .id_fields()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+
+        Some(id_fields)
+            .filter(|fields| !fields.is_empty())
+            .into_iter()
+            .chain(self.unique_fields.iter().map(|name| vec![name.clone()]))
+            .chain(
+                self.indexes
+                    .iter()
+                    .filter(|index| index.unique)
+                    .map(|index| index.fields.clone()),
+            )
+            .collect()
+    }
+
+    /// Get the names of the fields, enum relations and model relations that
+    /// must be given a value when a record is created: those that are not
+    /// optional, not lists, not timestamps, and have no default value, and
+    /// required many-to-one relations.
+    #[must_use]
+    pub fn required_fields(&self) -> Vec<Cow<'a, str>> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, field)| (name, field.cardinality));
+
+        let enums = self
+            .enums
+            .iter()
+            .map(|(name, relation)| (name, relation.cardinality));
+
+        let relations = self.relations.iter().filter_map(|(name, relation)| {
+            (relation.r#type == model_relation::Type::ManyToOne
+                && !relation.optional)
+                .then_some(name)
+        });
+
+        fields
+            .chain(enums)
+            .filter_map(|(name, cardinality)| {
+                (cardinality == Cardinality::One
+                    && !self.defaults.contains_key(name)
+                    && self.timestamps.created_at.as_deref() != Some(name)
+                    && self.timestamps.updated_at.as_deref() != Some(name))
+                .then_some(name)
+            })
+            .chain(relations)
+            .map(|name| Cow::Owned(name.to_owned()))
+            .collect()
+    }
+
+    /// Insert the automatic timestamp fields into the model.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamps` - The timestamp fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the name of a timestamp field is already
+    /// registered.
+    pub fn insert_timestamps(
+        &mut self,
+        timestamps: Timestamps<'a>,
+    ) -> Result<(), TypeError<'a>> {
+        for (name, cardinality) in [
+            (&timestamps.created_at, Cardinality::One),
+            (&timestamps.updated_at, Cardinality::One),
+            (&timestamps.deleted_at, Cardinality::Optional),
+        ] {
+            if let Some(name) = name {
+                self.insert_field(Field {
+                    name: name.clone(),
+                    r#type: Type::DateTime,
+                    cardinality,
+                    doc: None,
+                })?;
+            }
+        }
+
+        self.timestamps = timestamps;
+
+        Ok(())
+    }
+
     /// Get the name of the model.
     #[must_use]
     pub fn name(&self) -> Cow<'a, str> {
@@ -393,13 +891,36 @@ impl<'a> Model<'a> {
     {
         self.relations.get(name.as_ref()).cloned()
     }
+
+    /// Get the cardinality of a data field or enum relation by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field or enum relation.
+    #[must_use]
+    pub fn scalar_cardinality<S>(
+        &self,
+        name: S,
+    ) -> Option<Cardinality>
+    where
+        S: AsRef<str>,
+    {
+        self.fields.get(name.as_ref()).map_or_else(
+            || {
+                self.enums
+                    .get(name.as_ref())
+                    .map(|relation| relation.cardinality)
+            },
+            |field| Some(field.cardinality),
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use {
         super::*,
-        crate::Type,
+        id::Strategy,
     };
 
     #[test]
@@ -412,6 +933,13 @@ mod tests {
                 relations: OrdStrMap::new(),
                 enums: OrdStrMap::new(),
                 keys: BTreeSet::new(),
+                doc: None,
+                defaults: OrdStrMap::new(),
+                unique_fields: BTreeSet::new(),
+                indexes: Vec::new(),
+                id: Id::default(),
+                timestamps: Timestamps::default(),
+                native_types: OrdStrMap::new(),
             }
         );
     }
@@ -425,6 +953,7 @@ mod tests {
                 name: "name".into(),
                 r#type: Type::String,
                 cardinality: Cardinality::One,
+                doc: None,
             })
             .unwrap();
 
@@ -433,6 +962,7 @@ mod tests {
                 name: "name".into(),
                 r#type: Type::String,
                 cardinality: Cardinality::One,
+                doc: None,
             }),
             Err(TypeError::duplicate_model_field("User", "name"))
         );
@@ -473,4 +1003,265 @@ mod tests {
             Err(TypeError::duplicate_model_field("User", "name"))
         );
     }
+
+    #[test]
+    fn test_insert_unique() {
+        let mut model = Model::new("User");
+
+        model
+            .insert_field(Field {
+                name: "email".into(),
+                r#type: Type::String,
+                cardinality: Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        model
+            .insert_field(Field {
+                name: "tags".into(),
+                r#type: Type::String,
+                cardinality: Cardinality::Many,
+                doc: None,
+            })
+            .unwrap();
+
+        model.insert_one_to_one("profile", "Profile").unwrap();
+
+        assert!(model.insert_unique("email").is_ok());
+        assert!(model.unique_fields.contains("email"));
+
+        assert_eq!(
+            model.insert_unique("tags"),
+            Err(TypeError::invalid_model_attribute("User", "tags", "unique"))
+        );
+
+        assert_eq!(
+            model.insert_unique("profile"),
+            Err(TypeError::invalid_model_attribute(
+                "User", "profile", "unique"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_insert_field_doc() {
+        let mut model = Model::new("User");
+
+        model
+            .insert_field(Field {
+                name: "name".into(),
+                r#type: Type::String,
+                cardinality: Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        model.insert_enum_relation("role", "Role").unwrap();
+        model.insert_many_to_one("country", "Country").unwrap();
+        model.insert_field_doc("name", "The full name.".into());
+        model.insert_field_doc("role", "The role.".into());
+        model.insert_field_doc("country", "The country.".into());
+        model.insert_field_doc("age", "The age.".into());
+
+        assert_eq!(
+            model.field("name").unwrap().doc,
+            Some("The full name.".into())
+        );
+
+        assert_eq!(
+            model.enum_relation("role").unwrap().doc,
+            Some("The role.".into())
+        );
+
+        assert_eq!(
+            model.model_relation("country").unwrap().doc,
+            Some("The country.".into())
+        );
+
+        assert!(!model.keys.contains("age"));
+    }
+
+    #[test]
+    fn test_insert_index() {
+        let mut model = Model::new("Post");
+
+        model
+            .insert_field(Field {
+                name: "title".into(),
+                r#type: Type::String,
+                cardinality: Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        model.insert_many_to_one("author", "User").unwrap();
+
+        assert!(model
+            .insert_index(Index {
+                fields: vec!["title".into()],
+                unique: false,
+            })
+            .is_ok());
+
+        assert_eq!(
+            model.insert_index(Index {
+                fields: vec!["title".into(), "slug".into()],
+                unique: true,
+            }),
+            Err(TypeError::undefined_model_index_field("Post", "slug"))
+        );
+
+        assert_eq!(
+            model.insert_index(Index {
+                fields: vec!["author".into()],
+                unique: false,
+            }),
+            Err(TypeError::invalid_model_index_field("Post", "author"))
+        );
+
+        assert_eq!(model.indexes.len(), 1);
+    }
+
+    #[test]
+    fn test_insert_id() {
+        let mut model = Model::new("Passport");
+
+        model
+            .insert_field(Field {
+                name: "countryCode".into(),
+                r#type: Type::String,
+                cardinality: Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        model
+            .insert_field(Field {
+                name: "number".into(),
+                r#type: Type::Int,
+                cardinality: Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        model
+            .insert_field(Field {
+                name: "stamps".into(),
+                r#type: Type::String,
+                cardinality: Cardinality::Many,
+                doc: None,
+            })
+            .unwrap();
+
+        assert_eq!(model.id_fields(), vec![("id".into(), Type::Int)]);
+
+        assert_eq!(
+            model.insert_id(Id::Field("holder".into())),
+            Err(TypeError::undefined_model_id_field("Passport", "holder"))
+        );
+
+        assert_eq!(
+            model.insert_id(Id::Field("stamps".into())),
+            Err(TypeError::invalid_model_id_field("Passport", "stamps"))
+        );
+
+        model
+            .insert_id(Id::Composite(vec![
+                "countryCode".into(),
+                "number".into(),
+            ]))
+            .unwrap();
+
+        assert_eq!(
+            model.id_fields(),
+            vec![
+                ("countryCode".into(), Type::String),
+                ("number".into(), Type::Int),
+            ]
+        );
+
+        model.insert_id(Id::Generated(Strategy::Uuid)).unwrap();
+
+        assert_eq!(model.id_fields(), vec![("id".into(), Type::String)]);
+    }
+
+    #[test]
+    fn test_insert_timestamps() {
+        let mut model = Model::new("Post");
+
+        model
+            .insert_field(Field {
+                name: "updatedAt".into(),
+                r#type: Type::DateTime,
+                cardinality: Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        model
+            .insert_timestamps(Timestamps {
+                created_at: Some("insertedAt".into()),
+                updated_at: None,
+                deleted_at: Some("deletedAt".into()),
+            })
+            .unwrap();
+
+        assert_eq!(
+            model.field("deletedAt"),
+            Some(Field {
+                name: "deletedAt".into(),
+                r#type: Type::DateTime,
+                cardinality: Cardinality::Optional,
+                doc: None,
+            })
+        );
+
+        assert_eq!(
+            model.insert_timestamps(Timestamps {
+                updated_at: Some("updatedAt".into()),
+                ..Timestamps::default()
+            }),
+            Err(TypeError::duplicate_model_field("Post", "updatedAt"))
+        );
+    }
+
+    #[test]
+    fn test_required_fields() {
+        let mut model = Model::new("Post");
+
+        for (name, cardinality) in [
+            ("title", Cardinality::One),
+            ("subtitle", Cardinality::Optional),
+            ("tags", Cardinality::Many),
+            ("views", Cardinality::One),
+        ] {
+            model
+                .insert_field(Field {
+                    name: name.into(),
+                    r#type: Type::String,
+                    cardinality,
+                    doc: None,
+                })
+                .unwrap();
+        }
+
+        let _: Option<Value<'_>> =
+            model.defaults.insert("views", Value::Int("0".into()));
+
+        model.insert_enum_relation("status", "Status").unwrap();
+        model.insert_many_to_one("author", "User").unwrap();
+        model.insert_optional_many_to_one("editor", "User").unwrap();
+        model.insert_one_to_many("comments", "Comment").unwrap();
+
+        model
+            .insert_timestamps(Timestamps {
+                created_at: Some("createdAt".into()),
+                updated_at: Some("updatedAt".into()),
+                deleted_at: None,
+            })
+            .unwrap();
+
+        assert_eq!(model.required_fields(), vec!["title", "status", "author"]);
+    }
 }
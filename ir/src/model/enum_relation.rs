@@ -10,4 +10,6 @@ pub struct EnumRelation<'a> {
     pub name: Cow<'a, str>,
     /// The cardinality of the relation.
     pub cardinality: Cardinality,
+    /// The documentation of the field.
+    pub doc: Option<Cow<'a, str>>,
 }
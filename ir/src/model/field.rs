@@ -15,4 +15,6 @@ pub struct Field<'a> {
     pub r#type: Type,
     /// The cardinality of the field.
     pub cardinality: Cardinality,
+    /// The documentation of the field.
+    pub doc: Option<Cow<'a, str>>,
 }
@@ -0,0 +1,85 @@
+use {
+    crate::Type,
+    std::borrow::Cow,
+};
+
+/// The way the records of a model are identified.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Id<'a> {
+    /// A combination of data fields.
+    Composite(Vec<Cow<'a, str>>),
+    /// A single data field.
+    Field(Cow<'a, str>),
+    /// A generated `id` field.
+    Generated(Strategy),
+}
+
+impl Default for Id<'_> {
+    fn default() -> Self {
+        Self::Generated(Strategy::AutoIncrement)
+    }
+}
+
+/// The way the values of a generated `id` field are created.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Strategy {
+    /// An auto-incrementing integer.
+    AutoIncrement,
+    /// A CUID string.
+    Cuid,
+    /// A UUID string.
+    Uuid,
+}
+
+impl Strategy {
+    /// Get a strategy by its name in the DSL. Returns `None` if the name is
+    /// unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the strategy.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::model::id::Strategy;
+    ///
+    /// assert_eq!(Strategy::from_name("uuid"), Some(Strategy::Uuid));
+    /// assert_eq!(Strategy::from_name("serial"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "autoincrement" => Some(Self::AutoIncrement),
+            "cuid" => Some(Self::Cuid),
+            "uuid" => Some(Self::Uuid),
+            _ => None,
+        }
+    }
+
+    /// The type of the generated `id` field.
+    #[must_use]
+    pub const fn r#type(self) -> Type {
+        match self {
+            Self::AutoIncrement => Type::Int,
+            Self::Cuid | Self::Uuid => Type::String,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Id::default(), Id::Generated(Strategy::AutoIncrement));
+    }
+
+    #[test]
+    fn test_type() {
+        assert_eq!(Strategy::AutoIncrement.r#type(), Type::Int);
+        assert_eq!(Strategy::Cuid.r#type(), Type::String);
+        assert_eq!(Strategy::Uuid.r#type(), Type::String);
+    }
+}
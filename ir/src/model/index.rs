@@ -0,0 +1,10 @@
+use std::borrow::Cow;
+
+/// An index on one or more fields of a model.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Index<'a> {
+    /// The names of the indexed fields.
+    pub fields: Vec<Cow<'a, str>>,
+    /// Whether no two records can have the same values for the fields.
+    pub unique: bool,
+}
@@ -1,4 +1,7 @@
-use std::borrow::Cow;
+use {
+    super::ReferentialAction,
+    std::borrow::Cow,
+};
 
 /// A model relation type.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -20,4 +23,23 @@ pub struct ModelRelation<'a> {
     pub model_name: Cow<'a, str>,
     /// The cardinality of the relation.
     pub r#type: Type,
+    /// Whether the related model may be absent. Only applies to one-to-one
+    /// and many-to-one relations.
+    pub optional: bool,
+    /// The name of the relation, shared by both of its sides.
+    pub name: Cow<'a, str>,
+    /// The name of the field on the related model that refers back to this
+    /// model.
+    pub inverse_name: Cow<'a, str>,
+    /// Whether the inverse field is declared in the related model, rather
+    /// than generated.
+    pub inverse_declared: bool,
+    /// What happens to the records of the relation when the record they
+    /// refer to is deleted.
+    pub on_delete: Option<ReferentialAction>,
+    /// What happens to the records of the relation when the id of the record
+    /// they refer to is updated.
+    pub on_update: Option<ReferentialAction>,
+    /// The documentation of the field.
+    pub doc: Option<Cow<'a, str>>,
 }
@@ -0,0 +1,332 @@
+use {
+    crate::{
+        database::Provider,
+        Type,
+    },
+    std::{
+        borrow::Cow,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+    },
+};
+
+/// A native type: its name, the scalar type it stores, and the minimum and
+/// maximum number of arguments it takes.
+type Signature = (&'static str, Type, usize, usize);
+
+/// Native types of `PostgreSQL`.
+const POSTGRESQL: &[Signature] = &[
+    ("BigInt", Type::BigInt, 0, 0),
+    ("Bit", Type::String, 0, 1),
+    ("Boolean", Type::Boolean, 0, 0),
+    ("ByteA", Type::Bytes, 0, 0),
+    ("Char", Type::String, 0, 1),
+    ("Citext", Type::String, 0, 0),
+    ("Date", Type::DateTime, 0, 0),
+    ("Decimal", Type::Decimal, 0, 2),
+    ("DoublePrecision", Type::Float, 0, 0),
+    ("Inet", Type::String, 0, 0),
+    ("Integer", Type::Int, 0, 0),
+    ("Json", Type::Json, 0, 0),
+    ("JsonB", Type::Json, 0, 0),
+    ("Money", Type::Decimal, 0, 0),
+    ("Oid", Type::Int, 0, 0),
+    ("Real", Type::Float, 0, 0),
+    ("SmallInt", Type::Int, 0, 0),
+    ("Text", Type::String, 0, 0),
+    ("Time", Type::DateTime, 0, 1),
+    ("Timestamp", Type::DateTime, 0, 1),
+    ("Timestamptz", Type::DateTime, 0, 1),
+    ("Timetz", Type::DateTime, 0, 1),
+    ("Uuid", Type::String, 0, 0),
+    ("VarBit", Type::String, 0, 1),
+    ("VarChar", Type::String, 0, 1),
+    ("Xml", Type::String, 0, 0),
+];
+
+/// Native types of `CockroachDB`.
+const COCKROACHDB: &[Signature] = &[
+    ("Bit", Type::String, 0, 1),
+    ("Bool", Type::Boolean, 0, 0),
+    ("Bytes", Type::Bytes, 0, 0),
+    ("CatalogSingleChar", Type::String, 0, 0),
+    ("Char", Type::String, 0, 1),
+    ("Date", Type::DateTime, 0, 0),
+    ("Decimal", Type::Decimal, 0, 2),
+    ("Float4", Type::Float, 0, 0),
+    ("Float8", Type::Float, 0, 0),
+    ("Inet", Type::String, 0, 0),
+    ("Int2", Type::Int, 0, 0),
+    ("Int4", Type::Int, 0, 0),
+    ("Int8", Type::BigInt, 0, 0),
+    ("JsonB", Type::Json, 0, 0),
+    ("String", Type::String, 0, 1),
+    ("Time", Type::DateTime, 0, 1),
+    ("Timestamp", Type::DateTime, 0, 1),
+    ("Timestamptz", Type::DateTime, 0, 1),
+    ("Timetz", Type::DateTime, 0, 1),
+    ("Uuid", Type::String, 0, 0),
+    ("VarBit", Type::String, 0, 1),
+];
+
+/// Native types of `MySQL`.
+const MYSQL: &[Signature] = &[
+    ("BigInt", Type::BigInt, 0, 0),
+    ("Binary", Type::Bytes, 0, 1),
+    ("Bit", Type::Boolean, 0, 1),
+    ("Bit", Type::Bytes, 0, 1),
+    ("Blob", Type::Bytes, 0, 0),
+    ("Char", Type::String, 1, 1),
+    ("Date", Type::DateTime, 0, 0),
+    ("DateTime", Type::DateTime, 0, 1),
+    ("Decimal", Type::Decimal, 0, 2),
+    ("Double", Type::Float, 0, 0),
+    ("Float", Type::Float, 0, 0),
+    ("Int", Type::Int, 0, 0),
+    ("Json", Type::Json, 0, 0),
+    ("LongBlob", Type::Bytes, 0, 0),
+    ("LongText", Type::String, 0, 0),
+    ("MediumBlob", Type::Bytes, 0, 0),
+    ("MediumInt", Type::Int, 0, 0),
+    ("MediumText", Type::String, 0, 0),
+    ("SmallInt", Type::Int, 0, 0),
+    ("Text", Type::String, 0, 0),
+    ("Time", Type::DateTime, 0, 1),
+    ("Timestamp", Type::DateTime, 0, 1),
+    ("TinyBlob", Type::Bytes, 0, 0),
+    ("TinyInt", Type::Boolean, 0, 1),
+    ("TinyInt", Type::Int, 0, 1),
+    ("TinyText", Type::String, 0, 0),
+    ("UnsignedBigInt", Type::BigInt, 0, 0),
+    ("UnsignedInt", Type::Int, 0, 0),
+    ("UnsignedMediumInt", Type::Int, 0, 0),
+    ("UnsignedSmallInt", Type::Int, 0, 0),
+    ("UnsignedTinyInt", Type::Int, 0, 0),
+    ("VarBinary", Type::Bytes, 1, 1),
+    ("VarChar", Type::String, 1, 1),
+    ("Year", Type::Int, 0, 0),
+];
+
+/// Native types of SQL Server.
+const SQLSERVER: &[Signature] = &[
+    ("BigInt", Type::BigInt, 0, 0),
+    ("Binary", Type::Bytes, 0, 1),
+    ("Bit", Type::Boolean, 0, 0),
+    ("Bit", Type::Int, 0, 0),
+    ("Char", Type::String, 0, 1),
+    ("Date", Type::DateTime, 0, 0),
+    ("DateTime", Type::DateTime, 0, 0),
+    ("DateTime2", Type::DateTime, 0, 0),
+    ("DateTimeOffset", Type::DateTime, 0, 0),
+    ("Decimal", Type::Decimal, 0, 2),
+    ("Float", Type::Float, 0, 1),
+    ("Image", Type::Bytes, 0, 0),
+    ("Int", Type::Int, 0, 0),
+    ("Money", Type::Decimal, 0, 0),
+    ("NChar", Type::String, 0, 1),
+    ("NText", Type::String, 0, 0),
+    ("NVarChar", Type::String, 0, 1),
+    ("Real", Type::Float, 0, 0),
+    ("SmallDateTime", Type::DateTime, 0, 0),
+    ("SmallInt", Type::Int, 0, 0),
+    ("SmallMoney", Type::Decimal, 0, 0),
+    ("Text", Type::String, 0, 0),
+    ("Time", Type::DateTime, 0, 0),
+    ("TinyInt", Type::Int, 0, 0),
+    ("UniqueIdentifier", Type::String, 0, 0),
+    ("VarBinary", Type::Bytes, 0, 1),
+    ("VarChar", Type::String, 0, 1),
+    ("Xml", Type::String, 0, 0),
+];
+
+/// Native types of `MongoDB`.
+const MONGODB: &[Signature] = &[
+    ("BinData", Type::Bytes, 0, 0),
+    ("Bool", Type::Boolean, 0, 0),
+    ("Date", Type::DateTime, 0, 0),
+    ("Double", Type::Float, 0, 0),
+    ("Int", Type::BigInt, 0, 0),
+    ("Int", Type::Int, 0, 0),
+    ("Long", Type::BigInt, 0, 0),
+    ("Long", Type::Int, 0, 0),
+    ("ObjectId", Type::Bytes, 0, 0),
+    ("ObjectId", Type::String, 0, 0),
+    ("String", Type::String, 0, 0),
+    ("Timestamp", Type::DateTime, 0, 0),
+];
+
+/// The native database type of a field, such as `VarChar(255)`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NativeType<'a> {
+    /// The name of the native type.
+    pub name: Cow<'a, str>,
+    /// The arguments of the native type, such as a length or precision.
+    pub arguments: Vec<Cow<'a, str>>,
+}
+
+impl NativeType<'_> {
+    /// The native types that the given provider supports.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The database provider.
+    const fn signatures(provider: Provider) -> &'static [Signature] {
+        match provider {
+            Provider::CockroachDb => COCKROACHDB,
+            Provider::MongoDb => MONGODB,
+            Provider::MySql => MYSQL,
+            Provider::PostgreSql => POSTGRESQL,
+            Provider::SqlServer => SQLSERVER,
+            Provider::Sqlite => &[],
+        }
+    }
+
+    /// Whether the given provider can store fields of the given type as this
+    /// native type. The name must be known to the provider, it must store
+    /// values of the type, and it must take this many arguments. Arguments
+    /// must be numbers, or `Max` for SQL Server.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The database provider.
+    /// * `r#type` - The type of the field.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::{
+    ///     database::Provider,
+    ///     model::NativeType,
+    ///     Type,
+    /// };
+    ///
+    /// let native_type = NativeType {
+    ///     name: "VarChar".into(),
+    ///     arguments: vec!["255".into()],
+    /// };
+    ///
+    /// assert!(native_type.is_supported(Provider::PostgreSql, Type::String));
+    /// assert!(native_type.is_supported(Provider::MySql, Type::Uuid));
+    /// assert!(!native_type.is_supported(Provider::PostgreSql, Type::Int));
+    /// assert!(!native_type.is_supported(Provider::Sqlite, Type::String));
+    /// ```
+    #[must_use]
+    pub fn is_supported(
+        &self,
+        provider: Provider,
+        r#type: Type,
+    ) -> bool {
+        let r#type = if r#type == Type::Uuid {
+            Type::String
+        } else {
+            r#type
+        };
+
+        let arguments_valid = self.arguments.iter().all(|argument| {
+            argument.bytes().all(|byte| byte.is_ascii_digit())
+                || (provider == Provider::SqlServer && argument == "Max")
+        });
+
+        arguments_valid
+            && Self::signatures(provider).iter().any(
+                |&(name, stored_type, min, max)| {
+                    name == self.name
+                        && stored_type == r#type
+                        && (min..=max).contains(&self.arguments.len())
+                },
+            )
+    }
+}
+
+impl<'a> From<ast::model::attribute::NativeType<'a>> for NativeType<'a> {
+    fn from(
+        ast::model::attribute::NativeType { name, arguments }: ast::model::attribute::NativeType<'a>
+    ) -> Self {
+        Self { name, arguments }
+    }
+}
+
+impl Display for NativeType<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let Self { name, arguments } = self;
+
+        if arguments.is_empty() {
+            write!(f, "@db.{name}")
+        } else {
+            write!(f, "@db.{name}({})", arguments.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn native_type(
+        name: &'static str,
+        arguments: &[&'static str],
+    ) -> NativeType<'static> {
+        NativeType {
+            name: name.into(),
+            arguments: arguments
+                .iter()
+                .map(|&argument| argument.into())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_is_supported() {
+        assert!(native_type("Timestamptz", &["6"])
+            .is_supported(Provider::PostgreSql, Type::DateTime));
+
+        assert!(native_type("Decimal", &["10", "2"])
+            .is_supported(Provider::MySql, Type::Decimal));
+
+        assert!(native_type("Uuid", &[])
+            .is_supported(Provider::PostgreSql, Type::Uuid));
+
+        assert!(native_type("VarChar", &["Max"])
+            .is_supported(Provider::SqlServer, Type::String));
+
+        assert!(native_type("Long", &[])
+            .is_supported(Provider::MongoDb, Type::BigInt));
+    }
+
+    #[test]
+    fn test_is_supported_invalid() {
+        assert!(!native_type("Timestamptz", &["6"])
+            .is_supported(Provider::MySql, Type::DateTime));
+
+        assert!(!native_type("Text", &["255"])
+            .is_supported(Provider::PostgreSql, Type::String));
+
+        assert!(!native_type("VarChar", &[])
+            .is_supported(Provider::MySql, Type::String));
+
+        assert!(!native_type("VarChar", &["Max"])
+            .is_supported(Provider::PostgreSql, Type::String));
+
+        assert!(!native_type("Text", &[])
+            .is_supported(Provider::PostgreSql, Type::Int));
+
+        assert!(!native_type("Text", &[])
+            .is_supported(Provider::Sqlite, Type::String));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(native_type("Text", &[]).to_string(), "@db.Text");
+
+        assert_eq!(
+            native_type("Decimal", &["10", "2"]).to_string(),
+            "@db.Decimal(10, 2)"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use {
+    crate::database::{
+        Database,
+        Provider,
+        RelationMode,
+    },
+    std::fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// What happens to the records of a relation when the record they refer to
+/// is deleted or its id is updated.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ReferentialAction {
+    /// The records are deleted or updated as well.
+    Cascade,
+    /// The operation fails if records refer to the record, checked at the
+    /// end of the transaction.
+    NoAction,
+    /// The operation fails if records refer to the record.
+    Restrict,
+    /// The foreign keys of the records are set to their default values.
+    SetDefault,
+    /// The foreign keys of the records are set to null.
+    SetNull,
+}
+
+impl ReferentialAction {
+    /// Get a referential action by its name in the DSL. Returns `None` if the
+    /// name is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the referential action.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::model::ReferentialAction;
+    ///
+    /// assert_eq!(
+    ///     ReferentialAction::from_name("Cascade"),
+    ///     Some(ReferentialAction::Cascade)
+    /// );
+    ///
+    /// assert_eq!(ReferentialAction::from_name("Ignore"), None);
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "Cascade" => Some(Self::Cascade),
+            "NoAction" => Some(Self::NoAction),
+            "Restrict" => Some(Self::Restrict),
+            "SetDefault" => Some(Self::SetDefault),
+            "SetNull" => Some(Self::SetNull),
+            _ => None,
+        }
+    }
+
+    /// Whether the given database supports this referential action. When
+    /// relations are emulated by Prisma, `SetDefault` is not supported, nor
+    /// is `NoAction` on `PostgreSQL` and `SQLite`. SQL Server does not
+    /// support `Restrict`.
+    ///
+    /// # Arguments
+    ///
+    /// * `database` - The database.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::{
+    ///     database::{
+    ///         Provider,
+    ///         RelationMode,
+    ///     },
+    ///     model::ReferentialAction,
+    ///     Database,
+    /// };
+    ///
+    /// let mut database = Database::new(Provider::PostgreSql);
+    ///
+    /// database.relation_mode = RelationMode::Prisma;
+    ///
+    /// assert!(ReferentialAction::Cascade.is_supported(&database));
+    /// assert!(!ReferentialAction::SetDefault.is_supported(&database));
+    ///
+    /// database.relation_mode = RelationMode::ForeignKeys;
+    ///
+    /// assert!(ReferentialAction::SetDefault.is_supported(&database));
+    /// ```
+    #[must_use]
+    pub const fn is_supported(
+        self,
+        database: &Database<'_>,
+    ) -> bool {
+        !matches!(
+            (self, database.relation_mode, database.provider),
+            (Self::SetDefault, RelationMode::Prisma, _)
+                | (
+                    Self::NoAction,
+                    RelationMode::Prisma,
+                    Provider::PostgreSql | Provider::Sqlite,
+                )
+                | (Self::Restrict, _, Provider::SqlServer)
+        )
+    }
+}
+
+impl Display for ReferentialAction {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Cascade => write!(f, "Cascade"),
+            Self::NoAction => write!(f, "NoAction"),
+            Self::Restrict => write!(f, "Restrict"),
+            Self::SetDefault => write!(f, "SetDefault"),
+            Self::SetNull => write!(f, "SetNull"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name() {
+        for action in [
+            ReferentialAction::Cascade,
+            ReferentialAction::NoAction,
+            ReferentialAction::Restrict,
+            ReferentialAction::SetDefault,
+            ReferentialAction::SetNull,
+        ] {
+            assert_eq!(
+                ReferentialAction::from_name(&action.to_string()),
+                Some(action)
+            );
+        }
+
+        assert_eq!(ReferentialAction::from_name("cascade"), None);
+    }
+
+    #[test]
+    fn test_is_supported() {
+        let mut database = Database::new(Provider::Sqlite);
+
+        database.relation_mode = RelationMode::Prisma;
+
+        assert!(ReferentialAction::Restrict.is_supported(&database));
+        assert!(!ReferentialAction::NoAction.is_supported(&database));
+
+        database.provider = Provider::MySql;
+
+        assert!(ReferentialAction::NoAction.is_supported(&database));
+
+        database.provider = Provider::SqlServer;
+        database.relation_mode = RelationMode::ForeignKeys;
+
+        assert!(ReferentialAction::NoAction.is_supported(&database));
+        assert!(!ReferentialAction::Restrict.is_supported(&database));
+    }
+}
@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+
+/// The automatic timestamp fields of a model, by the name of each field.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Timestamps<'a> {
+    /// The field that holds the date and time at which a record was created.
+    pub created_at: Option<Cow<'a, str>>,
+    /// The field that holds the date and time at which a record was last
+    /// updated.
+    pub updated_at: Option<Cow<'a, str>>,
+    /// The field that holds the date and time at which a record was
+    /// soft-deleted. Empty while the record is not deleted.
+    pub deleted_at: Option<Cow<'a, str>>,
+}
+
+impl<'a> Timestamps<'a> {
+    /// The timestamps of a model that does not configure them: only
+    /// `createdAt`.
+    #[must_use]
+    pub fn standard() -> Self {
+        Self {
+            created_at: Some("createdAt".into()),
+            ..Self::default()
+        }
+    }
+
+    /// Get the field of a kind of timestamp. Returns `None` if the kind is
+    /// unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of timestamp: `createdAt`, `updatedAt` or
+    ///   `deletedAt`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::model::Timestamps;
+    ///
+    /// let mut timestamps = Timestamps::default();
+    ///
+    /// *timestamps.get_mut("updatedAt").unwrap() = Some("modifiedAt".into());
+    ///
+    /// assert_eq!(timestamps.updated_at, Some("modifiedAt".into()));
+    /// assert!(timestamps.get_mut("archivedAt").is_none());
+    /// ```
+    pub fn get_mut(
+        &mut self,
+        kind: &str,
+    ) -> Option<&mut Option<Cow<'a, str>>> {
+        match kind {
+            "createdAt" => Some(&mut self.created_at),
+            "updatedAt" => Some(&mut self.updated_at),
+            "deletedAt" => Some(&mut self.deleted_at),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard() {
+        assert_eq!(
+            Timestamps::standard(),
+            Timestamps {
+                created_at: Some("createdAt".into()),
+                updated_at: None,
+                deleted_at: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,102 @@
+pub use self::{
+    action::Action,
+    assignment::Assignment,
+    operation::Operation,
+};
+use {
+    crate::query::{
+        Argument,
+        Where,
+    },
+    ord_str_map::OrdStrMap,
+    std::borrow::Cow,
+};
+
+/// What a mutation does with a field.
+pub mod action;
+/// Actions on fields.
+pub mod assignment;
+/// The ways in which a mutation changes records.
+pub mod operation;
+
+/// A mutation.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Mutation<'a> {
+    /// The name of the mutation.
+    pub name: Cow<'a, str>,
+    /// The name of the mutated model.
+    pub model_name: Cow<'a, str>,
+    /// The arguments to the mutation.
+    pub arguments: OrdStrMap<Argument<'a>>,
+    /// The operation of the mutation.
+    pub operation: Operation<'a>,
+    /// The where clause that selects the record to update, delete or upsert.
+    pub r#where: Option<Where<'a>>,
+    /// The documentation of the mutation.
+    pub doc: Option<Cow<'a, str>>,
+}
+
+impl<'a> Mutation<'a> {
+    /// Create a new mutation.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the mutation.
+    /// * `model_name` - The name of the mutated model.
+    /// * `operation` - The operation of the mutation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::mutation::{
+    ///     Mutation,
+    ///     Operation,
+    /// };
+    ///
+    /// let mutation = Mutation::new("deletePost", "Post", Operation::Delete);
+    ///
+    /// assert_eq!(mutation.name, "deletePost");
+    /// assert_eq!(mutation.model_name, "Post");
+    /// assert!(mutation.arguments.is_empty());
+    /// assert!(mutation.r#where.is_none());
+    /// ```
+    #[must_use]
+    pub fn new<S, T>(
+        name: S,
+        model_name: T,
+        operation: Operation<'a>,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            name: name.into(),
+            model_name: model_name.into(),
+            arguments: OrdStrMap::new(),
+            operation,
+            r#where: None,
+            doc: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            Mutation::new("deletePost", "Post", Operation::Delete),
+            Mutation {
+                name: "deletePost".into(),
+                model_name: "Post".into(),
+                arguments: OrdStrMap::new(),
+                operation: Operation::Delete,
+                r#where: None,
+                doc: None,
+            }
+        );
+    }
+}
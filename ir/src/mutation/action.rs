@@ -0,0 +1,13 @@
+use crate::query::Operand;
+
+/// What a mutation does with a field.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Action<'a> {
+    /// Relate the record to the record of the related model with the given
+    /// id.
+    Connect(Operand<'a>),
+    /// Remove the relation to the related record.
+    Disconnect,
+    /// Set the field to the given value.
+    Set(Operand<'a>),
+}
@@ -0,0 +1,13 @@
+use {
+    super::Action,
+    std::borrow::Cow,
+};
+
+/// An action on a field of the mutated record.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Assignment<'a> {
+    /// The name of the field.
+    pub field_name: Cow<'a, str>,
+    /// What is done with the field.
+    pub action: Action<'a>,
+}
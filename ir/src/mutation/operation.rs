@@ -0,0 +1,19 @@
+use super::Assignment;
+
+/// The way a mutation changes the records of a model.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Operation<'a> {
+    /// Create a record.
+    Create(Vec<Assignment<'a>>),
+    /// Delete the matching record.
+    Delete,
+    /// Update the matching record.
+    Update(Vec<Assignment<'a>>),
+    /// Update the matching record, or create it if it does not exist.
+    Upsert {
+        /// The assignments used to create the record.
+        create: Vec<Assignment<'a>>,
+        /// The assignments used to update the record.
+        update: Vec<Assignment<'a>>,
+    },
+}
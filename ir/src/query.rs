@@ -2,7 +2,13 @@ pub use {
     argument::Argument,
     argument_type::ArgumentType,
     condition::Condition,
+    cursor::Cursor,
+    direction::Direction,
+    filter::Filter,
+    mode::Mode,
+    operand::Operand,
     operator::Operator,
+    ordering::Ordering,
     r#where::Where,
     return_type::ReturnType,
     schema::{
@@ -21,8 +27,20 @@ pub mod argument;
 pub mod argument_type;
 /// Query conditions.
 pub mod condition;
+/// Query cursors.
+pub mod cursor;
+/// Query ordering directions.
+pub mod direction;
+/// Query condition trees.
+pub mod filter;
+/// Query condition modes.
+pub mod mode;
+/// Query condition operands.
+pub mod operand;
 /// Query condition operators.
 pub mod operator;
+/// Query result orderings.
+pub mod ordering;
 /// Return types.
 pub mod return_type;
 /// Query schemas.
@@ -43,6 +61,16 @@ pub struct Query<'a> {
     pub schema: Schema<'a>,
     /// The where clause of the query.
     pub r#where: Option<Where<'a>>,
+    /// The orderings of the results, in order of precedence.
+    pub order_by: Vec<Ordering<'a>>,
+    /// The maximum number of results.
+    pub take: Option<Operand<'a>>,
+    /// The number of results to skip.
+    pub skip: Option<Operand<'a>>,
+    /// The record after which the results start.
+    pub cursor: Option<Cursor<'a>>,
+    /// The documentation of the query.
+    pub doc: Option<Cow<'a, str>>,
 }
 
 impl<'a> Query<'a> {
@@ -72,6 +100,11 @@ impl<'a> Query<'a> {
                 nodes: Vec::new(),
             },
             r#where: None,
+            order_by: Vec::new(),
+            take: None,
+            skip: None,
+            cursor: None,
+            doc: None,
         }
     }
 }
@@ -108,6 +141,11 @@ mod tests {
                     nodes: Vec::new(),
                 },
                 r#where: None,
+                order_by: Vec::new(),
+                take: None,
+                skip: None,
+                cursor: None,
+                doc: None,
             }
         );
     }
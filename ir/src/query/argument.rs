@@ -34,98 +34,28 @@ impl<'a> Argument<'a> {
         argument: &ast::query::Argument<'a>,
         enum_names: &BTreeSet<Cow<'a, str>>,
     ) -> Option<Self> {
-        let argument_name = argument.name.clone();
+        let name = argument.name.clone();
+        let cardinality = Cardinality::from(&argument.r#type);
 
-        match &argument.r#type {
-            ast::r#type::Type::Scalar(ast::r#type::Scalar::Boolean) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::Boolean),
-                    cardinality: Cardinality::One,
-                })
-            }
-            ast::r#type::Type::Scalar(ast::r#type::Scalar::DateTime) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::DateTime),
-                    cardinality: Cardinality::One,
-                })
-            }
-            ast::r#type::Type::Scalar(ast::r#type::Scalar::Float) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::Float),
-                    cardinality: Cardinality::One,
-                })
-            }
-            ast::r#type::Type::Scalar(ast::r#type::Scalar::Int) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::Int),
-                    cardinality: Cardinality::One,
-                })
-            }
-            ast::r#type::Type::Scalar(ast::r#type::Scalar::String) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::String),
-                    cardinality: Cardinality::One,
-                })
-            }
-            ast::r#type::Type::Scalar(ast::r#type::Scalar::Reference(name)) => {
-                enum_names.contains(name).then(|| {
+        match argument.r#type.scalar() {
+            ast::r#type::Scalar::Reference(enum_name) => {
+                enum_names.contains(enum_name).then(|| {
                     Self {
-                        name: argument_name,
-                        r#type: ArgumentType::Enum(name.clone()),
-                        cardinality: Cardinality::One,
+                        name,
+                        r#type: ArgumentType::Enum(enum_name.clone()),
+                        cardinality,
                     }
                 })
             }
-            ast::r#type::Type::Array(ast::r#type::Scalar::Boolean) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::Boolean),
-                    cardinality: Cardinality::Many,
-                })
-            }
-            ast::r#type::Type::Array(ast::r#type::Scalar::DateTime) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::DateTime),
-                    cardinality: Cardinality::Many,
-                })
-            }
-            ast::r#type::Type::Array(ast::r#type::Scalar::Float) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::Float),
-                    cardinality: Cardinality::Many,
-                })
-            }
-            ast::r#type::Type::Array(ast::r#type::Scalar::Int) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::Int),
-                    cardinality: Cardinality::Many,
-                })
-            }
-            ast::r#type::Type::Array(ast::r#type::Scalar::String) => {
-                Some(Self {
-                    name: argument_name,
-                    r#type: ArgumentType::Type(Type::String),
-                    cardinality: Cardinality::Many,
-                })
-            }
-            ast::r#type::Type::Array(ast::r#type::Scalar::Reference(name)) => {
-                enum_names.contains(name).then(|| {
+            scalar => {
+                Type::from_ast_scalar(scalar).map(|r#type| {
                     Self {
-                        name: argument_name,
-                        r#type: ArgumentType::Enum(name.clone()),
-                        cardinality: Cardinality::Many,
+                        name,
+                        r#type: ArgumentType::Type(r#type),
+                        cardinality,
                     }
                 })
             }
-            _ => None,
         }
     }
 }
@@ -1,5 +1,9 @@
 use {
-    super::Operator,
+    super::{
+        Mode,
+        Operand,
+        Operator,
+    },
     std::{
         borrow::Cow,
         collections::VecDeque,
@@ -13,6 +17,8 @@ pub struct Condition<'a> {
     pub lhs: VecDeque<Cow<'a, str>>,
     /// The condition operator.
     pub operator: Operator,
-    /// The rhs operand (the argument name).
-    pub rhs: Cow<'a, str>,
+    /// The rhs operand.
+    pub rhs: Operand<'a>,
+    /// How string values are compared.
+    pub mode: Mode,
 }
@@ -0,0 +1,13 @@
+use {
+    super::Operand,
+    std::borrow::Cow,
+};
+
+/// The record after which a paginated query starts.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Cursor<'a> {
+    /// The name of the id or unique field that identifies the record.
+    pub field_name: Cow<'a, str>,
+    /// The value of the field.
+    pub rhs: Operand<'a>,
+}
@@ -0,0 +1,35 @@
+/// The direction in which query results are ordered.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    /// Ascending.
+    Asc,
+    /// Descending.
+    Desc,
+}
+
+impl From<ast::query::order_by::Direction> for Direction {
+    fn from(value: ast::query::order_by::Direction) -> Self {
+        match value {
+            ast::query::order_by::Direction::Asc => Self::Asc,
+            ast::query::order_by::Direction::Desc => Self::Desc,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ast() {
+        assert_eq!(
+            Direction::from(ast::query::order_by::Direction::Asc),
+            Direction::Asc
+        );
+
+        assert_eq!(
+            Direction::from(ast::query::order_by::Direction::Desc),
+            Direction::Desc
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use super::Condition;
+
+/// A tree of query conditions.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Filter<'a> {
+    /// All of the filters must be met.
+    And(Vec<Self>),
+    /// A single condition.
+    Condition(Condition<'a>),
+    /// The filters must not all be met.
+    Not(Vec<Self>),
+    /// At least one of the filters must be met.
+    Or(Vec<Self>),
+}
+
+impl<'a> Filter<'a> {
+    /// The conditions in this filter, in source order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::query::{
+    ///     Condition,
+    ///     Filter,
+    ///     Mode,
+    ///     Operand,
+    ///     Operator,
+    /// };
+    ///
+    /// let condition = Condition {
+    ///     lhs: ["title".into()].into(),
+    ///     operator: Operator::Equals,
+    ///     rhs: Operand::Argument("title".into()),
+    ///     mode: Mode::Default,
+    /// };
+    ///
+    /// let filter = Filter::Or(vec![Filter::Not(vec![Filter::Condition(
+    ///     condition.clone(),
+    /// )])]);
+    ///
+    /// assert_eq!(filter.conditions(), vec![&condition]);
+    /// ```
+    #[must_use]
+    pub fn conditions(&self) -> Vec<&Condition<'a>> {
+        match self {
+            Self::And(filters) | Self::Not(filters) | Self::Or(filters) => {
+                filters.iter().flat_map(Self::conditions).collect()
+            }
+            Self::Condition(condition) => vec![condition],
+        }
+    }
+}
@@ -0,0 +1,36 @@
+/// How string values are compared in a query condition.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Mode {
+    /// Case-sensitive.
+    #[default]
+    Default,
+    /// Case-insensitive.
+    Insensitive,
+}
+
+impl From<ast::query::r#where::Mode> for Mode {
+    fn from(value: ast::query::r#where::Mode) -> Self {
+        match value {
+            ast::query::r#where::Mode::Default => Self::Default,
+            ast::query::r#where::Mode::Insensitive => Self::Insensitive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_ast() {
+        assert_eq!(
+            Mode::from(ast::query::r#where::Mode::Default),
+            Mode::Default
+        );
+
+        assert_eq!(
+            Mode::from(ast::query::r#where::Mode::Insensitive),
+            Mode::Insensitive
+        );
+    }
+}
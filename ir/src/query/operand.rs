@@ -0,0 +1,15 @@
+use {
+    crate::Value,
+    std::borrow::Cow,
+};
+
+/// The rhs operand of a query condition.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Operand<'a> {
+    /// The name of a query argument.
+    Argument(Cow<'a, str>),
+    /// The absence of a value.
+    Null,
+    /// A type-checked literal value.
+    Value(Value<'a>),
+}
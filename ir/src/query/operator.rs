@@ -1,17 +1,47 @@
 /// A query condition operator.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Operator {
-    /// Equals.
-    Equals,
     /// Contains.
     Contains,
+    /// Ends with.
+    EndsWith,
+    /// Equals.
+    Equals,
+    /// Greater than.
+    Gt,
+    /// Greater than or equal to.
+    Gte,
+    /// Is one of.
+    In,
+    /// Is empty.
+    IsNull,
+    /// Less than.
+    Lt,
+    /// Less than or equal to.
+    Lte,
+    /// Does not equal.
+    NotEquals,
+    /// Is not one of.
+    NotIn,
+    /// Starts with.
+    StartsWith,
 }
 
 impl From<ast::query::r#where::Operator> for Operator {
     fn from(value: ast::query::r#where::Operator) -> Self {
         match value {
-            ast::query::r#where::Operator::Equals => Self::Equals,
             ast::query::r#where::Operator::Contains => Self::Contains,
+            ast::query::r#where::Operator::EndsWith => Self::EndsWith,
+            ast::query::r#where::Operator::Equals => Self::Equals,
+            ast::query::r#where::Operator::Gt => Self::Gt,
+            ast::query::r#where::Operator::Gte => Self::Gte,
+            ast::query::r#where::Operator::In => Self::In,
+            ast::query::r#where::Operator::IsNull => Self::IsNull,
+            ast::query::r#where::Operator::Lt => Self::Lt,
+            ast::query::r#where::Operator::Lte => Self::Lte,
+            ast::query::r#where::Operator::NotEquals => Self::NotEquals,
+            ast::query::r#where::Operator::NotIn => Self::NotIn,
+            ast::query::r#where::Operator::StartsWith => Self::StartsWith,
         }
     }
 }
@@ -32,4 +62,17 @@ mod tests {
             Operator::Contains
         );
     }
+
+    #[test]
+    fn test_from_ast_comparison() {
+        assert_eq!(
+            Operator::from(ast::query::r#where::Operator::Gte),
+            Operator::Gte
+        );
+
+        assert_eq!(
+            Operator::from(ast::query::r#where::Operator::NotIn),
+            Operator::NotIn
+        );
+    }
 }
@@ -0,0 +1,16 @@
+use {
+    super::Direction,
+    std::{
+        borrow::Cow,
+        collections::VecDeque,
+    },
+};
+
+/// An ordering of query results on a single field.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Ordering<'a> {
+    /// The path to the field.
+    pub path: VecDeque<Cow<'a, str>>,
+    /// The direction of the ordering.
+    pub direction: Direction,
+}
@@ -1,5 +1,8 @@
 use {
-    super::Condition,
+    super::{
+        Condition,
+        Filter,
+    },
     std::borrow::Cow,
 };
 
@@ -8,6 +11,14 @@ use {
 pub struct Where<'a> {
     /// The alias of the where clause.
     pub alias: Cow<'a, str>,
-    /// The conditions of the where clause.
-    pub conditions: Vec<Condition<'a>>,
+    /// The filters of the where clause.
+    pub filters: Vec<Filter<'a>>,
+}
+
+impl<'a> Where<'a> {
+    /// The conditions of the where clause, in source order.
+    #[must_use]
+    pub fn conditions(&self) -> Vec<&Condition<'a>> {
+        self.filters.iter().flat_map(Filter::conditions).collect()
+    }
 }
@@ -1,14 +1,52 @@
 /// A scalar type.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Type {
+    /// An integer too large for `Int`.
+    BigInt,
     /// A boolean.
     Boolean,
+    /// A sequence of bytes.
+    Bytes,
     /// A date time.
     DateTime,
+    /// An exact decimal number.
+    Decimal,
     /// A floating point number.
     Float,
     /// An integer.
     Int,
+    /// A JSON value.
+    Json,
     /// A string.
     String,
+    /// A UUID.
+    Uuid,
+}
+
+impl Type {
+    /// Create a type from an AST scalar. Returns `None` if the scalar is a
+    /// reference to a model or enum.
+    ///
+    /// # Arguments
+    ///
+    /// * `scalar` - The AST scalar.
+    #[must_use]
+    pub const fn from_ast_scalar(
+        scalar: &ast::r#type::Scalar<'_>
+    ) -> Option<Self> {
+        match scalar {
+            ast::r#type::Scalar::BigInt => Some(Self::BigInt),
+            ast::r#type::Scalar::Boolean => Some(Self::Boolean),
+            ast::r#type::Scalar::Bytes => Some(Self::Bytes),
+            ast::r#type::Scalar::DateTime => Some(Self::DateTime),
+            ast::r#type::Scalar::Decimal => Some(Self::Decimal),
+            ast::r#type::Scalar::Float => Some(Self::Float),
+            ast::r#type::Scalar::Int => Some(Self::Int),
+            ast::r#type::Scalar::Json => Some(Self::Json),
+            ast::r#type::Scalar::String => Some(Self::String),
+            ast::r#type::Scalar::Uuid => Some(Self::Uuid),
+            ast::r#type::Scalar::Reference(_)
+            | ast::r#type::Scalar::Owned(_) => None,
+        }
+    }
 }
@@ -1,6 +1,9 @@
 use {
+    aggregate_error::AggregateError,
+    database_error::DatabaseError,
     enum_error::EnumError,
     model_error::ModelError,
+    mutation_error::MutationError,
     query_error::QueryError,
     std::{
         borrow::Cow,
@@ -13,16 +16,34 @@ use {
     },
 };
 
+/// Aggregate errors.
+pub mod aggregate_error;
+/// Database errors.
+pub mod database_error;
 /// Enum errors.
 pub mod enum_error;
 /// Model errors.
 pub mod model_error;
+/// Mutation errors.
+pub mod mutation_error;
 /// Query errors.
 pub mod query_error;
 
 /// Type checking errors.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum TypeError<'a> {
+    /// An aggregate error.
+    AggregateError {
+        /// The name of the aggregate.
+        aggregate_name: Cow<'a, str>,
+        /// The error.
+        error: AggregateError<'a>,
+    },
+    /// A database error.
+    DatabaseError {
+        /// The error.
+        error: DatabaseError<'a>,
+    },
     /// An enum error.
     EnumError {
         /// The name of the enum.
@@ -37,6 +58,13 @@ pub enum TypeError<'a> {
         /// The error.
         error: ModelError<'a>,
     },
+    /// A mutation error.
+    MutationError {
+        /// The name of the mutation.
+        mutation_name: Cow<'a, str>,
+        /// The error.
+        error: MutationError<'a>,
+    },
     /// A query error.
     QueryError {
         /// The name of the query.
@@ -47,6 +75,159 @@ pub enum TypeError<'a> {
 }
 
 impl<'a> TypeError<'a> {
+    /// Create a duplicate aggregate error.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_name` - The name of the aggregate.
+    #[must_use]
+    pub fn duplicate_aggregate<S>(aggregate_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::AggregateError {
+            aggregate_name: aggregate_name.into(),
+            error: AggregateError::Duplicate,
+        }
+    }
+
+    /// Create an empty aggregate error.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_name` - The name of the aggregate.
+    #[must_use]
+    pub fn empty_aggregate<S>(aggregate_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::AggregateError {
+            aggregate_name: aggregate_name.into(),
+            error: AggregateError::Empty,
+        }
+    }
+
+    /// Create an invalid aggregation error.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_name` - The name of the aggregate.
+    /// * `function` - The name of the function.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn invalid_aggregation<S, T, U>(
+        aggregate_name: S,
+        function: T,
+        field_name: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::AggregateError {
+            aggregate_name: aggregate_name.into(),
+            error: AggregateError::InvalidAggregation {
+                function: function.into(),
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid aggregate group by error.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_name` - The name of the aggregate.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn invalid_aggregate_group_by<S, T>(
+        aggregate_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::AggregateError {
+            aggregate_name: aggregate_name.into(),
+            error: AggregateError::InvalidGroupBy {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an undefined aggregate model error.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggregate_name` - The name of the aggregate.
+    /// * `model_name` - The name of the model.
+    #[must_use]
+    pub fn undefined_aggregate_model<S, T>(
+        aggregate_name: S,
+        model_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::AggregateError {
+            aggregate_name: aggregate_name.into(),
+            error: AggregateError::UndefinedModel {
+                model_name: model_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid database setting error.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - The name of the setting.
+    /// * `value` - The value of the setting.
+    #[must_use]
+    pub fn invalid_database_setting<S, T>(
+        setting: S,
+        value: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::DatabaseError {
+            error: DatabaseError::InvalidSetting {
+                setting: setting.into(),
+                value: value.into(),
+            },
+        }
+    }
+
+    /// Create a missing database provider error.
+    #[must_use]
+    pub const fn missing_database_provider() -> Self {
+        Self::DatabaseError {
+            error: DatabaseError::MissingProvider,
+        }
+    }
+
+    /// Create an unknown database setting error.
+    ///
+    /// # Arguments
+    ///
+    /// * `setting` - The name of the setting.
+    #[must_use]
+    pub fn unknown_database_setting<S>(setting: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::DatabaseError {
+            error: DatabaseError::UnknownSetting {
+                setting: setting.into(),
+            },
+        }
+    }
+
     /// Create a duplicate enum error.
     ///
     /// # Arguments
@@ -102,238 +283,1149 @@ impl<'a> TypeError<'a> {
         }
     }
 
-    /// Create an empty model error.
+    /// Create a duplicate model id error.
     ///
     /// # Arguments
     ///
     /// * `model_name` - The name of the model.
     #[must_use]
-    pub fn empty_model<S>(model_name: S) -> Self
+    pub fn duplicate_model_id<S>(model_name: S) -> Self
     where
         S: Into<Cow<'a, str>>,
     {
         Self::ModelError {
             model_name: model_name.into(),
-            error: ModelError::Empty,
+            error: ModelError::DuplicateId,
         }
     }
 
-    /// Create an unknown model field error.
+    /// Create a duplicate model timestamp error.
     ///
     /// # Arguments
     ///
     /// * `model_name` - The name of the model.
-    /// * `field` - The field.
+    /// * `timestamp` - The kind of timestamp.
     #[must_use]
-    pub fn unknown_model_field_type<S, T, U>(
+    pub fn duplicate_model_timestamp<S, T>(
         model_name: S,
-        field_name: T,
-        field_type: U,
+        timestamp: T,
     ) -> Self
     where
         S: Into<Cow<'a, str>>,
         T: Into<Cow<'a, str>>,
-        U: Into<Cow<'a, str>>,
     {
         Self::ModelError {
             model_name: model_name.into(),
-            error: ModelError::UnknownFieldType {
-                field_name: field_name.into(),
-                field_type: field_type.into(),
+            error: ModelError::DuplicateTimestamp {
+                timestamp: timestamp.into(),
             },
         }
     }
 
-    /// Create a duplicate query error.
+    /// Create an empty model error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
+    /// * `model_name` - The name of the model.
     #[must_use]
-    pub fn duplicate_query<S>(query_name: S) -> Self
+    pub fn empty_model<S>(model_name: S) -> Self
     where
         S: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::Duplicate,
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::Empty,
         }
     }
 
-    /// Create an empty query schema error.
+    /// Create an invalid model attribute error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
+    /// * `attribute` - The name of the attribute.
     #[must_use]
-    pub fn empty_query_schema<S>(query_name: S) -> Self
+    pub fn invalid_model_attribute<S, T, U>(
+        model_name: S,
+        field_name: T,
+        attribute: U,
+    ) -> Self
     where
         S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::EmptySchema,
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidAttribute {
+                field_name: field_name.into(),
+                attribute: attribute.into(),
+            },
         }
     }
 
-    /// Create an invalid query argument type error.
+    /// Create an invalid model default error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
-    /// * `argument` - The argument.
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
+    /// * `value` - The default value.
     #[must_use]
-    pub fn invalid_query_argument_type<S, T, U>(
-        query_name: S,
-        argument_name: T,
-        argument_type: U,
+    pub fn invalid_model_default<S, T, U>(
+        model_name: S,
+        field_name: T,
+        value: U,
     ) -> Self
     where
         S: Into<Cow<'a, str>>,
         T: Into<Cow<'a, str>>,
         U: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::InvalidArgumentType {
-                argument_name: argument_name.into(),
-                argument_type: argument_type.into(),
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidDefault {
+                field_name: field_name.into(),
+                value: value.into(),
             },
         }
     }
 
-    /// Create an invalid query condition error.
+    /// Create an invalid model id field error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
-    /// * `lhs_name` - The name of the left hand side of the condition.
-    /// * `rhs_name` - The name of the right hand side of the condition.
-    /// * `operator` - The operator of the condition.
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
     #[must_use]
-    pub fn invalid_query_condition<S, T, U, V>(
-        query_name: S,
-        lhs_name: T,
-        rhs_name: U,
-        operator: V,
+    pub fn invalid_model_id_field<S, T>(
+        model_name: S,
+        field_name: T,
     ) -> Self
     where
         S: Into<Cow<'a, str>>,
         T: Into<Cow<'a, str>>,
-        U: Into<Cow<'a, str>>,
-        V: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::InvalidCondition {
-                lhs_name: lhs_name.into(),
-                rhs_name: rhs_name.into(),
-                operator: operator.into(),
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidIdField {
+                field_name: field_name.into(),
             },
         }
     }
 
-    /// Create an invalid query where name error.
+    /// Create an invalid model index field error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
-    /// * `schema_name` - The name of the root node of schema.
-    /// * `where_name` - The name of the root node of where clause.
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
     #[must_use]
-    pub fn invalid_query_where_name<S, T, U>(
-        query_name: S,
-        schema_name: T,
-        where_name: U,
+    pub fn invalid_model_index_field<S, T>(
+        model_name: S,
+        field_name: T,
     ) -> Self
     where
         S: Into<Cow<'a, str>>,
         T: Into<Cow<'a, str>>,
-        U: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::InvalidWhereName {
-                schema_name: schema_name.into(),
-                where_name: where_name.into(),
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidIndexField {
+                field_name: field_name.into(),
             },
         }
     }
 
-    /// Create an undefined query argument error.
+    /// Create a duplicate model inverse error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
-    /// * `argument_name` - The name of the argument.
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the relation.
+    /// * `inverse_name` - The name of the inverse field.
     #[must_use]
-    pub fn undefined_query_argument<S, T>(
-        query_name: S,
-        argument_name: T,
+    pub fn duplicate_model_inverse<S, T, U>(
+        model_name: S,
+        field_name: T,
+        inverse_name: U,
     ) -> Self
     where
         S: Into<Cow<'a, str>>,
         T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::UndefinedArgument {
-                argument_name: argument_name.into(),
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::DuplicateInverse {
+                field_name: field_name.into(),
+                inverse_name: inverse_name.into(),
             },
         }
     }
 
-    /// Create an undefined query field name error.
+    /// Create an invalid model inverse error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
-    /// * `field_name` - The name of the field.
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the relation.
+    /// * `inverse_name` - The name of the inverse field.
     #[must_use]
-    pub fn undefined_query_field<S, T>(
-        query_name: S,
+    pub fn invalid_model_inverse<S, T, U>(
+        model_name: S,
         field_name: T,
+        inverse_name: U,
     ) -> Self
     where
         S: Into<Cow<'a, str>>,
         T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::UndefinedField {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidInverse {
                 field_name: field_name.into(),
+                inverse_name: inverse_name.into(),
             },
         }
     }
 
-    /// Create an undefined return type error.
+    /// Create a cyclic model ownership error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
-    /// * `return_type` - The return type.
+    /// * `model_name` - The name of the model.
+    /// * `path` - The owned relations of the cycle, as `Model.field`, followed
+    ///   by the name of the model.
     #[must_use]
-    pub fn undefined_query_return_type<S, T>(
-        query_name: S,
-        model_name: T,
+    pub fn cyclic_model_ownership<S, T, U>(
+        model_name: S,
+        path: T,
     ) -> Self
     where
         S: Into<Cow<'a, str>>,
-        T: Into<Cow<'a, str>>,
+        T: IntoIterator<Item = U>,
+        U: Into<Cow<'a, str>>,
     {
-        Self::QueryError {
-            query_name: query_name.into(),
-            error: QueryError::UndefinedReturnType {
-                model_name: model_name.into(),
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::CyclicOwnership {
+                path: path.into_iter().map(Into::into).collect(),
             },
         }
     }
 
-    /// Create an unused query argument error.
+    /// Create a multiple model owners error.
     ///
     /// # Arguments
     ///
-    /// * `query_name` - The name of the query.
+    /// * `model_name` - The name of the model.
+    /// * `owners` - The owned relations to the model, as `Model.field`.
+    #[must_use]
+    pub fn multiple_model_owners<S, T, U>(
+        model_name: S,
+        owners: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: IntoIterator<Item = U>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::MultipleOwners {
+                owners: owners.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+
+    /// Create an invalid model referential action error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the relation.
+    /// * `action` - The name of the referential action.
+    #[must_use]
+    pub fn invalid_model_referential_action<S, T, U>(
+        model_name: S,
+        field_name: T,
+        action: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidReferentialAction {
+                field_name: field_name.into(),
+                action: action.into(),
+            },
+        }
+    }
+
+    /// Create an unknown model referential action error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the relation.
+    /// * `action` - The name of the referential action.
+    #[must_use]
+    pub fn unknown_model_referential_action<S, T, U>(
+        model_name: S,
+        field_name: T,
+        action: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::UnknownReferentialAction {
+                field_name: field_name.into(),
+                action: action.into(),
+            },
+        }
+    }
+
+    /// Create an invalid model list error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn invalid_model_list<S, T>(
+        model_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidList {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid model native type error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
+    /// * `native_type` - The native type, as written in the source.
+    #[must_use]
+    pub fn invalid_model_native_type<S, T, U>(
+        model_name: S,
+        field_name: T,
+        native_type: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::InvalidNativeType {
+                field_name: field_name.into(),
+                native_type: native_type.into(),
+            },
+        }
+    }
+
+    /// Create an unexpected model native type error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn unexpected_model_native_type<S, T>(
+        model_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::UnexpectedNativeType {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an undefined model id field error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn undefined_model_id_field<S, T>(
+        model_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::UndefinedIdField {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an undefined model index field error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn undefined_model_index_field<S, T>(
+        model_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::UndefinedIndexField {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an unknown model id strategy error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `strategy` - The name of the strategy.
+    #[must_use]
+    pub fn unknown_model_id_strategy<S, T>(
+        model_name: S,
+        strategy: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::UnknownIdStrategy {
+                strategy: strategy.into(),
+            },
+        }
+    }
+
+    /// Create an unknown model timestamp error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `timestamp` - The kind of timestamp.
+    #[must_use]
+    pub fn unknown_model_timestamp<S, T>(
+        model_name: S,
+        timestamp: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::UnknownTimestamp {
+                timestamp: timestamp.into(),
+            },
+        }
+    }
+
+    /// Create an unknown model field error.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_name` - The name of the model.
+    /// * `field` - The field.
+    #[must_use]
+    pub fn unknown_model_field_type<S, T, U>(
+        model_name: S,
+        field_name: T,
+        field_type: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::ModelError {
+            model_name: model_name.into(),
+            error: ModelError::UnknownFieldType {
+                field_name: field_name.into(),
+                field_type: field_type.into(),
+            },
+        }
+    }
+
+    /// Create a duplicate mutation error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    #[must_use]
+    pub fn duplicate_mutation<S>(mutation_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::Duplicate,
+        }
+    }
+
+    /// Create an invalid mutation argument type error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `argument_name` - The name of the argument.
+    /// * `argument_type` - The type of the argument.
+    #[must_use]
+    pub fn invalid_mutation_argument_type<S, T, U>(
+        mutation_name: S,
+        argument_name: T,
+        argument_type: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::InvalidArgumentType {
+                argument_name: argument_name.into(),
+                argument_type: argument_type.into(),
+            },
+        }
+    }
+
+    /// Create an invalid mutation assignment error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `field_name` - The name of the field.
+    /// * `rhs_name` - The name of the argument, or the literal value.
+    #[must_use]
+    pub fn invalid_mutation_assignment<S, T, U>(
+        mutation_name: S,
+        field_name: T,
+        rhs_name: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::InvalidAssignment {
+                field_name: field_name.into(),
+                rhs_name: rhs_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid mutation condition error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `lhs_name` - The name of the field.
+    /// * `rhs_name` - The name of the argument, or the literal value.
+    /// * `operator` - The operator.
+    #[must_use]
+    pub fn invalid_mutation_condition<S, T, U, V>(
+        mutation_name: S,
+        lhs_name: T,
+        rhs_name: U,
+        operator: V,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::InvalidCondition {
+                lhs_name: lhs_name.into(),
+                rhs_name: rhs_name.into(),
+                operator: operator.into(),
+            },
+        }
+    }
+
+    /// Create an invalid mutation condition mode error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `lhs_name` - The name of the field.
+    #[must_use]
+    pub fn invalid_mutation_condition_mode<S, T>(
+        mutation_name: S,
+        lhs_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::InvalidConditionMode {
+                lhs_name: lhs_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid mutation disconnect error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn invalid_mutation_disconnect<S, T>(
+        mutation_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::InvalidDisconnect {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid mutation where clause error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    #[must_use]
+    pub fn invalid_mutation_where<S>(mutation_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::InvalidWhere,
+        }
+    }
+
+    /// Create a missing mutation field error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn missing_mutation_field<S, T>(
+        mutation_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::MissingField {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create a missing mutation where clause error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    #[must_use]
+    pub fn missing_mutation_where<S>(mutation_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::MissingWhere,
+        }
+    }
+
+    /// Create an undefined mutation argument error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `argument_name` - The name of the argument.
+    #[must_use]
+    pub fn undefined_mutation_argument<S, T>(
+        mutation_name: S,
+        argument_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::UndefinedArgument {
+                argument_name: argument_name.into(),
+            },
+        }
+    }
+
+    /// Create an undefined mutation model error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `model_name` - The name of the model.
+    #[must_use]
+    pub fn undefined_mutation_model<S, T>(
+        mutation_name: S,
+        model_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::UndefinedModel {
+                model_name: model_name.into(),
+            },
+        }
+    }
+
+    /// Create an unexpected mutation where clause error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    #[must_use]
+    pub fn unexpected_mutation_where<S>(mutation_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::UnexpectedWhere,
+        }
+    }
+
+    /// Create an unused mutation argument error.
+    ///
+    /// # Arguments
+    ///
+    /// * `mutation_name` - The name of the mutation.
+    /// * `argument_name` - The name of the argument.
+    #[must_use]
+    pub fn unused_mutation_argument<S, T>(
+        mutation_name: S,
+        argument_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::MutationError {
+            mutation_name: mutation_name.into(),
+            error: MutationError::UnusedArgument {
+                argument_name: argument_name.into(),
+            },
+        }
+    }
+
+    /// Create a duplicate query error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    #[must_use]
+    pub fn duplicate_query<S>(query_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::Duplicate,
+        }
+    }
+
+    /// Create an empty query schema error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    #[must_use]
+    pub fn empty_query_schema<S>(query_name: S) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::EmptySchema,
+        }
+    }
+
+    /// Create an invalid query argument type error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `argument` - The argument.
+    #[must_use]
+    pub fn invalid_query_argument_type<S, T, U>(
+        query_name: S,
+        argument_name: T,
+        argument_type: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidArgumentType {
+                argument_name: argument_name.into(),
+                argument_type: argument_type.into(),
+            },
+        }
+    }
+
+    /// Create an invalid query condition error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `lhs_name` - The name of the left hand side of the condition.
+    /// * `rhs_name` - The name of the right hand side of the condition.
+    /// * `operator` - The operator of the condition.
+    #[must_use]
+    pub fn invalid_query_condition<S, T, U, V>(
+        query_name: S,
+        lhs_name: T,
+        rhs_name: U,
+        operator: V,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+        V: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidCondition {
+                lhs_name: lhs_name.into(),
+                rhs_name: rhs_name.into(),
+                operator: operator.into(),
+            },
+        }
+    }
+
+    /// Create an invalid query condition mode error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `lhs_name` - The name of the left hand side of the condition.
+    #[must_use]
+    pub fn invalid_query_condition_mode<S, T>(
+        query_name: S,
+        lhs_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidConditionMode {
+                lhs_name: lhs_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid query cursor error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `field_name` - The name of the cursor field.
+    #[must_use]
+    pub fn invalid_query_cursor<S, T>(
+        query_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidCursor {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an invalid query ordering error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `path` - The path to the field.
+    #[must_use]
+    pub fn invalid_query_ordering<S, T>(
+        query_name: S,
+        path: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidOrdering { path: path.into() },
+        }
+    }
+
+    /// Create an invalid query pagination error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `clause` - The name of the pagination clause.
+    #[must_use]
+    pub fn invalid_query_pagination<S, T>(
+        query_name: S,
+        clause: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidPagination {
+                clause: clause.into(),
+            },
+        }
+    }
+
+    /// Create an invalid query pagination value error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `clause` - The name of the pagination clause.
+    /// * `value` - The value of the clause.
+    #[must_use]
+    pub fn invalid_query_pagination_value<S, T, U>(
+        query_name: S,
+        clause: T,
+        value: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidPaginationValue {
+                clause: clause.into(),
+                value: value.into(),
+            },
+        }
+    }
+
+    /// Create an invalid query where name error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `schema_name` - The name of the root node of schema.
+    /// * `where_name` - The name of the root node of where clause.
+    #[must_use]
+    pub fn invalid_query_where_name<S, T, U>(
+        query_name: S,
+        schema_name: T,
+        where_name: U,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::InvalidWhereName {
+                schema_name: schema_name.into(),
+                where_name: where_name.into(),
+            },
+        }
+    }
+
+    /// Create a missing query selection error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `field_name` - The path to the relation.
+    #[must_use]
+    pub fn missing_query_selection<S, T>(
+        query_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::MissingSelection {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an undefined query argument error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `argument_name` - The name of the argument.
+    #[must_use]
+    pub fn undefined_query_argument<S, T>(
+        query_name: S,
+        argument_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::UndefinedArgument {
+                argument_name: argument_name.into(),
+            },
+        }
+    }
+
+    /// Create an undefined query field name error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `field_name` - The name of the field.
+    #[must_use]
+    pub fn undefined_query_field<S, T>(
+        query_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::UndefinedField {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an undefined return type error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `return_type` - The return type.
+    #[must_use]
+    pub fn undefined_query_return_type<S, T>(
+        query_name: S,
+        model_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::UndefinedReturnType {
+                model_name: model_name.into(),
+            },
+        }
+    }
+
+    /// Create an unexpected query selection error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
+    /// * `field_name` - The path to the field.
+    #[must_use]
+    pub fn unexpected_query_selection<S, T>(
+        query_name: S,
+        field_name: T,
+    ) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+        T: Into<Cow<'a, str>>,
+    {
+        Self::QueryError {
+            query_name: query_name.into(),
+            error: QueryError::UnexpectedSelection {
+                field_name: field_name.into(),
+            },
+        }
+    }
+
+    /// Create an unused query argument error.
+    ///
+    /// # Arguments
+    ///
+    /// * `query_name` - The name of the query.
     /// * `argument_name` - The name of the argument.
     #[must_use]
     pub fn unused_query_argument<S, T>(
@@ -351,40 +1443,262 @@ impl<'a> TypeError<'a> {
             },
         }
     }
-}
+}
+
+impl Display for TypeError<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::AggregateError {
+                aggregate_name,
+                error,
+            } => {
+                write!(f, "Error in aggregate `{aggregate_name}`: {error}.")
+            }
+            Self::DatabaseError { error } => {
+                write!(f, "Error in database: {error}.")
+            }
+            Self::EnumError { enum_name, error } => {
+                write!(f, "Error in enum `{enum_name}`: {error}.")
+            }
+            Self::ModelError { model_name, error } => {
+                write!(f, "Error in model `{model_name}`: {error}.")
+            }
+            Self::MutationError {
+                mutation_name,
+                error,
+            } => {
+                write!(f, "Error in mutation `{mutation_name}`: {error}.")
+            }
+            Self::QueryError { query_name, error } => {
+                write!(f, "Error in query `{query_name}`: {error}.")
+            }
+        }
+    }
+}
+
+impl Error for TypeError<'static> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::AggregateError { error, .. } => Some(error),
+            Self::DatabaseError { error } => Some(error),
+            Self::EnumError { error, .. } => Some(error),
+            Self::ModelError { error, .. } => Some(error),
+            Self::MutationError { error, .. } => Some(error),
+            Self::QueryError { error, .. } => Some(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_aggregate() {
+        assert_eq!(
+            TypeError::duplicate_aggregate("postStats").to_string(),
+            "Error in aggregate `postStats`: aggregate already exists."
+        );
+    }
+
+    #[test]
+    fn test_empty_aggregate() {
+        assert_eq!(
+            TypeError::empty_aggregate("postStats").to_string(),
+            "Error in aggregate `postStats`: aggregate has no count or \
+             functions."
+        );
+    }
+
+    #[test]
+    fn test_invalid_aggregation() {
+        assert_eq!(
+            TypeError::invalid_aggregation("postStats", "avg", "title")
+                .to_string(),
+            "Error in aggregate `postStats`: function `avg` cannot apply to \
+             `title`."
+        );
+    }
+
+    #[test]
+    fn test_invalid_aggregate_group_by() {
+        assert_eq!(
+            TypeError::invalid_aggregate_group_by("postStats", "author")
+                .to_string(),
+            "Error in aggregate `postStats`: records cannot be grouped by \
+             `author`."
+        );
+    }
+
+    #[test]
+    fn test_undefined_aggregate_model() {
+        assert_eq!(
+            TypeError::undefined_aggregate_model("postStats", "Post")
+                .to_string(),
+            "Error in aggregate `postStats`: model `Post` is undefined."
+        );
+    }
+
+    #[test]
+    fn test_duplicate_mutation() {
+        assert_eq!(
+            TypeError::duplicate_mutation("createPost").to_string(),
+            "Error in mutation `createPost`: mutation already exists."
+        );
+    }
+
+    #[test]
+    fn test_invalid_mutation_argument_type() {
+        assert_eq!(
+            TypeError::invalid_mutation_argument_type(
+                "updatePost",
+                "title",
+                "Post"
+            )
+            .to_string(),
+            "Error in mutation `updatePost`: argument `$title` has invalid \
+             type `Post`."
+        );
+    }
 
-impl Display for TypeError<'_> {
-    fn fmt(
-        &self,
-        f: &mut Formatter<'_>,
-    ) -> fmt::Result {
-        match self {
-            Self::EnumError { enum_name, error } => {
-                write!(f, "Error in enum `{enum_name}`: {error}.")
-            }
-            Self::ModelError { model_name, error } => {
-                write!(f, "Error in model `{model_name}`: {error}.")
-            }
-            Self::QueryError { query_name, error } => {
-                write!(f, "Error in query `{query_name}`: {error}.")
-            }
-        }
+    #[test]
+    fn test_invalid_mutation_assignment() {
+        assert_eq!(
+            TypeError::invalid_mutation_assignment(
+                "createPost",
+                "title",
+                "count"
+            )
+            .to_string(),
+            "Error in mutation `createPost`: `count` cannot be assigned to \
+             `title`."
+        );
     }
-}
 
-impl Error for TypeError<'static> {
-    fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match self {
-            Self::EnumError { error, .. } => Some(error),
-            Self::ModelError { error, .. } => Some(error),
-            Self::QueryError { error, .. } => Some(error),
-        }
+    #[test]
+    fn test_invalid_mutation_condition() {
+        assert_eq!(
+            TypeError::invalid_mutation_condition(
+                "updatePost",
+                "id",
+                "title",
+                "equals"
+            )
+            .to_string(),
+            "Error in mutation `updatePost`: condition `id equals title` is \
+             invalid."
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_invalid_mutation_condition_mode() {
+        assert_eq!(
+            TypeError::invalid_mutation_condition_mode("updatePost", "id")
+                .to_string(),
+            "Error in mutation `updatePost`: mode `insensitive` is not \
+             allowed on non-string field `id`."
+        );
+    }
+
+    #[test]
+    fn test_invalid_mutation_disconnect() {
+        assert_eq!(
+            TypeError::invalid_mutation_disconnect("updatePost", "author")
+                .to_string(),
+            "Error in mutation `updatePost`: `author` cannot be disconnected."
+        );
+    }
+
+    #[test]
+    fn test_invalid_mutation_where() {
+        assert_eq!(
+            TypeError::invalid_mutation_where("updatePost").to_string(),
+            "Error in mutation `updatePost`: where clause does not select a \
+             record by its id or a unique field."
+        );
+    }
+
+    #[test]
+    fn test_missing_mutation_field() {
+        assert_eq!(
+            TypeError::missing_mutation_field("createPost", "title")
+                .to_string(),
+            "Error in mutation `createPost`: required field `title` is not \
+             assigned."
+        );
+    }
+
+    #[test]
+    fn test_missing_mutation_where() {
+        assert_eq!(
+            TypeError::missing_mutation_where("deletePost").to_string(),
+            "Error in mutation `deletePost`: where clause is missing."
+        );
+    }
+
+    #[test]
+    fn test_undefined_mutation_argument() {
+        assert_eq!(
+            TypeError::undefined_mutation_argument("updatePost", "id")
+                .to_string(),
+            "Error in mutation `updatePost`: argument `$id` is undefined."
+        );
+    }
+
+    #[test]
+    fn test_undefined_mutation_model() {
+        assert_eq!(
+            TypeError::undefined_mutation_model("createPost", "Post")
+                .to_string(),
+            "Error in mutation `createPost`: model `Post` is undefined."
+        );
+    }
+
+    #[test]
+    fn test_unexpected_mutation_where() {
+        assert_eq!(
+            TypeError::unexpected_mutation_where("createPost").to_string(),
+            "Error in mutation `createPost`: create cannot have a where \
+             clause."
+        );
+    }
+
+    #[test]
+    fn test_unused_mutation_argument() {
+        assert_eq!(
+            TypeError::unused_mutation_argument("updatePost", "id").to_string(),
+            "Error in mutation `updatePost`: argument `$id` is unused."
+        );
+    }
+
+    #[test]
+    fn test_invalid_database_setting() {
+        assert_eq!(
+            TypeError::invalid_database_setting("relationMode", "emulated")
+                .to_string(),
+            "Error in database: setting `relationMode` has invalid value \
+             `emulated`."
+        );
+    }
+
+    #[test]
+    fn test_missing_database_provider() {
+        assert_eq!(
+            TypeError::missing_database_provider().to_string(),
+            "Error in database: provider is missing."
+        );
+    }
+
+    #[test]
+    fn test_unknown_database_setting() {
+        assert_eq!(
+            TypeError::unknown_database_setting("host").to_string(),
+            "Error in database: setting `host` is unknown."
+        );
+    }
 
     #[test]
     fn test_duplicate_enum() {
@@ -418,6 +1732,191 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_model_attribute() {
+        assert_eq!(
+            TypeError::invalid_model_attribute("foo", "bar", "unique")
+                .to_string(),
+            "Error in model `foo`: attribute `@unique` is not allowed on \
+             field `bar`."
+        );
+    }
+
+    #[test]
+    fn test_invalid_model_default() {
+        assert_eq!(
+            TypeError::invalid_model_default("foo", "bar", "\"baz\"")
+                .to_string(),
+            "Error in model `foo`: field `bar` has invalid default value \
+             `\"baz\"`."
+        );
+    }
+
+    #[test]
+    fn test_duplicate_model_inverse() {
+        assert_eq!(
+            TypeError::duplicate_model_inverse("Image", "country", "image")
+                .to_string(),
+            "Error in model `Image`: inverse `image` of relation `country` \
+             already exists."
+        );
+    }
+
+    #[test]
+    fn test_invalid_model_inverse() {
+        assert_eq!(
+            TypeError::invalid_model_inverse("Image", "country", "flags")
+                .to_string(),
+            "Error in model `Image`: inverse `flags` of relation `country` \
+             does not match it."
+        );
+    }
+
+    #[test]
+    fn test_cyclic_model_ownership() {
+        assert_eq!(
+            TypeError::cyclic_model_ownership(
+                "Country",
+                ["Country.capital", "City.country", "Country"]
+            )
+            .to_string(),
+            "Error in model `Country`: model owns itself through \
+             `Country.capital -> City.country -> Country`."
+        );
+    }
+
+    #[test]
+    fn test_multiple_model_owners() {
+        assert_eq!(
+            TypeError::multiple_model_owners(
+                "Image",
+                ["Country.flag", "Region.flag"]
+            )
+            .to_string(),
+            "Error in model `Image`: model is owned by more than one \
+             relation: `Country.flag`, `Region.flag`."
+        );
+    }
+
+    #[test]
+    fn test_invalid_model_referential_action() {
+        assert_eq!(
+            TypeError::invalid_model_referential_action(
+                "Province", "country", "SetNull"
+            )
+            .to_string(),
+            "Error in model `Province`: referential action `SetNull` is not \
+             allowed on relation `country`."
+        );
+    }
+
+    #[test]
+    fn test_unknown_model_referential_action() {
+        assert_eq!(
+            TypeError::unknown_model_referential_action(
+                "Province", "country", "Ignore"
+            )
+            .to_string(),
+            "Error in model `Province`: referential action `Ignore` of \
+             relation `country` is unknown."
+        );
+    }
+
+    #[test]
+    fn test_invalid_model_list() {
+        assert_eq!(
+            TypeError::invalid_model_list("foo", "bar").to_string(),
+            "Error in model `foo`: field `bar` is a list, which the database \
+             does not support."
+        );
+    }
+
+    #[test]
+    fn test_invalid_model_native_type() {
+        assert_eq!(
+            TypeError::invalid_model_native_type("foo", "bar", "@db.Text")
+                .to_string(),
+            "Error in model `foo`: field `bar` has unsupported native type \
+             `@db.Text`."
+        );
+    }
+
+    #[test]
+    fn test_unexpected_model_native_type() {
+        assert_eq!(
+            TypeError::unexpected_model_native_type("foo", "bar").to_string(),
+            "Error in model `foo`: field `bar` has a native type, but no \
+             database is declared."
+        );
+    }
+
+    #[test]
+    fn test_invalid_model_index_field() {
+        assert_eq!(
+            TypeError::invalid_model_index_field("foo", "bar").to_string(),
+            "Error in model `foo`: index field `bar` is a relation."
+        );
+    }
+
+    #[test]
+    fn test_undefined_model_index_field() {
+        assert_eq!(
+            TypeError::undefined_model_index_field("foo", "bar").to_string(),
+            "Error in model `foo`: index field `bar` is undefined."
+        );
+    }
+
+    #[test]
+    fn test_duplicate_model_id() {
+        assert_eq!(
+            TypeError::duplicate_model_id("foo").to_string(),
+            "Error in model `foo`: model has more than one id."
+        );
+    }
+
+    #[test]
+    fn test_invalid_model_id_field() {
+        assert_eq!(
+            TypeError::invalid_model_id_field("foo", "bar").to_string(),
+            "Error in model `foo`: id field `bar` is not a required data \
+             field."
+        );
+    }
+
+    #[test]
+    fn test_undefined_model_id_field() {
+        assert_eq!(
+            TypeError::undefined_model_id_field("foo", "bar").to_string(),
+            "Error in model `foo`: id field `bar` is undefined."
+        );
+    }
+
+    #[test]
+    fn test_unknown_model_id_strategy() {
+        assert_eq!(
+            TypeError::unknown_model_id_strategy("foo", "serial").to_string(),
+            "Error in model `foo`: id strategy `serial` is unknown."
+        );
+    }
+
+    #[test]
+    fn test_duplicate_model_timestamp() {
+        assert_eq!(
+            TypeError::duplicate_model_timestamp("foo", "createdAt")
+                .to_string(),
+            "Error in model `foo`: timestamp `createdAt` is configured more \
+             than once."
+        );
+    }
+
+    #[test]
+    fn test_unknown_model_timestamp() {
+        assert_eq!(
+            TypeError::unknown_model_timestamp("foo", "archivedAt").to_string(),
+            "Error in model `foo`: timestamp `archivedAt` is unknown."
+        );
+    }
+
     #[test]
     fn test_unknown_model_field_type() {
         assert_eq!(
@@ -461,6 +1960,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_invalid_query_condition_mode() {
+        assert_eq!(
+            TypeError::invalid_query_condition_mode("foo", "bar").to_string(),
+            "Error in query `foo`: mode `insensitive` is not allowed on \
+             non-string field `bar`."
+        );
+    }
+
     #[test]
     fn test_invalid_query_where() {
         assert_eq!(
@@ -471,6 +1979,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_missing_query_selection() {
+        assert_eq!(
+            TypeError::missing_query_selection("foo", "bar").to_string(),
+            "Error in query `foo`: relation `bar` must select at least one \
+             field."
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_cursor() {
+        assert_eq!(
+            TypeError::invalid_query_cursor("foo", "bar").to_string(),
+            "Error in query `foo`: cursor field `bar` is not an id or unique \
+             field."
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_ordering() {
+        assert_eq!(
+            TypeError::invalid_query_ordering("foo", "bar").to_string(),
+            "Error in query `foo`: cannot order by `bar`, which is not a \
+             scalar field."
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_pagination() {
+        assert_eq!(
+            TypeError::invalid_query_pagination("foo", "take").to_string(),
+            "Error in query `foo`: `take` is only allowed on queries that \
+             return an array."
+        );
+    }
+
+    #[test]
+    fn test_invalid_query_pagination_value() {
+        assert_eq!(
+            TypeError::invalid_query_pagination_value("foo", "skip", "$bar")
+                .to_string(),
+            "Error in query `foo`: `skip` has invalid value `$bar`."
+        );
+    }
+
+    #[test]
+    fn test_unexpected_query_selection() {
+        assert_eq!(
+            TypeError::unexpected_query_selection("foo", "bar").to_string(),
+            "Error in query `foo`: field `bar` is not a relation."
+        );
+    }
+
     #[test]
     fn test_undefined_query_argument() {
         assert_eq!(
@@ -565,4 +2126,26 @@ mod tests {
             "query already exists"
         );
     }
+
+    #[test]
+    fn test_source_duplicate_aggregate() {
+        assert_eq!(
+            TypeError::duplicate_aggregate("foo")
+                .source()
+                .unwrap()
+                .to_string(),
+            "aggregate already exists"
+        );
+    }
+
+    #[test]
+    fn test_source_duplicate_mutation() {
+        assert_eq!(
+            TypeError::duplicate_mutation("foo")
+                .source()
+                .unwrap()
+                .to_string(),
+            "mutation already exists"
+        );
+    }
 }
@@ -0,0 +1,134 @@
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// Errors that can occur when type checking an aggregate.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AggregateError<'a> {
+    /// The name of an aggregate must be unique within the application. This
+    /// aggregate has the same name as another aggregate.
+    Duplicate,
+    /// An aggregate must count records or apply at least one function to a
+    /// field. This aggregate does neither, so it would return no data.
+    Empty,
+    /// `sum` and `avg` apply to `Int` and `Float` fields, and `min` and `max`
+    /// also apply to `DateTime` fields. This aggregate applies a function to
+    /// a field of another type, a list, or an undefined field.
+    InvalidAggregation {
+        /// The name of the function.
+        function: Cow<'a, str>,
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// Records can only be grouped by scalar fields and enum fields. This
+    /// aggregate groups by a relation, a list, or an undefined field.
+    InvalidGroupBy {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// An aggregate must aggregate an existing model. The model of this
+    /// aggregate is undefined.
+    UndefinedModel {
+        /// The name of the model.
+        model_name: Cow<'a, str>,
+    },
+}
+
+impl Display for AggregateError<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Duplicate => write!(f, "aggregate already exists"),
+            Self::Empty => write!(f, "aggregate has no count or functions"),
+            Self::InvalidAggregation {
+                function,
+                field_name,
+            } => {
+                write!(
+                    f,
+                    "function `{function}` cannot apply to `{field_name}`"
+                )
+            }
+            Self::InvalidGroupBy { field_name } => {
+                write!(f, "records cannot be grouped by `{field_name}`")
+            }
+            Self::UndefinedModel { model_name } => {
+                write!(f, "model `{model_name}` is undefined")
+            }
+        }
+    }
+}
+
+impl Error for AggregateError<'_> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_duplicate() {
+        assert_eq!(
+            AggregateError::Duplicate.to_string(),
+            "aggregate already exists"
+        );
+    }
+
+    #[test]
+    fn test_display_empty() {
+        assert_eq!(
+            AggregateError::Empty.to_string(),
+            "aggregate has no count or functions"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_aggregation() {
+        assert_eq!(
+            AggregateError::InvalidAggregation {
+                function: "sum".into(),
+                field_name: "title".into(),
+            }
+            .to_string(),
+            "function `sum` cannot apply to `title`"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_group_by() {
+        assert_eq!(
+            AggregateError::InvalidGroupBy {
+                field_name: "author".into(),
+            }
+            .to_string(),
+            "records cannot be grouped by `author`"
+        );
+    }
+
+    #[test]
+    fn test_display_undefined_model() {
+        assert_eq!(
+            AggregateError::UndefinedModel {
+                model_name: "Post".into(),
+            }
+            .to_string(),
+            "model `Post` is undefined"
+        );
+    }
+
+    #[test]
+    fn test_source() {
+        assert!(AggregateError::Empty.source().is_none());
+    }
+}
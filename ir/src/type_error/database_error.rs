@@ -0,0 +1,95 @@
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// Errors that can occur when type checking the database declaration.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DatabaseError<'a> {
+    /// Each setting accepts a specific kind of value. This setting has a
+    /// value of another kind, or a value that is not one of the allowed ones.
+    InvalidSetting {
+        /// The name of the setting.
+        setting: Cow<'a, str>,
+        /// The value of the setting, as written in the source.
+        value: Cow<'a, str>,
+    },
+    /// A database must specify its provider, but this one does not.
+    MissingProvider,
+    /// A setting must be one of `provider`, `url`, `shadowDatabaseUrl`,
+    /// `directUrl`, `relationMode` or `previewFeatures`. This declaration
+    /// contains another setting.
+    UnknownSetting {
+        /// The name of the setting.
+        setting: Cow<'a, str>,
+    },
+}
+
+impl Display for DatabaseError<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::InvalidSetting { setting, value } => {
+                write!(f, "setting `{setting}` has invalid value `{value}`")
+            }
+            Self::MissingProvider => write!(f, "provider is missing"),
+            Self::UnknownSetting { setting } => {
+                write!(f, "setting `{setting}` is unknown")
+            }
+        }
+    }
+}
+
+impl Error for DatabaseError<'_> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_invalid_setting() {
+        assert_eq!(
+            DatabaseError::InvalidSetting {
+                setting: "provider".into(),
+                value: "oracle".into(),
+            }
+            .to_string(),
+            "setting `provider` has invalid value `oracle`"
+        );
+    }
+
+    #[test]
+    fn test_display_missing_provider() {
+        assert_eq!(
+            DatabaseError::MissingProvider.to_string(),
+            "provider is missing"
+        );
+    }
+
+    #[test]
+    fn test_display_unknown_setting() {
+        assert_eq!(
+            DatabaseError::UnknownSetting {
+                setting: "host".into(),
+            }
+            .to_string(),
+            "setting `host` is unknown"
+        );
+    }
+
+    #[test]
+    fn test_source() {
+        assert!(DatabaseError::MissingProvider.source().is_none());
+    }
+}
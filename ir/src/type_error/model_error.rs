@@ -11,6 +11,13 @@ use std::{
 /// Errors that can occur when type checking a model.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum ModelError<'a> {
+    /// A model can not own itself, directly or through other models. This
+    /// model owns itself through a cycle of owned relations.
+    CyclicOwnership {
+        /// The owned relations of the cycle, as `Model.field`, followed by
+        /// the name of this model.
+        path: Vec<Cow<'a, str>>,
+    },
     /// The name of a model must be unique within the application. This model
     /// has the same name as another model.
     Duplicate,
@@ -20,8 +27,133 @@ pub enum ModelError<'a> {
         /// The name of the field.
         field_name: Cow<'a, str>,
     },
+    /// A model can be identified in only one way. This model declares more
+    /// than one id.
+    DuplicateId,
+    /// The inverse of a relation is generated in the related model, so its
+    /// name must be unique within that model. This model contains a relation
+    /// whose generated inverse has the same name as another one.
+    DuplicateInverse {
+        /// The name of the relation.
+        field_name: Cow<'a, str>,
+        /// The name of the inverse field.
+        inverse_name: Cow<'a, str>,
+    },
+    /// A kind of timestamp can be configured only once. This model configures
+    /// a timestamp more than once.
+    DuplicateTimestamp {
+        /// The kind of timestamp.
+        timestamp: Cow<'a, str>,
+    },
     /// A model should contain at least one field, but this model is empty.
     Empty,
+    /// An attribute can only be applied to a data field or enum relation
+    /// that is not a list. This model applies it to another kind of field.
+    InvalidAttribute {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+        /// The name of the attribute.
+        attribute: Cow<'a, str>,
+    },
+    /// The default value of a field must match its type. This model contains
+    /// a field with a default value of another type.
+    InvalidDefault {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+        /// The default value, as written in the source.
+        value: Cow<'a, str>,
+    },
+    /// Lists of scalars and enum values are only supported by `PostgreSQL`,
+    /// `CockroachDB` and `MongoDB`. This model contains such a list and the
+    /// database has another provider.
+    InvalidList {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// A native type must be supported by the database provider for the type
+    /// of the field. This model contains a field with another native type.
+    InvalidNativeType {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+        /// The native type, as written in the source.
+        native_type: Cow<'a, str>,
+    },
+    /// The inverse of a relation must either be undeclared or be a relation
+    /// back to it, and the pair must describe one relation: exactly one side
+    /// references a single record, or both sides are lists. This model
+    /// contains a relation with another kind of inverse.
+    InvalidInverse {
+        /// The name of the relation.
+        field_name: Cow<'a, str>,
+        /// The name of the inverse field.
+        inverse_name: Cow<'a, str>,
+    },
+    /// A referential action must be supported by the database, and `SetNull`
+    /// requires an optional many-to-one relation. This model contains a
+    /// relation with another referential action.
+    InvalidReferentialAction {
+        /// The name of the relation.
+        field_name: Cow<'a, str>,
+        /// The name of the referential action.
+        action: Cow<'a, str>,
+    },
+    /// An id can only consist of required data fields. This model contains
+    /// an id with another kind of field.
+    InvalidIdField {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// An index can not contain a relation to another model. This model
+    /// contains an index on a relation.
+    InvalidIndexField {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// A model can be owned through at most one relation. This model is owned
+    /// through more than one.
+    MultipleOwners {
+        /// The owned relations to this model, as `Model.field`.
+        owners: Vec<Cow<'a, str>>,
+    },
+    /// A native type is specific to a database provider. This model contains
+    /// a field with a native type, but no database is declared.
+    UnexpectedNativeType {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// The fields of an id must be defined within the model. This model
+    /// contains an id with an undefined field.
+    UndefinedIdField {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// The fields of an index must be defined within the model. This model
+    /// contains an index on an undefined field.
+    UndefinedIndexField {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// A generated id must use a known strategy. This model uses an unknown
+    /// strategy.
+    UnknownIdStrategy {
+        /// The name of the strategy.
+        strategy: Cow<'a, str>,
+    },
+    /// A referential action must be one of `Cascade`, `NoAction`, `Restrict`,
+    /// `SetDefault` or `SetNull`. This model contains a relation with another
+    /// referential action.
+    UnknownReferentialAction {
+        /// The name of the relation.
+        field_name: Cow<'a, str>,
+        /// The name of the referential action.
+        action: Cow<'a, str>,
+    },
+    /// A timestamp must be one of `createdAt`, `updatedAt` or `deletedAt`.
+    /// This model configures another kind of timestamp.
+    UnknownTimestamp {
+        /// The kind of timestamp.
+        timestamp: Cow<'a, str>,
+    },
     /// The type of a field must be defined within the application.
     /// This model contains a field with an undefined type.
     UnknownFieldType {
@@ -33,16 +165,133 @@ pub enum ModelError<'a> {
 }
 
 impl Display for ModelError<'_> {
+    #[allow(clippy::too_many_lines)]
     fn fmt(
         &self,
         f: &mut Formatter<'_>,
     ) -> fmt::Result {
         match self {
+            Self::CyclicOwnership { path } => {
+                write!(f, "model owns itself through `{}`", path.join(" -> "))
+            }
             Self::Duplicate => write!(f, "model already exists"),
             Self::DuplicateField { field_name } => {
                 write!(f, "field `{field_name}` already exists")
             }
+            Self::DuplicateId => write!(f, "model has more than one id"),
+            Self::DuplicateInverse {
+                field_name,
+                inverse_name,
+            } => {
+                write!(
+                    f,
+                    "inverse `{inverse_name}` of relation `{field_name}` \
+                     already exists"
+                )
+            }
+            Self::DuplicateTimestamp { timestamp } => {
+                write!(
+                    f,
+                    "timestamp `{timestamp}` is configured more than once"
+                )
+            }
             Self::Empty => write!(f, "model has no fields"),
+            Self::InvalidAttribute {
+                field_name,
+                attribute,
+            } => {
+                write!(
+                    f,
+                    "attribute `@{attribute}` is not allowed on field \
+                     `{field_name}`"
+                )
+            }
+            Self::InvalidDefault { field_name, value } => {
+                write!(
+                    f,
+                    "field `{field_name}` has invalid default value `{value}`"
+                )
+            }
+            Self::InvalidList { field_name } => {
+                write!(
+                    f,
+                    "field `{field_name}` is a list, which the database does \
+                     not support"
+                )
+            }
+            Self::InvalidNativeType {
+                field_name,
+                native_type,
+            } => {
+                write!(
+                    f,
+                    "field `{field_name}` has unsupported native type \
+                     `{native_type}`"
+                )
+            }
+            Self::InvalidInverse {
+                field_name,
+                inverse_name,
+            } => {
+                write!(
+                    f,
+                    "inverse `{inverse_name}` of relation `{field_name}` does \
+                     not match it"
+                )
+            }
+            Self::InvalidReferentialAction { field_name, action } => {
+                write!(
+                    f,
+                    "referential action `{action}` is not allowed on relation \
+                     `{field_name}`"
+                )
+            }
+            Self::InvalidIdField { field_name } => {
+                write!(
+                    f,
+                    "id field `{field_name}` is not a required data field"
+                )
+            }
+            Self::InvalidIndexField { field_name } => {
+                write!(f, "index field `{field_name}` is a relation")
+            }
+            Self::MultipleOwners { owners } => {
+                write!(
+                    f,
+                    "model is owned by more than one relation: {}",
+                    owners
+                        .iter()
+                        .map(|owner| format!("`{owner}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Self::UnexpectedNativeType { field_name } => {
+                write!(
+                    f,
+                    "field `{field_name}` has a native type, but no database \
+                     is declared"
+                )
+            }
+            Self::UndefinedIdField { field_name } => {
+                write!(f, "id field `{field_name}` is undefined")
+            }
+            Self::UndefinedIndexField { field_name } => {
+                write!(f, "index field `{field_name}` is undefined")
+            }
+            Self::UnknownIdStrategy { strategy } => {
+                write!(f, "id strategy `{strategy}` is unknown")
+            }
+            Self::UnknownReferentialAction { field_name, action } => {
+                write!(
+                    f,
+                    "referential action `{action}` of relation `{field_name}` \
+                     is unknown"
+                )
+            }
+            Self::UnknownTimestamp { timestamp } => {
+                write!(f, "timestamp `{timestamp}` is unknown")
+            }
             Self::UnknownFieldType {
                 field_name,
                 field_type,
@@ -66,6 +315,24 @@ impl Error for ModelError<'_> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_source_cyclic_ownership() {
+        assert!(ModelError::CyclicOwnership {
+            path: vec!["Foo.bar".into(), "Foo".into()],
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_multiple_owners() {
+        assert!(ModelError::MultipleOwners {
+            owners: vec!["Foo.bar".into(), "Baz.bar".into()],
+        }
+        .source()
+        .is_none());
+    }
+
     #[test]
     fn test_source_duplicate() {
         assert!(ModelError::Duplicate.source().is_none());
@@ -80,11 +347,167 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn test_source_duplicate_id() {
+        assert!(ModelError::DuplicateId.source().is_none());
+    }
+
+    #[test]
+    fn test_source_duplicate_inverse() {
+        assert!(ModelError::DuplicateInverse {
+            field_name: "foo".into(),
+            inverse_name: "bar".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_duplicate_timestamp() {
+        assert!(ModelError::DuplicateTimestamp {
+            timestamp: "createdAt".into(),
+        }
+        .source()
+        .is_none());
+    }
+
     #[test]
     fn test_source_empty() {
         assert!(ModelError::Empty.source().is_none());
     }
 
+    #[test]
+    fn test_source_invalid_attribute() {
+        assert!(ModelError::InvalidAttribute {
+            field_name: "foo".into(),
+            attribute: "unique".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_default() {
+        assert!(ModelError::InvalidDefault {
+            field_name: "foo".into(),
+            value: "true".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_inverse() {
+        assert!(ModelError::InvalidInverse {
+            field_name: "foo".into(),
+            inverse_name: "bar".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_referential_action() {
+        assert!(ModelError::InvalidReferentialAction {
+            field_name: "foo".into(),
+            action: "SetNull".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_id_field() {
+        assert!(ModelError::InvalidIdField {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_index_field() {
+        assert!(ModelError::InvalidIndexField {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_list() {
+        assert!(ModelError::InvalidList {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_native_type() {
+        assert!(ModelError::InvalidNativeType {
+            field_name: "foo".into(),
+            native_type: "@db.Text".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_unexpected_native_type() {
+        assert!(ModelError::UnexpectedNativeType {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_undefined_id_field() {
+        assert!(ModelError::UndefinedIdField {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_undefined_index_field() {
+        assert!(ModelError::UndefinedIndexField {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_unknown_id_strategy() {
+        assert!(ModelError::UnknownIdStrategy {
+            strategy: "serial".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_unknown_referential_action() {
+        assert!(ModelError::UnknownReferentialAction {
+            field_name: "foo".into(),
+            action: "Ignore".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_unknown_timestamp() {
+        assert!(ModelError::UnknownTimestamp {
+            timestamp: "archivedAt".into(),
+        }
+        .source()
+        .is_none());
+    }
+
     #[test]
     fn test_source_unknown_field_type() {
         assert!(ModelError::UnknownFieldType {
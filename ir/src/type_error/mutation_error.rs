@@ -0,0 +1,316 @@
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+};
+
+/// Errors that can occur when type checking a mutation.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MutationError<'a> {
+    /// The name of a mutation must be unique within the application. This
+    /// mutation has the same name as another mutation.
+    Duplicate,
+    /// The type of an argument must be a primitive type or an enum. This
+    /// mutation has an argument of another type.
+    InvalidArgumentType {
+        /// The name of the argument.
+        argument_name: Cow<'a, str>,
+        /// The type of the argument.
+        argument_type: Cow<'a, str>,
+    },
+    /// A field can only be set to a value of its own type, and a relation can
+    /// only be connected to the id of a record of the related model. This
+    /// mutation assigns a value of another type, or assigns to an undefined
+    /// field.
+    InvalidAssignment {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+        /// The name of the argument, or the literal value.
+        rhs_name: Cow<'a, str>,
+    },
+    /// Operands must be compatible with their condition. This mutation has a
+    /// where clause condition whose field and argument or literal are not
+    /// compatible with the operator.
+    InvalidCondition {
+        /// The name of the field.
+        lhs_name: Cow<'a, str>,
+        /// The name of the argument, or the literal value.
+        rhs_name: Cow<'a, str>,
+        /// The operator.
+        operator: Cow<'a, str>,
+    },
+    /// Only strings can be compared case-insensitively. This mutation
+    /// compares a field of another type case-insensitively.
+    InvalidConditionMode {
+        /// The name of the field.
+        lhs_name: Cow<'a, str>,
+    },
+    /// Only optional relations to a single record can be disconnected. This
+    /// mutation disconnects a required relation, a relation to many records,
+    /// or a field that is not a relation.
+    InvalidDisconnect {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// An update, delete or upsert changes a single record, so its where
+    /// clause must compare every field of the id or of a unique key to a
+    /// value with `equals`. The where clause of this mutation may select more
+    /// than one record.
+    InvalidWhere,
+    /// Creating a record requires a value for every field that is not
+    /// optional, a list, or defaulted, and for every required relation. This
+    /// mutation does not assign such a field.
+    MissingField {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// An update, delete or upsert must select the record it changes with a
+    /// where clause. This mutation has no where clause.
+    MissingWhere,
+    /// Conditions and assignments can only refer to arguments of the
+    /// mutation. This mutation refers to an undefined argument.
+    UndefinedArgument {
+        /// The name of the argument.
+        argument_name: Cow<'a, str>,
+    },
+    /// An existing model must be mutated. The model of this mutation is
+    /// undefined.
+    UndefinedModel {
+        /// The name of the model.
+        model_name: Cow<'a, str>,
+    },
+    /// A create does not select an existing record, so it cannot have a
+    /// where clause. This mutation creates a record and has a where clause.
+    UnexpectedWhere,
+    /// Every argument of a mutation must be used in its where clause or in
+    /// an assignment. This mutation has an unused argument.
+    UnusedArgument {
+        /// The name of the argument.
+        argument_name: Cow<'a, str>,
+    },
+}
+
+impl Display for MutationError<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Duplicate => write!(f, "mutation already exists"),
+            Self::InvalidArgumentType {
+                argument_name,
+                argument_type,
+            } => {
+                write!(
+                    f,
+                    "argument `${argument_name}` has invalid type \
+                     `{argument_type}`"
+                )
+            }
+            Self::InvalidAssignment {
+                field_name,
+                rhs_name,
+            } => {
+                write!(f, "`{rhs_name}` cannot be assigned to `{field_name}`")
+            }
+            Self::InvalidCondition {
+                lhs_name,
+                rhs_name,
+                operator,
+            } => {
+                write!(
+                    f,
+                    "condition `{lhs_name} {operator} {rhs_name}` is invalid"
+                )
+            }
+            Self::InvalidConditionMode { lhs_name } => {
+                write!(
+                    f,
+                    "mode `insensitive` is not allowed on non-string field \
+                     `{lhs_name}`"
+                )
+            }
+            Self::InvalidDisconnect { field_name } => {
+                write!(f, "`{field_name}` cannot be disconnected")
+            }
+            Self::InvalidWhere => {
+                write!(
+                    f,
+                    "where clause does not select a record by its id or a \
+                     unique field"
+                )
+            }
+            Self::MissingField { field_name } => {
+                write!(f, "required field `{field_name}` is not assigned")
+            }
+            Self::MissingWhere => write!(f, "where clause is missing"),
+            Self::UndefinedArgument { argument_name } => {
+                write!(f, "argument `${argument_name}` is undefined")
+            }
+            Self::UndefinedModel { model_name } => {
+                write!(f, "model `{model_name}` is undefined")
+            }
+            Self::UnexpectedWhere => {
+                write!(f, "create cannot have a where clause")
+            }
+            Self::UnusedArgument { argument_name } => {
+                write!(f, "argument `${argument_name}` is unused")
+            }
+        }
+    }
+}
+
+impl Error for MutationError<'_> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_duplicate() {
+        assert_eq!(
+            MutationError::Duplicate.to_string(),
+            "mutation already exists"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_argument_type() {
+        assert_eq!(
+            MutationError::InvalidArgumentType {
+                argument_name: "title".into(),
+                argument_type: "Post".into(),
+            }
+            .to_string(),
+            "argument `$title` has invalid type `Post`"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_assignment() {
+        assert_eq!(
+            MutationError::InvalidAssignment {
+                field_name: "title".into(),
+                rhs_name: "count".into(),
+            }
+            .to_string(),
+            "`count` cannot be assigned to `title`"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_condition() {
+        assert_eq!(
+            MutationError::InvalidCondition {
+                lhs_name: "id".into(),
+                rhs_name: "title".into(),
+                operator: "equals".into(),
+            }
+            .to_string(),
+            "condition `id equals title` is invalid"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_condition_mode() {
+        assert_eq!(
+            MutationError::InvalidConditionMode {
+                lhs_name: "id".into(),
+            }
+            .to_string(),
+            "mode `insensitive` is not allowed on non-string field `id`"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_disconnect() {
+        assert_eq!(
+            MutationError::InvalidDisconnect {
+                field_name: "author".into(),
+            }
+            .to_string(),
+            "`author` cannot be disconnected"
+        );
+    }
+
+    #[test]
+    fn test_display_invalid_where() {
+        assert_eq!(
+            MutationError::InvalidWhere.to_string(),
+            "where clause does not select a record by its id or a unique field"
+        );
+    }
+
+    #[test]
+    fn test_display_missing_field() {
+        assert_eq!(
+            MutationError::MissingField {
+                field_name: "title".into(),
+            }
+            .to_string(),
+            "required field `title` is not assigned"
+        );
+    }
+
+    #[test]
+    fn test_display_missing_where() {
+        assert_eq!(
+            MutationError::MissingWhere.to_string(),
+            "where clause is missing"
+        );
+    }
+
+    #[test]
+    fn test_display_undefined_argument() {
+        assert_eq!(
+            MutationError::UndefinedArgument {
+                argument_name: "id".into(),
+            }
+            .to_string(),
+            "argument `$id` is undefined"
+        );
+    }
+
+    #[test]
+    fn test_display_undefined_model() {
+        assert_eq!(
+            MutationError::UndefinedModel {
+                model_name: "Post".into(),
+            }
+            .to_string(),
+            "model `Post` is undefined"
+        );
+    }
+
+    #[test]
+    fn test_display_unexpected_where() {
+        assert_eq!(
+            MutationError::UnexpectedWhere.to_string(),
+            "create cannot have a where clause"
+        );
+    }
+
+    #[test]
+    fn test_display_unused_argument() {
+        assert_eq!(
+            MutationError::UnusedArgument {
+                argument_name: "id".into(),
+            }
+            .to_string(),
+            "argument `$id` is unused"
+        );
+    }
+
+    #[test]
+    fn test_source() {
+        assert!(MutationError::Duplicate.source().is_none());
+    }
+}
@@ -34,11 +34,45 @@ pub enum QueryError<'a> {
     InvalidCondition {
         /// The name of the field.
         lhs_name: Cow<'a, str>,
-        /// The name of the argument.
+        /// The name of the argument, or the literal value.
         rhs_name: Cow<'a, str>,
         /// The operator.
         operator: Cow<'a, str>,
     },
+    /// Only strings can be compared case-insensitively. This query compares
+    /// a field of another type case-insensitively.
+    InvalidConditionMode {
+        /// The name of the field.
+        lhs_name: Cow<'a, str>,
+    },
+    /// A cursor must refer to the id field or a unique field of the returned
+    /// model. The cursor of this query refers to another field.
+    InvalidCursor {
+        /// The name of the field.
+        field_name: Cow<'a, str>,
+    },
+    /// Results can only be ordered by scalar fields, optionally through
+    /// relations. This query orders by a relation, a list, or an undefined
+    /// field.
+    InvalidOrdering {
+        /// The path to the field.
+        path: Cow<'a, str>,
+    },
+    /// Only queries that return an array can be paginated. This query returns
+    /// a single record but uses `take`, `skip` or a cursor.
+    InvalidPagination {
+        /// The name of the pagination clause.
+        clause: Cow<'a, str>,
+    },
+    /// `take` and `skip` require an `Int`, and a cursor requires a value of
+    /// the type of its field. A pagination clause of this query has a value
+    /// of another type.
+    InvalidPaginationValue {
+        /// The name of the pagination clause.
+        clause: Cow<'a, str>,
+        /// The value of the clause, as written in the source.
+        value: Cow<'a, str>,
+    },
     /// The root node of content of the where clause should have the same name
     /// as the root node of the schema. The name of the root node of the where
     /// clause does not match that of the root node of the schema.
@@ -54,6 +88,13 @@ pub enum QueryError<'a> {
         /// The name of the argument.
         argument_name: Cow<'a, str>,
     },
+    /// A relation in a query schema must select at least one field of the
+    /// related model. This query schema selects a relation without selecting
+    /// any of its fields.
+    MissingSelection {
+        /// The path to the relation.
+        field_name: Cow<'a, str>,
+    },
     /// The structure of the schema of a query should match the structure of
     /// the model and its relations. This query schema includes a field that is
     /// not defined in the model.
@@ -67,8 +108,14 @@ pub enum QueryError<'a> {
         /// The name of the model.
         model_name: Cow<'a, str>,
     },
-    /// Every argument of a query must be used in the where clause. This query
-    /// contains an unused argument.
+    /// Only relations can have a selection of fields. This query schema
+    /// selects fields on a field that is not a relation.
+    UnexpectedSelection {
+        /// The path to the field.
+        field_name: Cow<'a, str>,
+    },
+    /// Every argument of a query must be used in the where clause or in a
+    /// pagination clause. This query contains an unused argument.
     UnusedArgument {
         /// The name of the argument.
         argument_name: Cow<'a, str>,
@@ -103,6 +150,35 @@ impl Display for QueryError<'_> {
                     "condition `{lhs_name} {operator} {rhs_name}` is invalid"
                 )
             }
+            Self::InvalidConditionMode { lhs_name } => {
+                write!(
+                    f,
+                    "mode `insensitive` is not allowed on non-string field \
+                     `{lhs_name}`"
+                )
+            }
+            Self::InvalidCursor { field_name } => {
+                write!(
+                    f,
+                    "cursor field `{field_name}` is not an id or unique field"
+                )
+            }
+            Self::InvalidOrdering { path } => {
+                write!(
+                    f,
+                    "cannot order by `{path}`, which is not a scalar field"
+                )
+            }
+            Self::InvalidPagination { clause } => {
+                write!(
+                    f,
+                    "`{clause}` is only allowed on queries that return an \
+                     array"
+                )
+            }
+            Self::InvalidPaginationValue { clause, value } => {
+                write!(f, "`{clause}` has invalid value `{value}`")
+            }
             Self::InvalidWhereName {
                 schema_name,
                 where_name,
@@ -113,6 +189,12 @@ impl Display for QueryError<'_> {
                      schema root `{schema_name}`"
                 )
             }
+            Self::MissingSelection { field_name } => {
+                write!(
+                    f,
+                    "relation `{field_name}` must select at least one field"
+                )
+            }
             Self::UndefinedArgument { argument_name } => {
                 write!(f, "argument `${argument_name}` is undefined")
             }
@@ -122,6 +204,9 @@ impl Display for QueryError<'_> {
             Self::UndefinedReturnType { model_name } => {
                 write!(f, "return type `{model_name}` is undefined")
             }
+            Self::UnexpectedSelection { field_name } => {
+                write!(f, "field `{field_name}` is not a relation")
+            }
             Self::UnusedArgument { argument_name } => {
                 write!(f, "argument `${argument_name}` is unused")
             }
@@ -170,6 +255,50 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn test_source_invalid_cursor() {
+        assert!(QueryError::InvalidCursor {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_ordering() {
+        assert!(QueryError::InvalidOrdering { path: "foo".into() }
+            .source()
+            .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_pagination() {
+        assert!(QueryError::InvalidPagination {
+            clause: "take".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_pagination_value() {
+        assert!(QueryError::InvalidPaginationValue {
+            clause: "take".into(),
+            value: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
+    #[test]
+    fn test_source_invalid_condition_mode() {
+        assert!(QueryError::InvalidConditionMode {
+            lhs_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
     #[test]
     fn test_source_invalid_where_name() {
         assert!(QueryError::InvalidWhereName {
@@ -180,6 +309,15 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn test_source_missing_selection() {
+        assert!(QueryError::MissingSelection {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
     #[test]
     fn test_source_undefined_argument() {
         assert!(QueryError::UndefinedArgument {
@@ -207,6 +345,15 @@ mod tests {
         .is_none());
     }
 
+    #[test]
+    fn test_source_unexpected_selection() {
+        assert!(QueryError::UnexpectedSelection {
+            field_name: "foo".into(),
+        }
+        .source()
+        .is_none());
+    }
+
     #[test]
     fn test_source_unused_argument() {
         assert!(QueryError::UnusedArgument {
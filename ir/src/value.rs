@@ -0,0 +1,173 @@
+use {
+    crate::{
+        Enum,
+        Type,
+    },
+    std::borrow::Cow,
+};
+
+/// A type-checked literal value, for example the default value of a field.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Value<'a> {
+    /// A boolean.
+    Boolean(bool),
+    /// A value of an enum.
+    EnumValue(Cow<'a, str>),
+    /// A floating point number, as written in the source.
+    Float(Cow<'a, str>),
+    /// An integer, as written in the source.
+    Int(Cow<'a, str>),
+    /// The current date and time.
+    Now,
+    /// A string, without the surrounding quotes.
+    String(Cow<'a, str>),
+}
+
+impl<'a> Value<'a> {
+    /// Create a value from an AST value of the given scalar type. Returns
+    /// `None` if the value does not match the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The AST value.
+    /// * `r#type` - The type of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ir::{
+    ///     Type,
+    ///     Value,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Value::from_ast_value(&ast::Value::Int("1".into()), Type::Float),
+    ///     Some(Value::Float("1".into()))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Value::from_ast_value(&ast::Value::Boolean(true), Type::String),
+    ///     None
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_ast_value(
+        value: &ast::Value<'a>,
+        r#type: Type,
+    ) -> Option<Self> {
+        match (r#type, value) {
+            (Type::Boolean, ast::Value::Boolean(value)) => {
+                Some(Self::Boolean(*value))
+            }
+            (Type::DateTime, ast::Value::Function(name)) if name == "now" => {
+                Some(Self::Now)
+            }
+            (
+                Type::Decimal | Type::Float,
+                ast::Value::Float(value) | ast::Value::Int(value),
+            ) => Some(Self::Float(value.clone())),
+            (Type::BigInt | Type::Int, ast::Value::Int(value)) => {
+                Some(Self::Int(value.clone()))
+            }
+            (
+                Type::Json | Type::String | Type::Uuid,
+                ast::Value::String(value),
+            ) => Some(Self::String(value.clone())),
+            _ => None,
+        }
+    }
+
+    /// Create a value from an AST value that should be a value of the given
+    /// enum. Returns `None` if it is not.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The AST value.
+    /// * `r#enum` - The enum.
+    #[must_use]
+    pub fn from_ast_enum_value(
+        value: &ast::Value<'a>,
+        r#enum: &Enum<'_>,
+    ) -> Option<Self> {
+        match value {
+            ast::Value::Identifier(name) if r#enum.values.contains(name) => {
+                Some(Self::EnumValue(name.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        ord_str_map::OrdStrMap,
+        token_set::TokenSet,
+    };
+
+    #[test]
+    fn test_from_ast_value() {
+        assert_eq!(
+            Value::from_ast_value(
+                &ast::Value::Function("now".into()),
+                Type::DateTime
+            ),
+            Some(Value::Now)
+        );
+
+        assert_eq!(
+            Value::from_ast_value(&ast::Value::Float("1.5".into()), Type::Int),
+            None
+        );
+
+        assert_eq!(
+            Value::from_ast_value(
+                &ast::Value::Float("9.99".into()),
+                Type::Decimal
+            ),
+            Some(Value::Float("9.99".into()))
+        );
+
+        assert_eq!(
+            Value::from_ast_value(&ast::Value::Int("1".into()), Type::BigInt),
+            Some(Value::Int("1".into()))
+        );
+
+        assert_eq!(
+            Value::from_ast_value(&ast::Value::String("{}".into()), Type::Json),
+            Some(Value::String("{}".into()))
+        );
+
+        assert_eq!(
+            Value::from_ast_value(&ast::Value::String("".into()), Type::Bytes),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_ast_enum_value() {
+        let r#enum = Enum {
+            name: "Role".into(),
+            values: TokenSet::from_iter(["Admin", "User"]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
+        };
+
+        assert_eq!(
+            Value::from_ast_enum_value(
+                &ast::Value::Identifier("Admin".into()),
+                &r#enum
+            ),
+            Some(Value::EnumValue("Admin".into()))
+        );
+
+        assert_eq!(
+            Value::from_ast_enum_value(
+                &ast::Value::Identifier("Guest".into()),
+                &r#enum
+            ),
+            None
+        );
+    }
+}
@@ -1,9 +1,9 @@
 use super::{
     alphabetics,
-    lowercase,
-    many,
-    many1,
+    chars_if,
+    option,
     uppercase,
+    Input,
     ParseError,
     ParseResult,
 };
@@ -12,7 +12,7 @@ use super::{
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -24,58 +24,62 @@ use super::{
 /// ```rust
 /// use parser::{
 ///     capitalized,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(capitalized("Foo"), Ok(("Foo".to_owned(), String::new())));
-/// assert_eq!(capitalized("F0o"), Ok(("F".to_owned(), "0o".to_owned())));
+/// let input = Input::new("Foo");
 ///
-/// assert_eq!(
-///     capitalized("Foo Bar"),
-///     Ok(("Foo".to_owned(), " Bar".to_owned()))
-/// );
+/// assert_eq!(capitalized(input), Ok(("Foo", input.advance(3))));
 ///
-/// assert_eq!(
-///     capitalized("FooBar"),
-///     Ok(("FooBar".to_owned(), String::new()))
-/// );
+/// let input = Input::new("F0o");
+///
+/// assert_eq!(capitalized(input), Ok(("F", input.advance(1))));
+///
+/// let input = Input::new("Foo Bar");
+///
+/// assert_eq!(capitalized(input), Ok(("Foo", input.advance(3))));
+///
+/// let input = Input::new("FooBar");
+///
+/// assert_eq!(capitalized(input), Ok(("FooBar", input.advance(6))));
 ///
 /// assert_eq!(
-///     capitalized("foo"),
+///     capitalized(Input::new("foo")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'f',
 ///         message: "Expected capitalized identifier to start with uppercase \
 ///                   character, found 'f'."
 ///             .to_string(),
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn capitalized(input: &str) -> ParseResult<String> {
-    let (head, input) = uppercase(input).map_err(|_| {
-        let actual = input.chars().next().map_or('\0', |c| c);
+pub fn capitalized(input: Input<'_>) -> ParseResult<'_, &str> {
+    let (_, new_input) = uppercase(input).map_err(|error| {
+        let actual = input.peek().map_or('\0', |c| c);
 
-        ParseError::UnexpectedChar {
+        ParseError::unexpected_char(
             actual,
-            message: format!(
+            format!(
                 "Expected capitalized identifier to start with uppercase \
                  character, found '{actual}'."
             ),
-        }
+            error.span(),
+        )
     })?;
 
-    let (tail, input) = many(&input, alphabetics)?;
+    let (_, end) = option(new_input, alphabetics)?;
 
-    Ok((
-        format!("{head}{}", tail.into_iter().collect::<String>()),
-        input,
-    ))
+    Ok((input.slice_to(end), end))
 }
 
 /// Parses a pascal case ASCII identifier.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -88,61 +92,73 @@ pub fn capitalized(input: &str) -> ParseResult<String> {
 /// ```rust
 /// use parser::{
 ///     pascal_case,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(
-///     pascal_case("FooBar"),
-///     Ok(("FooBar".to_owned(), String::new()))
-/// );
+/// let input = Input::new("FooBar");
+///
+/// assert_eq!(pascal_case(input), Ok(("FooBar", input.advance(6))));
 ///
 /// assert_eq!(
-///     pascal_case("foobar"),
+///     pascal_case(Input::new("foobar")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'f',
 ///         message: "Expected segment of PascalCase identifier to start with \
 ///                   uppercase character, found 'f'."
 ///             .to_string(),
+///         span: Span::new(0, 1),
 ///     })
 /// );
 ///
 /// assert_eq!(
-///     pascal_case("foo_bar"),
+///     pascal_case(Input::new("foo_bar")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'f',
 ///         message: "Expected segment of PascalCase identifier to start with \
 ///                   uppercase character, found 'f'."
 ///             .to_string(),
+///         span: Span::new(0, 1),
 ///     })
 /// );
 ///
 /// assert_eq!(
-///     pascal_case("foo-bar"),
+///     pascal_case(Input::new("foo-bar")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'f',
 ///         message: "Expected segment of PascalCase identifier to start with \
 ///                   uppercase character, found 'f'."
 ///             .to_string(),
+///         span: Span::new(0, 1),
 ///     })
 /// );
 ///
-/// assert_eq!(pascal_case(""), Err(ParseError::UnexpectedEof));
+/// assert_eq!(
+///     pascal_case(Input::new("")),
+///     Err(ParseError::UnexpectedEof { span: Span::at(0) })
+/// );
 /// ```
-pub fn pascal_case(input: &str) -> ParseResult<String> {
-    if let Ok((parts, input)) = many1(input, capitalized) {
-        return Ok((parts.join(""), input));
+pub fn pascal_case(input: Input<'_>) -> ParseResult<'_, &str> {
+    if let Ok((_, mut end)) = capitalized(input) {
+        while let Ok((_, new_end)) = capitalized(end) {
+            end = new_end;
+        }
+
+        return Ok((input.slice_to(end), end));
     }
 
-    return Err(input.chars().next().map_or(
-        ParseError::UnexpectedEof,
+    return Err(input.peek().map_or_else(
+        || ParseError::unexpected_eof(input.span()),
         |actual| {
-            ParseError::UnexpectedChar {
+            ParseError::unexpected_char(
                 actual,
-                message: format!(
+                format!(
                     "Expected segment of PascalCase identifier to start with \
                      uppercase character, found '{actual}'."
                 ),
-            }
+                input.span_to(input.advance(actual.len_utf8())),
+            )
         },
     ));
 }
@@ -151,7 +167,7 @@ pub fn pascal_case(input: &str) -> ParseResult<String> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -164,57 +180,60 @@ pub fn pascal_case(input: &str) -> ParseResult<String> {
 /// ```rust
 /// use parser::{
 ///     camel_case,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(camel_case("foo"), Ok(("foo".to_owned(), String::new())));
+/// let input = Input::new("foo");
 ///
-/// assert_eq!(
-///     camel_case("fooBar"),
-///     Ok(("fooBar".to_owned(), String::new()))
-/// );
+/// assert_eq!(camel_case(input), Ok(("foo", input.advance(3))));
+///
+/// let input = Input::new("fooBar");
+///
+/// assert_eq!(camel_case(input), Ok(("fooBar", input.advance(6))));
 ///
 /// assert_eq!(
-///     camel_case("FooBar"),
+///     camel_case(Input::new("FooBar")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected camelCase identifier to start with lowercase \
 ///                   character, found 'F'."
 ///             .to_string(),
 ///         actual: 'F',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 ///
-/// assert_eq!(camel_case(""), Err(ParseError::UnexpectedEof));
+/// assert_eq!(
+///     camel_case(Input::new("")),
+///     Err(ParseError::UnexpectedEof { span: Span::at(0) })
+/// );
 /// ```
-pub fn camel_case(input: &str) -> ParseResult<String> {
-    let (head, input) = many1(input, lowercase).map_err(|e| {
+pub fn camel_case(input: Input<'_>) -> ParseResult<'_, &str> {
+    let (_, mut end) = chars_if(
+        input,
+        |char| char.is_ascii_lowercase(),
+        "Expected lowercase character.",
+    )
+    .map_err(|e| {
         match e {
-            ParseError::UnexpectedChar { .. } => {
-                input.chars().next().map_or(
-                    ParseError::UnexpectedEof,
-                    |actual| {
-                        ParseError::UnexpectedChar {
-                            actual,
-                            message: format!(
-                                "Expected camelCase identifier to start with \
-                                 lowercase character, found '{actual}'."
-                            ),
-                        }
-                    },
+            ParseError::UnexpectedChar { actual, span, .. } => {
+                ParseError::unexpected_char(
+                    actual,
+                    format!(
+                        "Expected camelCase identifier to start with \
+                         lowercase character, found '{actual}'."
+                    ),
+                    span,
                 )
             }
             _ => e,
         }
     })?;
 
-    let (tail, input) = many(&input, capitalized)?;
+    while let Ok((_, new_end)) = capitalized(end) {
+        end = new_end;
+    }
 
-    Ok((
-        if tail.is_empty() {
-            head.iter().collect::<String>()
-        } else {
-            format!("{}{}", head.iter().collect::<String>(), tail.join(""))
-        },
-        input,
-    ))
+    Ok((input.slice_to(end), end))
 }
@@ -1,5 +1,6 @@
 use super::{
     char,
+    Input,
     ParseResult,
 };
 
@@ -7,7 +8,7 @@ use super::{
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -20,20 +21,25 @@ use super::{
 /// ```rust
 /// use parser::{
 ///     brace_open,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(brace_open("{"), Ok(('{', String::new())));
+/// let input = Input::new("{");
+///
+/// assert_eq!(brace_open(input), Ok(('{', input.advance(1))));
 ///
 /// assert_eq!(
-///     brace_open("}"),
+///     brace_open(Input::new("}")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character '{', found '}'.".to_owned(),
-///         actual: '}'
+///         actual: '}',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn brace_open(input: &str) -> ParseResult<char> {
+pub fn brace_open(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, '{')
 }
 
@@ -41,7 +47,7 @@ pub fn brace_open(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -54,20 +60,25 @@ pub fn brace_open(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     brace_close,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(brace_close("}"), Ok(('}', String::new())));
+/// let input = Input::new("}");
+///
+/// assert_eq!(brace_close(input), Ok(('}', input.advance(1))));
 ///
 /// assert_eq!(
-///     brace_close("{"),
+///     brace_close(Input::new("{")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character '}', found '{'.".to_owned(),
-///         actual: '{'
+///         actual: '{',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn brace_close(input: &str) -> ParseResult<char> {
+pub fn brace_close(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, '}')
 }
 
@@ -75,7 +86,7 @@ pub fn brace_close(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -87,20 +98,25 @@ pub fn brace_close(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     colon,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(colon(":"), Ok((':', String::new())));
+/// let input = Input::new(":");
+///
+/// assert_eq!(colon(input), Ok((':', input.advance(1))));
 ///
 /// assert_eq!(
-///     colon("a"),
+///     colon(Input::new("a")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character ':', found 'a'.".to_owned(),
-///         actual: 'a'
+///         actual: 'a',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn colon(input: &str) -> ParseResult<char> {
+pub fn colon(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, ':')
 }
 
@@ -108,7 +124,7 @@ pub fn colon(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -121,20 +137,25 @@ pub fn colon(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     paren_open,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(paren_open("("), Ok(('(', String::new())));
+/// let input = Input::new("(");
+///
+/// assert_eq!(paren_open(input), Ok(('(', input.advance(1))));
 ///
 /// assert_eq!(
-///     paren_open(")"),
+///     paren_open(Input::new(")")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character '(', found ')'.".to_owned(),
-///         actual: ')'
+///         actual: ')',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn paren_open(input: &str) -> ParseResult<char> {
+pub fn paren_open(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, '(')
 }
 
@@ -142,7 +163,7 @@ pub fn paren_open(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -155,20 +176,25 @@ pub fn paren_open(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     paren_close,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(paren_close(")"), Ok((')', String::new())));
+/// let input = Input::new(")");
+///
+/// assert_eq!(paren_close(input), Ok((')', input.advance(1))));
 ///
 /// assert_eq!(
-///     paren_close("("),
+///     paren_close(Input::new("(")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character ')', found '('.".to_owned(),
-///         actual: '('
+///         actual: '(',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn paren_close(input: &str) -> ParseResult<char> {
+pub fn paren_close(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, ')')
 }
 
@@ -176,7 +202,7 @@ pub fn paren_close(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -189,20 +215,25 @@ pub fn paren_close(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     dollar,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(dollar("$"), Ok(('$', String::new())));
+/// let input = Input::new("$");
+///
+/// assert_eq!(dollar(input), Ok(('$', input.advance(1))));
 ///
 /// assert_eq!(
-///     dollar("a"),
+///     dollar(Input::new("a")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character '$', found 'a'.".to_owned(),
-///         actual: 'a'
+///         actual: 'a',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn dollar(input: &str) -> ParseResult<char> {
+pub fn dollar(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, '$')
 }
 
@@ -210,7 +241,7 @@ pub fn dollar(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -222,20 +253,25 @@ pub fn dollar(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     comma,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(comma(","), Ok((',', String::new())));
+/// let input = Input::new(",");
+///
+/// assert_eq!(comma(input), Ok((',', input.advance(1))));
 ///
 /// assert_eq!(
-///     comma("a"),
+///     comma(Input::new("a")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character ',', found 'a'.".to_owned(),
-///         actual: 'a'
+///         actual: 'a',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn comma(input: &str) -> ParseResult<char> {
+pub fn comma(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, ',')
 }
 
@@ -243,7 +279,7 @@ pub fn comma(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -256,18 +292,61 @@ pub fn comma(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     at,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(at("@"), Ok(('@', String::new())));
+/// let input = Input::new("@");
+///
+/// assert_eq!(at(input), Ok(('@', input.advance(1))));
 /// assert_eq!(
-///     at("a"),
+///     at(Input::new("a")),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character '@', found 'a'.".to_owned(),
-///         actual: 'a'
+///         actual: 'a',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn at(input: &str) -> ParseResult<char> {
+pub fn at(input: Input<'_>) -> ParseResult<'_, char> {
     char(input, '@')
 }
+
+/// Parse a question mark.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// * Returns `ParseError::UnexpectedEof` if the input is empty.
+/// * Returns `ParseError::UnexpectedChar` if the next character is not a
+///   question mark.
+///
+/// # Examples
+///
+/// ```rust
+/// use parser::{
+///     question_mark,
+///     Input,
+///     ParseError,
+///     Span,
+/// };
+///
+/// let input = Input::new("?");
+///
+/// assert_eq!(question_mark(input), Ok(('?', input.advance(1))));
+/// assert_eq!(
+///     question_mark(Input::new("a")),
+///     Err(ParseError::UnexpectedChar {
+///         message: "Expected character '?', found 'a'.".to_owned(),
+///         actual: 'a',
+///         span: Span::new(0, 1),
+///     })
+/// );
+/// ```
+pub fn question_mark(input: Input<'_>) -> ParseResult<'_, char> {
+    char(input, '?')
+}
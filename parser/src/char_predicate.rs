@@ -1,5 +1,7 @@
 use super::{
-    many,
+    block_comment,
+    line_comment,
+    Input,
     ParseError,
     ParseResult,
 };
@@ -8,7 +10,7 @@ use super::{
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `predicate` - The predicate to apply.
 /// * `description` - A description of the predicate, used in error messages.
 ///
@@ -23,64 +25,72 @@ use super::{
 /// ```rust
 /// use parser::{
 ///     char_if,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
+/// let input = Input::new("a");
+///
 /// assert_eq!(
 ///     char_if(
-///         "a",
+///         input,
 ///         |c| c.is_ascii_lowercase(),
 ///         "Expected lowercase character."
 ///     ),
-///     Ok(('a', String::new())),
+///     Ok(('a', input.advance(1))),
 /// );
 ///
 /// assert_eq!(
 ///     char_if(
-///         "A",
+///         Input::new("A"),
 ///         |c| c.is_ascii_lowercase(),
 ///         "Expected lowercase character."
 ///     ),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'A',
 ///         message: "Expected lowercase character.".to_owned(),
+///         span: Span::new(0, 1),
 ///     }),
 /// );
 ///
 /// assert_eq!(
 ///     char_if(
-///         "",
+///         Input::new(""),
 ///         |c| c.is_ascii_lowercase(),
 ///         "Expected lowercase character."
 ///     ),
-///     Err(ParseError::UnexpectedEof),
+///     Err(ParseError::UnexpectedEof { span: Span::at(0) }),
 /// );
 /// ```
-pub fn char_if(
-    input: &str,
+pub fn char_if<'a>(
+    input: Input<'a>,
     predicate: fn(char) -> bool,
     description: &str,
-) -> ParseResult<char> {
-    if let Some(char) = input.chars().next() {
+) -> ParseResult<'a, char> {
+    if let Some(char) = input.peek() {
+        let new_input = input.advance(char.len_utf8());
+
         if predicate(char) {
-            return Ok((char, input[1..].to_string()));
+            return Ok((char, new_input));
         }
 
-        return Err(ParseError::UnexpectedChar {
-            actual: char,
-            message: description.to_owned(),
-        });
+        return Err(ParseError::unexpected_char(
+            char,
+            description,
+            input.span_to(new_input),
+        ));
     }
 
-    Err(ParseError::UnexpectedEof)
+    Err(ParseError::unexpected_eof(input.span()))
 }
 
-/// Parse one or more characters that fulfill the specified predicate into a
-/// string.
+/// Parse one or more characters that fulfill the specified predicate,
+/// returning the matched part of the source.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `predicate` - The predicate to apply.
 /// * `description` - The description of the predicate, used in error messages.
 ///
@@ -95,51 +105,54 @@ pub fn char_if(
 /// ```rust
 /// use parser::{
 ///     chars_if,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
+/// let input = Input::new("abc");
+///
 /// assert_eq!(
 ///     chars_if(
-///         "abc",
+///         input,
 ///         |c| c.is_ascii_alphabetic(),
 ///         "Expected alphabetic character."
 ///     ),
-///     Ok(("abc".to_owned(), String::new())),
+///     Ok(("abc", input.advance(3))),
 /// );
 ///
 /// assert_eq!(
 ///     chars_if(
-///         "123",
+///         Input::new("123"),
 ///         |c| c.is_ascii_alphabetic(),
 ///         "Expected alphabetic character."
 ///     ),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: '1',
 ///         message: "Expected alphabetic character.".to_owned(),
+///         span: Span::new(0, 1),
 ///     }),
 /// );
 /// ```
-pub fn chars_if(
-    input: &str,
+pub fn chars_if<'a>(
+    input: Input<'a>,
     predicate: fn(char) -> bool,
     description: &str,
-) -> ParseResult<String> {
-    let (head, mut input) = char_if(input, predicate, description)?;
-    let mut result = head.to_string();
+) -> ParseResult<'a, &'a str> {
+    let (_, mut end) = char_if(input, predicate, description)?;
 
-    while let Ok((char, new_input)) = char_if(&input, predicate, description) {
-        result.push(char);
-        input = new_input;
+    while let Ok((_, new_end)) = char_if(end, predicate, description) {
+        end = new_end;
     }
 
-    Ok((result, input))
+    Ok((input.slice_to(end), end))
 }
 
-/// Parse one or more alphabetic ASCII characters into a string.
+/// Parse one or more alphabetic ASCII characters.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -152,20 +165,25 @@ pub fn chars_if(
 /// ```rust
 /// use parser::{
 ///     alphabetics,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(alphabetics("abc"), Ok(("abc".to_owned(), String::new())));
+/// let input = Input::new("abc");
+///
+/// assert_eq!(alphabetics(input), Ok(("abc", input.advance(3))));
 ///
 /// assert_eq!(
-///     alphabetics("123"),
+///     alphabetics(Input::new("123")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: '1',
 ///         message: "Expected alphabetic character.".to_owned(),
+///         span: Span::new(0, 1),
 ///     }),
 /// );
 /// ```
-pub fn alphabetics(input: &str) -> ParseResult<String> {
+pub fn alphabetics(input: Input<'_>) -> ParseResult<'_, &str> {
     chars_if(
         input,
         |char| char.is_ascii_alphabetic(),
@@ -177,7 +195,7 @@ pub fn alphabetics(input: &str) -> ParseResult<String> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -190,20 +208,23 @@ pub fn alphabetics(input: &str) -> ParseResult<String> {
 /// ```rust
 /// use parser::{
 ///     lowercase,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert!(lowercase("a").is_ok());
+/// assert!(lowercase(Input::new("a")).is_ok());
 ///
 /// assert_eq!(
-///     lowercase("A"),
+///     lowercase(Input::new("A")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'A',
 ///         message: "Expected lowercase character.".to_owned(),
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn lowercase(input: &str) -> ParseResult<char> {
+pub fn lowercase(input: Input<'_>) -> ParseResult<'_, char> {
     char_if(
         input,
         |char| char.is_ascii_lowercase(),
@@ -215,7 +236,7 @@ pub fn lowercase(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -228,20 +249,23 @@ pub fn lowercase(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     uppercase,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert!(uppercase("A").is_ok());
+/// assert!(uppercase(Input::new("A")).is_ok());
 ///
 /// assert_eq!(
-///     uppercase("a"),
+///     uppercase(Input::new("a")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'a',
 ///         message: "Expected uppercase character.".to_owned(),
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn uppercase(input: &str) -> ParseResult<char> {
+pub fn uppercase(input: Input<'_>) -> ParseResult<'_, char> {
     char_if(
         input,
         |char| char.is_ascii_uppercase(),
@@ -253,7 +277,7 @@ pub fn uppercase(input: &str) -> ParseResult<char> {
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -266,23 +290,26 @@ pub fn uppercase(input: &str) -> ParseResult<char> {
 /// ```rust
 /// use parser::{
 ///     space,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert!(space(" ").is_ok());
-/// assert!(space("\t").is_ok());
-/// assert!(space("\r").is_ok());
-/// assert!(space("\n").is_ok());
+/// assert!(space(Input::new(" ")).is_ok());
+/// assert!(space(Input::new("\t")).is_ok());
+/// assert!(space(Input::new("\r")).is_ok());
+/// assert!(space(Input::new("\n")).is_ok());
 ///
 /// assert_eq!(
-///     space("a"),
+///     space(Input::new("a")),
 ///     Err(ParseError::UnexpectedChar {
 ///         actual: 'a',
 ///         message: "Expected whitespace character.".to_owned(),
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn space(input: &str) -> ParseResult<char> {
+pub fn space(input: Input<'_>) -> ParseResult<'_, char> {
     char_if(
         input,
         |char| char.is_ascii_whitespace(),
@@ -290,30 +317,70 @@ pub fn space(input: &str) -> ParseResult<char> {
     )
 }
 
-/// Consume zero or more whitespace characters.
+/// Consume zero or more whitespace characters, line comments and block
+/// comments, returning the consumed part of the source.
+///
+/// Doc comments are line comments too. Parsers of declarations that take a
+/// doc skip the whitespace and comments before them with `doc_comments`
+/// instead.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
-/// This parser always succeeds.
+/// Returns `ParseError::Custom` if a block comment is not closed.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use parser::{
 ///     spaces,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
+/// let input = Input::new(" \t\r\n");
+///
+/// assert_eq!(spaces(input), Ok((" \t\r\n", input.advance(4))));
+///
+/// let input = Input::new("abc");
+///
+/// assert_eq!(spaces(input), Ok(("", input)));
+///
+/// let input = Input::new("// foo\n/* bar */ /// baz\nqux");
+///
 /// assert_eq!(
-///     spaces(" \t\r\n"),
-///     Ok((vec![' ', '\t', '\r', '\n'], String::new()))
+///     spaces(input),
+///     Ok(("// foo\n/* bar */ /// baz\n", input.advance(25)))
+/// );
+///
+/// assert_eq!(
+///     spaces(Input::new(" /* foo")),
+///     Err(ParseError::Custom {
+///         message: "Unterminated block comment.".to_owned(),
+///         span: Span::new(1, 7),
+///     })
 /// );
-/// assert_eq!(spaces("abc"), Ok((Vec::new(), "abc".to_owned())));
 /// ```
-pub fn spaces(input: &str) -> ParseResult<Vec<char>> {
-    many(input, space)
+pub fn spaces(input: Input<'_>) -> ParseResult<'_, &str> {
+    let mut end = input;
+
+    loop {
+        let rest = end.rest();
+        let trimmed =
+            rest.trim_start_matches(|char: char| char.is_ascii_whitespace());
+
+        end = end.advance(rest.len() - trimmed.len());
+
+        if trimmed.starts_with("/*") {
+            (_, end) = block_comment(end)?;
+        } else if let Ok((_, new_end)) = line_comment(end) {
+            end = new_end;
+        } else {
+            return Ok((input.slice_to(end), end));
+        }
+    }
 }
@@ -0,0 +1,317 @@
+use super::{
+    literal,
+    Input,
+    ParseError,
+    ParseResult,
+};
+
+/// Parse a line comment: `//` followed by anything up to the end of the line.
+/// The line break itself is not consumed. Doc comments are line comments too.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// * Returns `ParseError::UnexpectedEof` if the input is empty.
+/// * Returns `ParseError::UnmatchedLiteral` if the input does not start with
+///   `//`.
+///
+/// # Examples
+///
+/// ```rust
+/// use parser::{
+///     line_comment,
+///     Input,
+///     ParseError,
+///     Span,
+/// };
+///
+/// let input = Input::new("// foo\nbar");
+///
+/// assert_eq!(line_comment(input), Ok(("// foo", input.advance(6))));
+///
+/// let input = Input::new("// foo");
+///
+/// assert_eq!(line_comment(input), Ok(("// foo", input.advance(6))));
+///
+/// assert_eq!(
+///     line_comment(Input::new("/ foo")),
+///     Err(ParseError::UnmatchedLiteral {
+///         expected: "//".to_owned(),
+///         span: Span::at(0),
+///     })
+/// );
+/// ```
+pub fn line_comment(input: Input<'_>) -> ParseResult<'_, &str> {
+    let (_, body) = literal(input, "//")?;
+    let rest = body.rest();
+    let end = body.advance(rest.find('\n').unwrap_or(rest.len()));
+
+    Ok((input.slice_to(end), end))
+}
+
+/// Parse a doc comment: `///` followed by anything up to the end of the
+/// line, returning the text after `///` and an optional single space.
+///
+/// Like in Rust, a line starting with four or more slashes is not a doc
+/// comment.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// * Returns `ParseError::UnexpectedEof` if the input is empty.
+/// * Returns `ParseError::UnmatchedLiteral` if the input does not start with
+///   `///`.
+/// * Returns `ParseError::UnexpectedChar` if the input starts with `////`.
+///
+/// # Examples
+///
+/// ```rust
+/// use parser::{
+///     doc_comment,
+///     Input,
+///     ParseError,
+///     Span,
+/// };
+///
+/// let input = Input::new("/// The title.\ntitle: String");
+///
+/// assert_eq!(doc_comment(input), Ok(("The title.", input.advance(14))));
+///
+/// assert_eq!(
+///     doc_comment(Input::new("//// Not documentation.")),
+///     Err(ParseError::UnexpectedChar {
+///         actual: '/',
+///         message: "Expected doc comment, found `////`.".to_owned(),
+///         span: Span::new(3, 4),
+///     })
+/// );
+/// ```
+pub fn doc_comment(input: Input<'_>) -> ParseResult<'_, &str> {
+    let (_, body) = literal(input, "///")?;
+
+    if body.peek() == Some('/') {
+        return Err(ParseError::unexpected_char(
+            '/',
+            "Expected doc comment, found `////`.",
+            body.span_to(body.advance(1)),
+        ));
+    }
+
+    let body = body.advance(usize::from(body.peek() == Some(' ')));
+    let rest = body.rest();
+    let end = body.advance(rest.find('\n').unwrap_or(rest.len()));
+
+    Ok((body.slice_to(end).trim_end(), end))
+}
+
+/// Skip whitespace and comments, and return the doc comments on the lines
+/// directly above the next token, joined by line breaks.
+///
+/// The input should start right after the previous token, so that doc
+/// comments on its line can be told apart. Blank lines between doc comments
+/// are skipped. A doc comment that does not start its line is not
+/// documentation, and an ordinary line or block comment ends the doc comments
+/// above it.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// Returns `ParseError::Custom` if a block comment is not closed.
+///
+/// # Examples
+///
+/// ```rust
+/// use parser::{
+///     doc_comments,
+///     Input,
+/// };
+///
+/// let input = Input::new("/// An image.\n///\n/// With a title.\nmodel");
+///
+/// assert_eq!(
+///     doc_comments(input),
+///     Ok((
+///         Some("An image.\n\nWith a title.".to_owned()),
+///         input.advance(36)
+///     ))
+/// );
+///
+/// let input = Input::new("model");
+///
+/// assert_eq!(doc_comments(input), Ok((None, input)));
+///
+/// let input = Input::new("title: String /// Not documentation.\nbody");
+///
+/// assert_eq!(
+///     doc_comments(input.advance(13)),
+///     Ok((None, input.advance(37)))
+/// );
+///
+/// let input = Input::new("/// Not documentation.\n// A comment.\nmodel");
+///
+/// assert_eq!(doc_comments(input), Ok((None, input.advance(37))));
+/// ```
+pub fn doc_comments(input: Input<'_>) -> ParseResult<'_, Option<String>> {
+    let mut at_line_start = input
+        .source()
+        .get(..input.offset())
+        .unwrap_or_default()
+        .rsplit('\n')
+        .next()
+        .is_none_or(|prefix| prefix.trim().is_empty());
+
+    let mut lines = Vec::new();
+    let mut end = input;
+
+    loop {
+        let rest = end.rest();
+        let trimmed =
+            rest.trim_start_matches(|char: char| char.is_ascii_whitespace());
+
+        let whitespace = &rest[..rest.len() - trimmed.len()];
+
+        at_line_start |= whitespace.contains('\n');
+        end = end.advance(whitespace.len());
+
+        if trimmed.starts_with("/*") {
+            (_, end) = block_comment(end)?;
+            lines.clear();
+        } else if let Ok((text, new_end)) = doc_comment(end) {
+            if at_line_start {
+                lines.push(text);
+            } else {
+                lines.clear();
+            }
+
+            end = new_end;
+        } else if let Ok((_, new_end)) = line_comment(end) {
+            lines.clear();
+            end = new_end;
+        } else {
+            break;
+        }
+
+        at_line_start = false;
+    }
+
+    if !at_line_start {
+        lines.clear();
+    }
+
+    Ok(((!lines.is_empty()).then(|| lines.join("\n")), end))
+}
+
+/// Parse a block comment: anything between `/*` and `*/`. Block comments do
+/// not nest.
+///
+/// # Arguments
+///
+/// * `input` - The input to parse.
+///
+/// # Errors
+///
+/// * Returns `ParseError::UnexpectedEof` if the input is empty.
+/// * Returns `ParseError::UnmatchedLiteral` if the input does not start with
+///   `/*`.
+/// * Returns `ParseError::Custom` if the comment is not closed.
+///
+/// # Examples
+///
+/// ```rust
+/// use parser::{
+///     block_comment,
+///     Input,
+///     ParseError,
+///     Span,
+/// };
+///
+/// let input = Input::new("/* foo\nbar */baz");
+///
+/// assert_eq!(
+///     block_comment(input),
+///     Ok(("/* foo\nbar */", input.advance(13)))
+/// );
+///
+/// assert_eq!(
+///     block_comment(Input::new("/* foo")),
+///     Err(ParseError::Custom {
+///         message: "Unterminated block comment.".to_owned(),
+///         span: Span::new(0, 6),
+///     })
+/// );
+/// ```
+pub fn block_comment(input: Input<'_>) -> ParseResult<'_, &str> {
+    let (_, body) = literal(input, "/*")?;
+
+    body.rest().find("*/").map_or_else(
+        || {
+            let end = body.advance(body.rest().len());
+
+            Err(ParseError::custom(
+                "Unterminated block comment.",
+                input.span_to(end),
+            ))
+        },
+        |index| {
+            let end = body.advance(index + 2);
+
+            Ok((input.slice_to(end), end))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_comment_empty() {
+        let input = Input::new("//\n");
+
+        assert_eq!(line_comment(input), Ok(("//", input.advance(2))));
+    }
+
+    #[test]
+    fn test_doc_comment_without_space() {
+        let input = Input::new("///Foo.  \n");
+
+        assert_eq!(doc_comment(input), Ok(("Foo.", input.advance(9))));
+    }
+
+    #[test]
+    fn test_block_comment_does_not_nest() {
+        let input = Input::new("/* /* foo */ */");
+
+        assert_eq!(
+            block_comment(input),
+            Ok(("/* /* foo */", input.advance(12)))
+        );
+    }
+
+    #[test]
+    fn test_doc_comments_after_ordinary_comment() {
+        let input =
+            Input::new("/// Foo.\n// Bar.\n/// Baz.\n\n/// Qux.\nmodel");
+
+        assert_eq!(
+            doc_comments(input),
+            Ok((Some("Baz.\nQux.".to_owned()), input.advance(36)))
+        );
+    }
+
+    #[test]
+    fn test_doc_comments_before_block_comment() {
+        let input = Input::new("/// Foo.\n/* Bar. */ model");
+
+        assert_eq!(doc_comments(input), Ok((None, input.advance(20))));
+    }
+}
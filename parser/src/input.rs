@@ -0,0 +1,258 @@
+use super::{
+    Location,
+    Span,
+};
+
+/// The input of a parser: a source string and the offset of the first byte
+/// that has not been consumed yet.
+///
+/// # Examples
+///
+/// ```rust
+/// use parser::{
+///     Input,
+///     Location,
+///     Span,
+/// };
+///
+/// let input = Input::new("foo\nbar").advance(5);
+///
+/// assert_eq!(input.rest(), "ar");
+/// assert_eq!(input.offset(), 5);
+/// assert_eq!(input.location(), Location { line: 2, column: 2 });
+/// assert_eq!(Input::new("foo\nbar").span_to(input), Span::new(0, 5));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Input<'a> {
+    /// The complete source.
+    source: &'a str,
+    /// The offset of the first byte that has not been consumed yet.
+    offset: usize,
+}
+
+impl<'a> Input<'a> {
+    /// Create a new input at the start of the given source.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// let input = Input::new("foo");
+    ///
+    /// assert_eq!(input.rest(), "foo");
+    /// assert_eq!(input.offset(), 0);
+    /// ```
+    #[must_use]
+    pub const fn new(source: &'a str) -> Self {
+        Self { source, offset: 0 }
+    }
+
+    /// The complete source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// assert_eq!(Input::new("foo").advance(1).source(), "foo");
+    /// ```
+    #[must_use]
+    pub const fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// The offset of the first byte that has not been consumed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// assert_eq!(Input::new("foo").advance(1).offset(), 1);
+    /// ```
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The part of the source that has not been consumed yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// assert_eq!(Input::new("foo").advance(1).rest(), "oo");
+    /// ```
+    #[must_use]
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.offset..]
+    }
+
+    /// Check if the whole source has been consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// assert!(!Input::new("foo").is_empty());
+    /// assert!(Input::new("foo").advance(3).is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.offset >= self.source.len()
+    }
+
+    /// The next character of the input, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// assert_eq!(Input::new("foo").peek(), Some('f'));
+    /// assert_eq!(Input::new("").peek(), None);
+    /// ```
+    #[must_use]
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Consume the given number of bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of bytes to consume.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// assert_eq!(Input::new("foo").advance(2).rest(), "o");
+    /// ```
+    #[must_use]
+    pub const fn advance(
+        self,
+        len: usize,
+    ) -> Self {
+        Self {
+            source: self.source,
+            offset: self.offset + len,
+        }
+    }
+
+    /// The span between this input and a later input.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The input after the spanned part of the source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::{
+    ///     Input,
+    ///     Span,
+    /// };
+    ///
+    /// let start = Input::new("foo bar").advance(4);
+    /// let end = start.advance(3);
+    ///
+    /// assert_eq!(start.span_to(end), Span::new(4, 7));
+    /// ```
+    #[must_use]
+    pub const fn span_to(
+        self,
+        end: Self,
+    ) -> Span {
+        Span::new(self.offset, end.offset)
+    }
+
+    /// The part of the source between this input and a later input.
+    ///
+    /// # Arguments
+    ///
+    /// * `end` - The input after the sliced part of the source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Input;
+    ///
+    /// let start = Input::new("foo bar").advance(4);
+    ///
+    /// assert_eq!(start.slice_to(start.advance(3)), "bar");
+    /// ```
+    #[must_use]
+    pub fn slice_to(
+        self,
+        end: Self,
+    ) -> &'a str {
+        &self.source[self.offset..end.offset]
+    }
+
+    /// An empty span at the current offset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::{
+    ///     Input,
+    ///     Span,
+    /// };
+    ///
+    /// assert_eq!(Input::new("foo").advance(1).span(), Span::at(1));
+    /// ```
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        Span::at(self.offset)
+    }
+
+    /// The line and column of the current offset.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::{
+    ///     Input,
+    ///     Location,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     Input::new("foo\nbar").advance(4).location(),
+    ///     Location { line: 2, column: 1 }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn location(&self) -> Location {
+        Location::from_offset(self.source, self.offset)
+    }
+}
+
+impl<'a> From<&'a str> for Input<'a> {
+    fn from(source: &'a str) -> Self {
+        Self::new(source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Input::from("foo"), Input::new("foo"));
+    }
+
+    #[test]
+    fn test_eq_same_rest_different_offset() {
+        assert_ne!(Input::new("oo"), Input::new("foo").advance(1));
+    }
+}
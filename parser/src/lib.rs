@@ -36,10 +36,18 @@ pub mod capitalization;
 pub mod char;
 /// Parse ASCII characters that match a predicate.
 pub mod char_predicate;
+/// Parse line, block and doc comments.
+pub mod comment;
+/// The input of a parser.
+pub mod input;
+/// Line and column positions.
+pub mod location;
 /// Macro utilities.
 pub mod r#macro;
 /// Parsing errors.
 pub mod parse_error;
+/// Byte offset ranges.
+pub mod span;
 
 pub use {
     self::char::{
@@ -51,6 +59,7 @@ pub use {
         dollar,
         paren_close,
         paren_open,
+        question_mark,
     },
     capitalization::{
         camel_case,
@@ -66,20 +75,29 @@ pub use {
         spaces,
         uppercase,
     },
+    comment::{
+        block_comment,
+        doc_comment,
+        doc_comments,
+        line_comment,
+    },
+    input::Input,
+    location::Location,
     parse_error::ParseError,
+    span::Span,
 };
 
-/// The result of applying a parser to an input string.
-pub type ParseResult<T, E = ParseError> = Result<(T, String), E>;
+/// The result of applying a parser to an input.
+pub type ParseResult<'a, T, E = ParseError> = Result<(T, Input<'a>), E>;
 
-/// A function that parses a string.
-pub type ParseFn<T> = fn(&str) -> ParseResult<T>;
+/// A function that parses an input.
+pub type ParseFn<'a, T> = fn(Input<'a>) -> ParseResult<'a, T>;
 
 /// Apply a parser and map over the result.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `parser` - The parser to apply.
 /// * `f` - The function to map over the result.
 ///
@@ -93,18 +111,21 @@ pub type ParseFn<T> = fn(&str) -> ParseResult<T>;
 /// use parser::{
 ///     char,
 ///     map,
+///     Input,
 /// };
 ///
+/// let input = Input::new("abc");
+///
 /// assert_eq!(
-///     map("abc", char!('a'), |c| c.to_ascii_uppercase()),
-///     Ok(('A', "bc".to_owned()))
+///     map(input, char!('a'), |c| c.to_ascii_uppercase()),
+///     Ok(('A', input.advance(1)))
 /// );
 /// ```
-pub fn map<T, U>(
-    input: &str,
-    parser: ParseFn<T>,
+pub fn map<'a, T, U>(
+    input: Input<'a>,
+    parser: ParseFn<'a, T>,
     f: fn(T) -> U,
-) -> ParseResult<U> {
+) -> ParseResult<'a, U> {
     parser(input).map(|(t, input)| (f(t), input))
 }
 
@@ -112,7 +133,7 @@ pub fn map<T, U>(
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `parser` - The parser to apply.
 /// * `value` - The value to return.
 ///
@@ -126,15 +147,18 @@ pub fn map<T, U>(
 /// use parser::{
 ///     char,
 ///     tag,
+///     Input,
 /// };
 ///
-/// assert_eq!(tag("abc", char!('a'), 1), Ok((1, "bc".to_owned())));
+/// let input = Input::new("abc");
+///
+/// assert_eq!(tag(input, char!('a'), 1), Ok((1, input.advance(1))));
 /// ```
-pub fn tag<T, U>(
-    input: &str,
-    parser: ParseFn<T>,
+pub fn tag<'a, T, U>(
+    input: Input<'a>,
+    parser: ParseFn<'a, T>,
     value: U,
-) -> ParseResult<U> {
+) -> ParseResult<'a, U> {
     parser(input).map(|(_, input)| (value, input))
 }
 
@@ -142,7 +166,7 @@ pub fn tag<T, U>(
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `open` - The string to parse before the expression.
 /// * `parser` - The parser to apply to the expression.
 /// * `close` - The string to parse after the expression.
@@ -162,25 +186,26 @@ pub fn tag<T, U>(
 /// use parser::{
 ///     between,
 ///     char,
+///     Input,
 ///     ParseError,
 /// };
 ///
-/// let input = "foo";
+/// let input = Input::new("foo");
 ///
 /// assert_eq!(
 ///     between(input, "f", |input| char(input, 'o'), "o"),
-///     Ok(('o', String::new())),
+///     Ok(('o', input.advance(3))),
 /// );
 /// ```
-pub fn between<T>(
-    input: &str,
+pub fn between<'a, T>(
+    input: Input<'a>,
     open: &str,
-    parser: ParseFn<T>,
+    parser: ParseFn<'a, T>,
     close: &str,
-) -> ParseResult<T> {
+) -> ParseResult<'a, T> {
     let (_, input) = literal(input, open)?;
-    let (t, input) = parser(&input)?;
-    let (_, input) = literal(&input, close)?;
+    let (t, input) = parser(input)?;
+    let (_, input) = literal(input, close)?;
 
     Ok((t, input))
 }
@@ -189,7 +214,7 @@ pub fn between<T>(
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `char` - The character to parse.
 ///
 /// # Errors
@@ -203,48 +228,58 @@ pub fn between<T>(
 /// ```rust
 /// use parser::{
 ///     char,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(char("a", 'a'), Ok(('a', String::new())));
-/// assert_eq!(char("a", 'a'), Ok(('a', "".to_owned())));
+/// let input = Input::new("a");
+///
+/// assert_eq!(char(input, 'a'), Ok(('a', input.advance(1))));
 ///
 /// assert_eq!(
-///     char("a", 'b'),
+///     char(input, 'b'),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected character 'b', found 'a'.".to_owned(),
-///         actual: 'a'
+///         actual: 'a',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 ///
-/// assert_eq!(char("", 'b'), Err(ParseError::UnexpectedEof));
+/// assert_eq!(
+///     char(Input::new(""), 'b'),
+///     Err(ParseError::UnexpectedEof { span: Span::at(0) })
+/// );
 /// ```
 pub fn char(
-    input: &str,
+    input: Input<'_>,
     expected: char,
-) -> ParseResult<char> {
-    input
-        .chars()
-        .next()
-        .map_or(Err(ParseError::UnexpectedEof), |actual| {
+) -> ParseResult<'_, char> {
+    input.peek().map_or_else(
+        || Err(ParseError::unexpected_eof(input.span())),
+        |actual| {
+            let len = actual.len_utf8();
+
             if actual == expected {
-                Ok((expected, input[1..].to_string()))
+                Ok((expected, input.advance(len)))
             } else {
-                Err(ParseError::UnexpectedChar {
-                    message: format!(
+                Err(ParseError::unexpected_char(
+                    actual,
+                    format!(
                         "Expected character '{expected}', found '{actual}'."
                     ),
-                    actual,
-                })
+                    input.span_to(input.advance(len)),
+                ))
             }
-        })
+        },
+    )
 }
 
 /// Parse the specified string literal.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `literal` - The literal to parse.
 ///
 /// # Errors
@@ -258,47 +293,55 @@ pub fn char(
 /// ```rust
 /// use parser::{
 ///     literal,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
-/// assert_eq!(literal("foo", "foo"), Ok(("foo".to_owned(), String::new())));
-/// assert_eq!(
-///     literal("foobar", "foo"),
-///     Ok(("foo".to_owned(), "bar".to_owned()))
-/// );
+/// let input = Input::new("foo");
+///
+/// assert_eq!(literal(input, "foo"), Ok(("foo", input.advance(3))));
+///
+/// let input = Input::new("foobar");
+///
+/// assert_eq!(literal(input, "foo"), Ok(("foo", input.advance(3))));
 ///
 /// assert_eq!(
-///     literal("foo", "bar"),
+///     literal(Input::new("foo"), "bar"),
 ///     Err(ParseError::UnmatchedLiteral {
-///         expected: "bar".to_owned()
+///         expected: "bar".to_owned(),
+///         span: Span::at(0),
 ///     })
 /// );
 ///
 /// assert_eq!(
-///     literal("bbar", "bar"),
+///     literal(Input::new("bbar"), "bar"),
 ///     Err(ParseError::UnmatchedLiteral {
-///         expected: "bar".to_owned()
+///         expected: "bar".to_owned(),
+///         span: Span::at(0),
 ///     })
 /// );
 ///
-/// assert_eq!(literal("", "bar"), Err(ParseError::UnexpectedEof));
+/// assert_eq!(
+///     literal(Input::new(""), "bar"),
+///     Err(ParseError::UnexpectedEof { span: Span::at(0) })
+/// );
 /// ```
-pub fn literal(
-    input: &str,
+pub fn literal<'a>(
+    input: Input<'a>,
     literal: &str,
-) -> ParseResult<String> {
+) -> ParseResult<'a, &'a str> {
     if input.is_empty() {
-        return Err(ParseError::UnexpectedEof);
+        return Err(ParseError::unexpected_eof(input.span()));
     }
 
-    input.strip_prefix(literal).map_or_else(
-        || {
-            Err(ParseError::UnmatchedLiteral {
-                expected: literal.to_owned(),
-            })
-        },
-        |input| Ok((literal.to_owned(), input.to_owned())),
-    )
+    if input.rest().starts_with(literal) {
+        let end = input.advance(literal.len());
+
+        Ok((input.slice_to(end), end))
+    } else {
+        Err(ParseError::unmatched_literal(literal, input.span()))
+    }
 }
 
 /// Apply a parser zero or more times until it fails, returning a vector of the
@@ -306,7 +349,7 @@ pub fn literal(
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `parser` - The parser to apply.
 ///
 /// # Errors
@@ -319,25 +362,39 @@ pub fn literal(
 /// use parser::{
 ///     lowercase,
 ///     many,
+///     Input,
 /// };
 ///
+/// let input = Input::new("abc");
+///
+/// assert_eq!(
+///     many(input, lowercase),
+///     Ok((vec!['a', 'b', 'c'], input.advance(3)))
+/// );
+///
+/// let input = Input::new("ab3");
+///
 /// assert_eq!(
-///     many("abc", lowercase),
-///     Ok((vec!['a', 'b', 'c'], String::new()))
+///     many(input, lowercase),
+///     Ok((vec!['a', 'b'], input.advance(2)))
 /// );
 ///
-/// assert_eq!(many("ab3", lowercase), Ok((vec!['a', 'b'], "3".to_owned())));
-/// assert_eq!(many("a23", lowercase), Ok((vec!['a'], "23".to_owned())));
-/// assert_eq!(many("123", lowercase), Ok((Vec::new(), "123".to_owned())));
+/// let input = Input::new("a23");
+///
+/// assert_eq!(many(input, lowercase), Ok((vec!['a'], input.advance(1))));
+///
+/// let input = Input::new("123");
+///
+/// assert_eq!(many(input, lowercase), Ok((Vec::new(), input)));
 /// ```
-pub fn many<T>(
-    input: &str,
-    parser: ParseFn<T>,
-) -> ParseResult<Vec<T>> {
-    let mut input = input.to_owned();
+pub fn many<'a, T>(
+    input: Input<'a>,
+    parser: ParseFn<'a, T>,
+) -> ParseResult<'a, Vec<T>> {
+    let mut input = input;
     let mut result = Vec::new();
 
-    while let Ok((value, new_input)) = parser(&input) {
+    while let Ok((value, new_input)) = parser(input) {
         result.push(value);
         input = new_input;
     }
@@ -350,7 +407,7 @@ pub fn many<T>(
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `parser` - The parser to apply.
 ///
 /// # Errors
@@ -363,43 +420,53 @@ pub fn many<T>(
 /// use parser::{
 ///     lowercase,
 ///     many1,
+///     Input,
 ///     ParseError,
 /// };
 ///
+/// let input = Input::new("abc");
+///
 /// assert_eq!(
-///     many1("abc", lowercase),
-///     Ok((vec!['a', 'b', 'c'], String::new()))
+///     many1(input, lowercase),
+///     Ok((vec!['a', 'b', 'c'], input.advance(3)))
 /// );
 ///
+/// let input = Input::new("ab3");
+///
 /// assert_eq!(
-///     many1("ab3", lowercase),
-///     Ok((vec!['a', 'b'], "3".to_owned()))
+///     many1(input, lowercase),
+///     Ok((vec!['a', 'b'], input.advance(2)))
 /// );
 ///
-/// assert_eq!(many1("a23", lowercase), Ok((vec!['a'], "23".to_owned())));
+/// let input = Input::new("a23");
+///
+/// assert_eq!(many1(input, lowercase), Ok((vec!['a'], input.advance(1))));
 /// ```
 ///
 /// ```rust
 /// use parser::{
 ///     lowercase,
 ///     many1,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
 /// assert_eq!(
-///     many1("123", lowercase),
+///     many1(Input::new("123"), lowercase),
 ///     Err(ParseError::UnexpectedChar {
 ///         message: "Expected lowercase character.".to_owned(),
 ///         actual: '1',
+///         span: Span::new(0, 1),
 ///     })
 /// );
 /// ```
-pub fn many1<T>(
-    input: &str,
-    parser: ParseFn<T>,
-) -> ParseResult<Vec<T>> {
+pub fn many1<'a, T>(
+    input: Input<'a>,
+    parser: ParseFn<'a, T>,
+) -> ParseResult<'a, Vec<T>> {
     let (head, input) = parser(input)?;
-    let (mut tail, input) = many(&input, parser)?;
+    let (mut tail, input) = many(input, parser)?;
 
     tail.insert(0, head);
 
@@ -410,7 +477,7 @@ pub fn many1<T>(
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 /// * `parsers` - The parsers to apply.
 ///
 /// # Errors
@@ -424,7 +491,9 @@ pub fn many1<T>(
 ///     choice,
 ///     literal,
 ///     tag,
+///     Input,
 ///     ParseError,
+///     Span,
 /// };
 ///
 /// #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -433,41 +502,45 @@ pub fn many1<T>(
 ///     B,
 /// }
 ///
+/// let input = Input::new("abc");
+///
 /// assert_eq!(
 ///     choice(
-///         "abc",
-///         vec![
+///         input,
+///         &[
 ///             tag!(literal!("abc"), Choice::A),
 ///             tag!(literal!("abc"), Choice::B),
 ///         ]
 ///     ),
-///     Ok((Choice::A, String::new())),
+///     Ok((Choice::A, input.advance(3))),
 /// );
 ///
 /// assert_eq!(
 ///     choice(
-///         "abc",
-///         vec![
+///         input,
+///         &[
 ///             tag!(literal!("abc"), Choice::B),
 ///             tag!(literal!("abc"), Choice::A),
 ///         ]
 ///     ),
-///     Ok((Choice::B, String::new())),
+///     Ok((Choice::B, input.advance(3))),
 /// );
 ///
 /// assert_eq!(
-///     choice("abc", vec![tag!(literal!("def"), Choice::A)]),
+///     choice(input, &[tag!(literal!("def"), Choice::A)]),
 ///     Err(ParseError::UnmatchedChoice {
 ///         errors: vec![ParseError::UnmatchedLiteral {
 ///             expected: "def".to_owned(),
-///         }]
+///             span: Span::at(0),
+///         }],
+///         span: Span::at(0),
 ///     })
 /// );
 /// ```
-pub fn choice<T>(
-    input: &str,
-    parsers: Vec<ParseFn<T>>,
-) -> ParseResult<T> {
+pub fn choice<'a, T>(
+    input: Input<'a>,
+    parsers: &[ParseFn<'a, T>],
+) -> ParseResult<'a, T> {
     let mut errors = Vec::new();
 
     for parser in parsers {
@@ -477,14 +550,14 @@ pub fn choice<T>(
         }
     }
 
-    Err(ParseError::UnmatchedChoice { errors })
+    Err(ParseError::unmatched_choice(errors, input.span()))
 }
 
 /// Optionally apply a parser.
 ///
 /// # Arguments
 ///
-/// * `input` - The input string to parse.
+/// * `input` - The input to parse.
 ///
 /// # Errors
 ///
@@ -496,25 +569,30 @@ pub fn choice<T>(
 /// use parser::{
 ///     literal,
 ///     option,
+///     Input,
 ///     ParseError,
 /// };
 ///
+/// let input = Input::new("abc");
+///
 /// assert_eq!(
-///     option("abc", |input| literal(input, "abc")),
-///     Ok((Some("abc".to_owned()), String::new())),
+///     option(input, |input| literal(input, "abc")),
+///     Ok((Some("abc"), input.advance(3))),
 /// );
 ///
+/// let input = Input::new("def");
+///
 /// assert_eq!(
-///     option("def", |input| literal(input, "abc")),
-///     Ok((None, "def".to_owned())),
+///     option(input, |input| literal(input, "abc")),
+///     Ok((None, input)),
 /// );
 /// ```
-pub fn option<T>(
-    input: &str,
-    parser: ParseFn<T>,
-) -> ParseResult<Option<T>> {
+pub fn option<'a, T>(
+    input: Input<'a>,
+    parser: ParseFn<'a, T>,
+) -> ParseResult<'a, Option<T>> {
     match parser(input) {
         Ok((value, input)) => Ok((Some(value), input)),
-        Err(_) => Ok((None, input.to_owned())),
+        Err(_) => Ok((None, input)),
     }
 }
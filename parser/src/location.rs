@@ -0,0 +1,117 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+/// A human-readable position in the source of a parser.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Location {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// The column number, starting at 1. Counted in characters.
+    pub column: usize,
+}
+
+impl Location {
+    /// Find the line and column of a byte offset in the given source.
+    ///
+    /// Offsets beyond the end of the source are clamped to the end of the
+    /// source.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source the offset points into.
+    /// * `offset` - The byte offset to locate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Location;
+    ///
+    /// let source = "model Foo {\n  bar: String\n}";
+    ///
+    /// assert_eq!(
+    ///     Location::from_offset(source, 0),
+    ///     Location { line: 1, column: 1 }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Location::from_offset(source, 14),
+    ///     Location { line: 2, column: 3 }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Location::from_offset(source, source.len()),
+    ///     Location { line: 3, column: 2 }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_offset(
+        source: &str,
+        offset: usize,
+    ) -> Self {
+        let mut offset = offset.min(source.len());
+
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let Self { line, column } = self;
+
+        write!(f, "{line}:{column}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Location {
+                line: 3,
+                column: 14
+            }
+            .to_string(),
+            "3:14"
+        );
+    }
+
+    #[test]
+    fn test_from_offset_out_of_bounds() {
+        assert_eq!(
+            Location::from_offset("foo\nbar", 100),
+            Location { line: 2, column: 4 }
+        );
+    }
+
+    #[test]
+    fn test_from_offset_multibyte() {
+        assert_eq!(
+            Location::from_offset("é\nöa", 5),
+            Location { line: 2, column: 2 }
+        );
+
+        assert_eq!(
+            Location::from_offset("é\nöa", 4),
+            Location { line: 2, column: 1 }
+        );
+    }
+}
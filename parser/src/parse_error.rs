@@ -1,9 +1,12 @@
-use std::{
-    error::Error,
-    fmt::{
-        self,
-        Display,
-        Formatter,
+use {
+    super::Span,
+    std::{
+        error::Error,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
     },
 };
 
@@ -14,9 +17,14 @@ pub enum ParseError {
     Custom {
         /// A description of the error.
         message: String,
+        /// The part of the source that the error refers to.
+        span: Span,
     },
     /// Unexpected end of file.
-    UnexpectedEof,
+    UnexpectedEof {
+        /// The end of the source.
+        span: Span,
+    },
     /// The first character of the input can not be parsed.
     ///
     /// aka `ParseError::DaBoiIsNotHappyWithThisOne` -- @Fecony
@@ -25,16 +33,22 @@ pub enum ParseError {
         actual: char,
         /// A description of the predicate.
         message: String,
+        /// The span of the character that is found.
+        span: Span,
     },
     /// None of the given parsers matches the input.
     UnmatchedChoice {
         /// The errors generated by each parser.
         errors: Vec<ParseError>,
+        /// The position at which the parsers were applied.
+        span: Span,
     },
     /// The input does not match the given string literal.
     UnmatchedLiteral {
         /// The expected string literal.
         expected: String,
+        /// The position at which the literal was expected.
+        span: Span,
     },
 }
 
@@ -44,26 +58,43 @@ impl ParseError {
     /// # Arguments
     ///
     /// * `message` - A description of the error.
+    /// * `span` - The part of the source that the error refers to.
     #[must_use]
-    pub fn custom<S>(message: S) -> Self
+    pub fn custom<S>(
+        message: S,
+        span: Span,
+    ) -> Self
     where
         S: Into<String>,
     {
         Self::Custom {
             message: message.into(),
+            span,
         }
     }
 
+    /// Create a new `UnexpectedEof` error.
+    ///
+    /// # Arguments
+    ///
+    /// * `span` - The end of the source.
+    #[must_use]
+    pub const fn unexpected_eof(span: Span) -> Self {
+        Self::UnexpectedEof { span }
+    }
+
     /// Create a new `UnexpectedChar` error.
     ///
     /// # Arguments
     ///
     /// * `actual` - The character that is found.
     /// * `message` - A description of the predicate.
+    /// * `span` - The span of the character that is found.
     #[must_use]
     pub fn unexpected_char<S>(
         actual: char,
         message: S,
+        span: Span,
     ) -> Self
     where
         S: Into<String>,
@@ -71,6 +102,7 @@ impl ParseError {
         Self::UnexpectedChar {
             actual,
             message: message.into(),
+            span,
         }
     }
 
@@ -79,13 +111,18 @@ impl ParseError {
     /// # Arguments
     ///
     /// * `errors` - The errors generated by each parser.
+    /// * `span` - The position at which the parsers were applied.
     #[must_use]
-    pub fn unmatched_choice<T>(errors: T) -> Self
+    pub fn unmatched_choice<T>(
+        errors: T,
+        span: Span,
+    ) -> Self
     where
         T: Into<Vec<Self>>,
     {
         Self::UnmatchedChoice {
             errors: errors.into(),
+            span,
         }
     }
 
@@ -94,13 +131,30 @@ impl ParseError {
     /// # Arguments
     ///
     /// * `expected` - The expected string literal.
+    /// * `span` - The position at which the literal was expected.
     #[must_use]
-    pub fn unmatched_literal<S>(expected: S) -> Self
+    pub fn unmatched_literal<S>(
+        expected: S,
+        span: Span,
+    ) -> Self
     where
         S: Into<String>,
     {
         Self::UnmatchedLiteral {
             expected: expected.into(),
+            span,
+        }
+    }
+
+    /// The part of the source that the error refers to.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::Custom { span, .. }
+            | Self::UnexpectedEof { span }
+            | Self::UnexpectedChar { span, .. }
+            | Self::UnmatchedChoice { span, .. }
+            | Self::UnmatchedLiteral { span, .. } => *span,
         }
     }
 }
@@ -111,14 +165,16 @@ impl Display for ParseError {
         f: &mut Formatter<'_>,
     ) -> fmt::Result {
         match self {
-            Self::Custom { message } => {
+            Self::Custom { message, .. } => {
                 write!(f, "{message}")
             }
-            Self::UnexpectedEof => write!(f, "Unexpected end of file"),
-            Self::UnexpectedChar { actual, message } => {
+            Self::UnexpectedEof { .. } => write!(f, "Unexpected end of file"),
+            Self::UnexpectedChar {
+                actual, message, ..
+            } => {
                 write!(f, "Unexpected character '{actual}': {message}")
             }
-            Self::UnmatchedChoice { errors } => {
+            Self::UnmatchedChoice { errors, .. } => {
                 let error_count = errors.len();
 
                 writeln!(f, "Unmatched choice:")?;
@@ -135,7 +191,7 @@ impl Display for ParseError {
 
                 Ok(())
             }
-            Self::UnmatchedLiteral { expected } => {
+            Self::UnmatchedLiteral { expected, .. } => {
                 write!(f, "Unmatched literal: {expected}")
             }
         }
@@ -146,10 +202,10 @@ impl Error for ParseError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::UnexpectedChar { .. }
-            | Self::UnexpectedEof
+            | Self::UnexpectedEof { .. }
             | Self::Custom { .. }
             | Self::UnmatchedLiteral { .. } => None,
-            Self::UnmatchedChoice { errors } => {
+            Self::UnmatchedChoice { errors, .. } => {
                 #[allow(clippy::option_if_let_else)]
                 if let Some(error) = errors.first() {
                     Some(error)
@@ -167,13 +223,13 @@ mod tests {
 
     #[test]
     fn test_display_custom_error() {
-        assert_eq!(ParseError::custom("foo").to_string(), "foo");
+        assert_eq!(ParseError::custom("foo", Span::at(0)).to_string(), "foo");
     }
 
     #[test]
     fn test_display_unexpected_eof_error() {
         assert_eq!(
-            ParseError::UnexpectedEof.to_string(),
+            ParseError::unexpected_eof(Span::at(3)).to_string(),
             "Unexpected end of file"
         );
     }
@@ -181,7 +237,8 @@ mod tests {
     #[test]
     fn test_display_unexpected_char_error() {
         assert_eq!(
-            ParseError::unexpected_char('a', "foo").to_string(),
+            ParseError::unexpected_char('a', "foo", Span::new(0, 1))
+                .to_string(),
             "Unexpected character 'a': foo"
         );
     }
@@ -189,10 +246,13 @@ mod tests {
     #[test]
     fn test_display_unmatched_choice_error() {
         assert_eq!(
-            ParseError::unmatched_choice([
-                ParseError::custom("foo"),
-                ParseError::UnexpectedEof,
-            ])
+            ParseError::unmatched_choice(
+                [
+                    ParseError::custom("foo", Span::at(0)),
+                    ParseError::unexpected_eof(Span::at(3)),
+                ],
+                Span::at(0)
+            )
             .to_string(),
             "
             
@@ -208,38 +268,45 @@ Unmatched choice:
     #[test]
     fn test_display_unmatched_literal_error() {
         assert_eq!(
-            ParseError::unmatched_literal("foo").to_string(),
+            ParseError::unmatched_literal("foo", Span::at(0)).to_string(),
             "Unmatched literal: foo"
         );
     }
 
     #[test]
     fn test_source_unexpected_char_error() {
-        assert!(ParseError::unexpected_char('a', "foo").source().is_none());
+        assert!(ParseError::unexpected_char('a', "foo", Span::new(0, 1))
+            .source()
+            .is_none());
     }
 
     #[test]
     fn test_source_unexpected_eof_error() {
-        assert!(ParseError::UnexpectedEof.source().is_none());
+        assert!(ParseError::unexpected_eof(Span::at(3)).source().is_none());
     }
 
     #[test]
     fn test_source_custom_error() {
-        assert!(ParseError::custom("foo").source().is_none());
+        assert!(ParseError::custom("foo", Span::at(0)).source().is_none());
     }
 
     #[test]
     fn test_source_unmatched_literal_error() {
-        assert!(ParseError::unmatched_literal("foo").source().is_none());
+        assert!(ParseError::unmatched_literal("foo", Span::at(0))
+            .source()
+            .is_none());
     }
 
     #[test]
     fn test_source_unmatched_choice_error() {
         assert_eq!(
-            ParseError::unmatched_choice([
-                ParseError::custom("foo"),
-                ParseError::UnexpectedEof,
-            ])
+            ParseError::unmatched_choice(
+                [
+                    ParseError::custom("foo", Span::at(0)),
+                    ParseError::unexpected_eof(Span::at(3)),
+                ],
+                Span::at(0)
+            )
             .source()
             .unwrap()
             .to_string(),
@@ -249,6 +316,33 @@ Unmatched choice:
 
     #[test]
     fn test_source_unmatched_choice_error_empty() {
-        assert!(ParseError::unmatched_choice([]).source().is_none());
+        assert!(ParseError::unmatched_choice([], Span::at(0))
+            .source()
+            .is_none());
+    }
+
+    #[test]
+    fn test_span() {
+        assert_eq!(
+            ParseError::custom("foo", Span::new(1, 4)).span(),
+            Span::new(1, 4)
+        );
+
+        assert_eq!(ParseError::unexpected_eof(Span::at(3)).span(), Span::at(3));
+
+        assert_eq!(
+            ParseError::unexpected_char('a', "foo", Span::new(2, 3)).span(),
+            Span::new(2, 3)
+        );
+
+        assert_eq!(
+            ParseError::unmatched_choice([], Span::at(5)).span(),
+            Span::at(5)
+        );
+
+        assert_eq!(
+            ParseError::unmatched_literal("foo", Span::at(6)).span(),
+            Span::at(6)
+        );
     }
 }
@@ -0,0 +1,115 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+/// A range of byte offsets into the source of a parser.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Span {
+    /// The offset of the first byte of the span.
+    pub start: usize,
+    /// The offset of the first byte after the span.
+    pub end: usize,
+}
+
+impl Span {
+    /// Create a new span.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The offset of the first byte of the span.
+    /// * `end` - The offset of the first byte after the span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Span;
+    ///
+    /// let span = Span::new(3, 7);
+    ///
+    /// assert_eq!(span.start, 3);
+    /// assert_eq!(span.end, 7);
+    /// ```
+    #[must_use]
+    pub const fn new(
+        start: usize,
+        end: usize,
+    ) -> Self {
+        Self { start, end }
+    }
+
+    /// Create an empty span at the given offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset of the span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Span;
+    ///
+    /// assert_eq!(Span::at(3), Span::new(3, 3));
+    /// ```
+    #[must_use]
+    pub const fn at(offset: usize) -> Self {
+        Self::new(offset, offset)
+    }
+
+    /// The number of bytes covered by the span.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Span;
+    ///
+    /// assert_eq!(Span::new(3, 7).len(), 4);
+    /// assert_eq!(Span::at(3).len(), 0);
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Check if the span is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parser::Span;
+    ///
+    /// assert!(Span::at(3).is_empty());
+    /// assert!(!Span::new(3, 7).is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Display for Span {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        let Self { start, end } = self;
+
+        write!(f, "{start}..{end}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Span::new(3, 7).to_string(), "3..7");
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Span::default(), Span::at(0));
+    }
+}
@@ -51,6 +51,23 @@ impl Print for Block<'_> {
     }
 }
 
+impl<'a> From<ir::model::Index<'a>> for Block<'a> {
+    fn from(index: ir::model::Index<'a>) -> Self {
+        let ir::model::Index { fields, unique } = index;
+
+        Self {
+            group: None,
+            name: if unique { "unique" } else { "index" }.into(),
+            arguments: vec![Argument {
+                name: None,
+                value: Value::Array(
+                    fields.into_iter().map(Value::Keyword).collect(),
+                ),
+            }],
+        }
+    }
+}
+
 /// A field attribute.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Field<'a> {
@@ -114,6 +131,65 @@ impl<'a> Field<'a> {
         }
     }
 
+    /// Standard `@default` attribute with the given value.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The default value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     print::PrintInline,
+    ///     prisma::{
+    ///         attribute::Field,
+    ///         Value,
+    ///     },
+    /// };
+    ///
+    /// let attribute = Field::default_value(Value::Number("0".into()));
+    /// let mut f = Vec::new();
+    ///
+    /// attribute.print(&mut f).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(f).unwrap(), " @default(0)");
+    /// ```
+    #[must_use]
+    pub fn default_value(value: Value<'a>) -> Self {
+        Self {
+            group: None,
+            name: "default".into(),
+            arguments: vec![Argument { name: None, value }],
+        }
+    }
+
+    /// Standard `@updatedAt` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     print::PrintInline,
+    ///     prisma::attribute::Field,
+    /// };
+    ///
+    /// let attribute = Field::updated_at();
+    /// let mut f = Vec::new();
+    ///
+    /// attribute.print(&mut f).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(f).unwrap(), " @updatedAt");
+    /// ```
+    #[must_use]
+    pub fn updated_at() -> Self {
+        Self {
+            group: None,
+            name: "updatedAt".into(),
+            arguments: Vec::new(),
+        }
+    }
+
     /// Standard `@default(autoincrement())` attribute.
     ///
     /// # Examples
@@ -146,6 +222,70 @@ impl<'a> Field<'a> {
         }
     }
 
+    /// Standard `@default(cuid())` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     print::PrintInline,
+    ///     prisma::attribute::Field,
+    /// };
+    ///
+    /// let attribute = Field::default_cuid();
+    /// let mut f = Vec::new();
+    ///
+    /// attribute.print(&mut f).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(f).unwrap(), " @default(cuid())");
+    /// ```
+    #[must_use]
+    pub fn default_cuid() -> Self {
+        Self {
+            group: None,
+            name: "default".into(),
+            arguments: vec![Argument {
+                name: None,
+                value: Value::Function(Function {
+                    name: "cuid".into(),
+                    parameters: Vec::new(),
+                }),
+            }],
+        }
+    }
+
+    /// Standard `@default(uuid())` attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use {
+    ///     print::PrintInline,
+    ///     prisma::attribute::Field,
+    /// };
+    ///
+    /// let attribute = Field::default_uuid();
+    /// let mut f = Vec::new();
+    ///
+    /// attribute.print(&mut f).unwrap();
+    ///
+    /// assert_eq!(String::from_utf8(f).unwrap(), " @default(uuid())");
+    /// ```
+    #[must_use]
+    pub fn default_uuid() -> Self {
+        Self {
+            group: None,
+            name: "default".into(),
+            arguments: vec![Argument {
+                name: None,
+                value: Value::Function(Function {
+                    name: "uuid".into(),
+                    parameters: Vec::new(),
+                }),
+            }],
+        }
+    }
+
     /// Standard `@default(now)` attribute.
     ///
     /// # Examples
@@ -179,6 +319,33 @@ impl<'a> Field<'a> {
     }
 }
 
+impl<'a> From<ir::model::NativeType<'a>> for Field<'a> {
+    fn from(native_type: ir::model::NativeType<'a>) -> Self {
+        let ir::model::NativeType { name, arguments } = native_type;
+
+        Self {
+            group: Some("db".into()),
+            name,
+            arguments: arguments
+                .into_iter()
+                .map(|argument| {
+                    Argument {
+                        name: None,
+                        value: if argument
+                            .bytes()
+                            .all(|byte| byte.is_ascii_digit())
+                        {
+                            Value::Number(argument)
+                        } else {
+                            Value::Keyword(argument)
+                        },
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
 impl PrintInline for Field<'_> {
     fn print(
         &self,
@@ -307,4 +474,44 @@ mod tests {
             " @foo(foo: bar, baz, qux())"
         );
     }
+
+    #[test]
+    fn test_from_ir_native_type() {
+        let print = |name: &'static str, arguments: &[&'static str]| {
+            let mut f = Vec::new();
+
+            Field::from(ir::model::NativeType {
+                name: name.into(),
+                arguments: arguments
+                    .iter()
+                    .map(|&argument| argument.into())
+                    .collect(),
+            })
+            .print(&mut f)
+            .unwrap();
+
+            String::from_utf8(f).unwrap()
+        };
+
+        assert_eq!(print("Text", &[]), " @db.Text");
+        assert_eq!(print("Decimal", &["10", "2"]), " @db.Decimal(10, 2)");
+        assert_eq!(print("VarChar", &["Max"]), " @db.VarChar(Max)");
+    }
+
+    #[test]
+    fn test_from_ir_index() {
+        let block = Block::from(ir::model::Index {
+            fields: vec!["title".into(), "createdAt".into()],
+            unique: true,
+        });
+
+        let mut f = Vec::new();
+
+        block.print(1, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "  @@unique([title, createdAt])\n"
+        );
+    }
 }
@@ -13,6 +13,15 @@ pub enum RelationMode {
     Prisma,
 }
 
+impl From<ir::database::RelationMode> for RelationMode {
+    fn from(value: ir::database::RelationMode) -> Self {
+        match value {
+            ir::database::RelationMode::ForeignKeys => Self::ForeignKeys,
+            ir::database::RelationMode::Prisma => Self::Prisma,
+        }
+    }
+}
+
 impl PrintInline for RelationMode {
     fn print(
         &self,
@@ -0,0 +1,87 @@
+use {
+    print::PrintInline,
+    std::{
+        borrow::Cow,
+        fmt::{
+            self,
+            Display,
+            Formatter,
+        },
+        io,
+    },
+};
+
+/// Escape the backslashes and double quotes in a string, so that it can be
+/// printed between double quotes.
+///
+/// # Arguments
+///
+/// * `value` - The string to escape.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// A connection URL.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Url<'a> {
+    /// The URL is read from the environment variable with the given name.
+    Env(Cow<'a, str>),
+    /// A literal URL. Backslashes and double quotes are escaped when it is
+    /// printed.
+    Literal(Cow<'a, str>),
+}
+
+impl Display for Url<'_> {
+    fn fmt(
+        &self,
+        f: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Env(name) => write!(f, "env(\"{name}\")"),
+            Self::Literal(url) => write!(f, "\"{}\"", escape(url)),
+        }
+    }
+}
+
+impl PrintInline for Url<'_> {
+    fn print(
+        &self,
+        f: &mut dyn io::Write,
+    ) -> io::Result<()> {
+        write!(f, "{self}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_env() {
+        let mut f = Vec::new();
+
+        Url::Env("DATABASE_URL".into()).print(&mut f).unwrap();
+
+        assert_eq!(f, b"env(\"DATABASE_URL\")");
+    }
+
+    #[test]
+    fn test_print_literal() {
+        let mut f = Vec::new();
+
+        Url::Literal("file:./dev.db".into()).print(&mut f).unwrap();
+
+        assert_eq!(f, b"\"file:./dev.db\"");
+    }
+
+    #[test]
+    fn test_print_escaped_literal() {
+        let mut f = Vec::new();
+
+        Url::Literal(r#"file:C:\dev "db".db"#.into())
+            .print(&mut f)
+            .unwrap();
+
+        assert_eq!(f, br#""file:C:\\dev \"db\".db""#);
+    }
+}
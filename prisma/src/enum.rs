@@ -1,6 +1,10 @@
 use {
-    super::attribute::Block,
+    super::{
+        attribute::Block,
+        print_doc_comment,
+    },
     ir,
+    ord_str_map::OrdStrMap,
     print::Print,
     std::{
         borrow::Cow,
@@ -18,6 +22,10 @@ pub struct Enum<'a> {
     pub values: TokenSet,
     /// The attributes of the enum.
     pub attributes: Vec<Block<'a>>,
+    /// The documentation of the enum.
+    pub doc: Option<Cow<'a, str>>,
+    /// The documentation of the values of the enum, by value.
+    pub value_docs: OrdStrMap<Cow<'a, str>>,
 }
 
 impl Print for Enum<'_> {
@@ -32,14 +40,24 @@ impl Print for Enum<'_> {
             attributes,
             name,
             values,
+            doc,
+            value_docs,
         } = self;
 
         let indent_outer = Self::indent(level);
         let indent_inner = Self::indent(level + 1);
 
+        if let Some(doc) = doc {
+            print_doc_comment(doc, &indent_outer, f)?;
+        }
+
         writeln!(f, "{indent_outer}enum {name} {{")?;
 
         for value in values.iter() {
+            if let Some(doc) = value_docs.get(value) {
+                print_doc_comment(doc, &indent_inner, f)?;
+            }
+
             writeln!(f, "{indent_inner}{value}")?;
         }
 
@@ -56,11 +74,20 @@ impl Print for Enum<'_> {
 }
 
 impl<'a> From<ir::Enum<'a>> for Enum<'a> {
-    fn from(ir::Enum { name, values }: ir::Enum<'a>) -> Self {
+    fn from(
+        ir::Enum {
+            name,
+            values,
+            doc,
+            value_docs,
+        }: ir::Enum<'a>
+    ) -> Self {
         Self {
             name: name.clone(),
             values,
             attributes: Vec::new(),
+            doc,
+            value_docs,
         }
     }
 }
@@ -88,6 +115,8 @@ mod tests {
                 }],
                 group: None,
             }],
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         let mut f = Vec::new();
@@ -107,11 +136,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_print_doc_comments() {
+        let r#enum = Enum {
+            name: "Color".into(),
+            values: TokenSet::from_iter(["Red", "Green"]),
+            attributes: Vec::new(),
+            doc: Some("A primary color.".into()),
+            value_docs: OrdStrMap::from_iter([("Red", "The default.".into())]),
+        };
+
+        let mut f = Vec::new();
+
+        r#enum.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "/// A primary color.
+enum Color {
+  /// The default.
+  Red
+  Green
+}
+"
+        );
+    }
+
     #[test]
     fn test_from_ir_enum() {
         let ir_enum = ir::Enum {
             name: "Color".into(),
             values: TokenSet::from_iter(["Red", "Green", "Blue"]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         let r#enum = Enum::from(ir_enum);
@@ -143,6 +200,8 @@ mod tests {
                 }],
                 group: None,
             }],
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         let mut f = Vec::new();
@@ -64,6 +64,38 @@ impl Display for PreviewFeature {
     }
 }
 
+impl From<ir::database::PreviewFeature> for PreviewFeature {
+    fn from(value: ir::database::PreviewFeature) -> Self {
+        match value {
+            ir::database::PreviewFeature::ClientExtensions => {
+                Self::ClientExtensions
+            }
+            ir::database::PreviewFeature::Deno => Self::Deno,
+            ir::database::PreviewFeature::ExtendedWhereUnique => {
+                Self::ExtendedWhereUnique
+            }
+            ir::database::PreviewFeature::FieldReference => {
+                Self::FieldReference
+            }
+            ir::database::PreviewFeature::FilteredRelationCount => {
+                Self::FilteredRelationCount
+            }
+            ir::database::PreviewFeature::FullTextIndex => Self::FullTextIndex,
+            ir::database::PreviewFeature::FullTextSearch => {
+                Self::FullTextSearch
+            }
+            ir::database::PreviewFeature::Metrics => Self::Metrics,
+            ir::database::PreviewFeature::MultiSchema => Self::MultiSchema,
+            ir::database::PreviewFeature::OrderByNulls => Self::OrderByNulls,
+            ir::database::PreviewFeature::PostgresqlExtensions => {
+                Self::PostgresqlExtensions
+            }
+            ir::database::PreviewFeature::Tracing => Self::Tracing,
+            ir::database::PreviewFeature::Views => Self::Views,
+        }
+    }
+}
+
 impl PrintInline for PreviewFeature {
     fn print(
         &self,
@@ -2,14 +2,14 @@ pub use field::Field;
 use {
     crate::{
         attribute,
+        foreign_key_name,
+        print_doc_comment,
+        referential_action_arguments,
         schema_error::SchemaError,
         Argument,
         Value,
     },
-    ir::{
-        self,
-        Cardinality,
-    },
+    ir,
     ord_str_map::OrdStrMap,
     print::{
         Print,
@@ -35,6 +35,8 @@ pub struct Model<'a> {
     pub fields: OrdStrMap<Field<'a>>,
     /// Block attributes.
     pub attributes: Vec<attribute::Block<'a>>,
+    /// The documentation of the model.
+    pub doc: Option<Cow<'a, str>>,
 }
 
 impl<'a> Model<'a> {
@@ -80,11 +82,16 @@ impl Print for Model<'_> {
             name,
             fields,
             attributes,
+            doc,
         } = self;
 
         let indent_outer = Self::indent(level);
         let indent_inner = Self::indent(level + 1);
 
+        if let Some(doc) = doc {
+            print_doc_comment(doc, &indent_outer, f)?;
+        }
+
         writeln!(f, "{indent_outer}model {name} {{")?;
 
         let mut max_field_type_length = 0;
@@ -102,6 +109,10 @@ impl Print for Model<'_> {
         max_field_name_length += 1;
 
         for (field_name, field) in fields.iter() {
+            if let Some(doc) = &field.doc {
+                print_doc_comment(doc, &indent_inner, f)?;
+            }
+
             write!(f, "{indent_inner}{field_name:<max_field_name_length$}")?;
 
             let mut g = Vec::new();
@@ -137,22 +148,39 @@ impl Print for Model<'_> {
     }
 }
 
-impl<'a> TryFrom<ir::Model<'a>> for Model<'a> {
-    type Error = SchemaError<'a>;
-
+impl<'a> Model<'a> {
+    /// Create a Prisma model from an IR model. The types of foreign keys
+    /// follow the ids of the related models, which are looked up in `models`.
+    /// Related models that are not found are assumed to have the default id.
+    ///
+    /// # Arguments
+    ///
+    /// * `ir_model` - The IR model.
+    /// * `models` - The IR models that the model may be related to.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SchemaError::DuplicateModelField` if a field is defined
+    /// more than once.
     #[allow(clippy::too_many_lines)]
-    fn try_from(ir_model: ir::Model<'a>) -> Result<Self, Self::Error> {
+    pub fn from_ir_model(
+        ir_model: ir::Model<'a>,
+        models: &OrdStrMap<ir::Model<'a>>,
+    ) -> Result<Self, SchemaError<'a>> {
         let name = ir_model.name();
 
         let mut model = Self {
             name: name.clone(),
-            fields: OrdStrMap::from_iter([
-                ("id", Field::id()),
-                ("createdAt", Field::created_at()),
-            ]),
+            fields: OrdStrMap::new(),
             attributes: Vec::new(),
+            doc: ir_model.doc,
         };
 
+        if let ir::model::Id::Generated(strategy) = ir_model.id {
+            let _: Option<Field> =
+                model.fields.insert("id", Field::id(strategy));
+        }
+
         for (name, field) in ir_model.fields {
             if model.fields.insert(name.clone(), field.into()).is_some() {
                 return Err(SchemaError::duplicate_model_field(
@@ -162,18 +190,18 @@ impl<'a> TryFrom<ir::Model<'a>> for Model<'a> {
         }
 
         for (relation_name, enum_relation) in ir_model.enums {
-            let ir::model::EnumRelation { name, cardinality } = enum_relation;
-
-            let modifier = match cardinality {
-                Cardinality::One => field::Modifier::None,
-                Cardinality::Many => field::Modifier::List,
-            };
+            let ir::model::EnumRelation {
+                name,
+                cardinality,
+                doc,
+            } = enum_relation;
 
             let field = Field {
                 r#type: field::Type::Name(name.clone()),
                 name: name.clone(),
-                modifier,
+                modifier: cardinality.into(),
                 attributes: Vec::new(),
+                doc,
             };
 
             model.insert_field(relation_name, field)?;
@@ -192,13 +220,11 @@ impl<'a> TryFrom<ir::Model<'a>> for Model<'a> {
                                 name: "relation".into(),
                                 arguments: vec![Argument {
                                     name: Some("name".into()),
-                                    value: Value::String(
-                                        format!("{relation_name}On{name}")
-                                            .into(),
-                                    ),
+                                    value: Value::String(relation.name.clone()),
                                 }],
                             }
                         }],
+                        doc: relation.doc.clone(),
                     };
 
                     model.insert_field(relation_name, field)?;
@@ -215,74 +241,205 @@ impl<'a> TryFrom<ir::Model<'a>> for Model<'a> {
                                 name: "relation".into(),
                                 arguments: vec![Argument {
                                     name: Some("name".into()),
-                                    value: Value::String(
-                                        format!("{relation_name}On{name}")
-                                            .into(),
-                                    ),
+                                    value: Value::String(relation.name.clone()),
                                 }],
                             }
                         }],
+                        doc: relation.doc.clone(),
                     };
 
                     model.insert_field(relation_name, field)?;
                 }
                 ir::model::model_relation::Type::ManyToOne => {
+                    let foreign_keys = models
+                        .get(&relation.model_name)
+                        .map_or_else(
+                            || {
+                                ir::Model::new(relation.model_name.clone())
+                                    .id_fields()
+                            },
+                            ir::Model::id_fields,
+                        )
+                        .into_iter()
+                        .map(|(id_field_name, r#type)| {
+                            (
+                                foreign_key_name(
+                                    &relation_name,
+                                    &id_field_name,
+                                ),
+                                id_field_name,
+                                r#type,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+
+                    let modifier = if relation.optional {
+                        field::Modifier::Optional
+                    } else {
+                        field::Modifier::None
+                    };
+
                     let field = Field {
                         name: relation_name.clone().into(),
                         r#type: field::Type::Name(relation.model_name.clone()),
-                        modifier: field::Modifier::Optional,
+                        modifier,
                         attributes: vec![{
                             attribute::Field {
                                 group: None,
                                 name: "relation".into(),
-                                arguments: vec![
+                                arguments: [
                                     Argument {
                                         name: Some("name".into()),
                                         value: Value::String(
-                                            format!("{relation_name}On{name}")
-                                                .into(),
+                                            relation.name.clone(),
                                         ),
                                     },
                                     Argument {
                                         name: Some("fields".into()),
-                                        value: Value::Array(vec![
-                                            Value::Keyword(
-                                                format!("{relation_name}Id")
-                                                    .into(),
-                                            ),
-                                        ]),
+                                        value: Value::Array(
+                                            foreign_keys
+                                                .iter()
+                                                .map(|(field_name, _, _)| {
+                                                    Value::Keyword(
+                                                        field_name
+                                                            .clone()
+                                                            .into(),
+                                                    )
+                                                })
+                                                .collect(),
+                                        ),
                                     },
                                     Argument {
                                         name: Some("references".into()),
-                                        value: Value::Array(vec![
-                                            Value::Keyword("id".into()),
-                                        ]),
+                                        value: Value::Array(
+                                            foreign_keys
+                                                .iter()
+                                                .map(|(_, id_field_name, _)| {
+                                                    Value::Keyword(
+                                                        id_field_name.clone(),
+                                                    )
+                                                })
+                                                .collect(),
+                                        ),
                                     },
-                                ],
+                                ]
+                                .into_iter()
+                                .chain(referential_action_arguments(&relation))
+                                .collect(),
                             }
                         }],
+                        doc: relation.doc.clone(),
                     };
 
                     model.insert_field(relation_name.clone(), field)?;
 
-                    let field_name = format!("{relation_name}Id");
-
-                    let field = Field {
-                        name: field_name.clone().into(),
-                        r#type: field::Type::Name("Int".into()),
-                        modifier: field::Modifier::Optional,
-                        attributes: vec![attribute::Field::unique()],
-                    };
-
-                    model.insert_field(field_name, field)?;
+                    let one_to_one = relation.inverse_declared
+                        && models
+                            .get(&relation.model_name)
+                            .and_then(|model| {
+                                model.relations.get(&relation.inverse_name)
+                            })
+                            .is_some_and(|inverse| {
+                                inverse.r#type
+                                    == ir::model::model_relation::Type::OneToOne
+                            });
+
+                    for (field_name, _, r#type) in foreign_keys {
+                        let field = Field {
+                            name: field_name.clone().into(),
+                            r#type: r#type.into(),
+                            modifier,
+                            attributes: if one_to_one {
+                                vec![attribute::Field::unique()]
+                            } else {
+                                Vec::new()
+                            },
+                            doc: None,
+                        };
+
+                        model.insert_field(field_name, field)?;
+                    }
                 }
             };
         }
 
+        match ir_model.id {
+            ir::model::Id::Composite(field_names) => {
+                model.attributes.push(attribute::Block {
+                    group: None,
+                    name: "id".into(),
+                    arguments: vec![Argument {
+                        name: None,
+                        value: Value::Array(
+                            field_names
+                                .into_iter()
+                                .map(Value::Keyword)
+                                .collect(),
+                        ),
+                    }],
+                });
+            }
+            ir::model::Id::Field(field_name) => {
+                if let Some(field) = model.fields.get_mut(&field_name) {
+                    field.attributes.insert(0, attribute::Field::id());
+                }
+            }
+            ir::model::Id::Generated(_) => {}
+        }
+
+        let ir::model::Timestamps {
+            created_at,
+            updated_at,
+            ..
+        } = ir_model.timestamps;
+
+        for (field_name, attribute) in [
+            (created_at, attribute::Field::default_now()),
+            (updated_at, attribute::Field::updated_at()),
+        ] {
+            if let Some(field) = field_name
+                .and_then(|field_name| model.fields.get_mut(&field_name))
+            {
+                field.attributes.push(attribute);
+            }
+        }
+
+        for field_name in ir_model.unique_fields {
+            if let Some(field) = model.fields.get_mut(&field_name) {
+                field.attributes.push(attribute::Field::unique());
+            }
+        }
+
+        for (field_name, value) in ir_model.defaults {
+            if let Some(field) = model.fields.get_mut(&field_name) {
+                field
+                    .attributes
+                    .push(attribute::Field::default_value(value.into()));
+            }
+        }
+
+        for (field_name, native_type) in ir_model.native_types {
+            if let Some(field) = model.fields.get_mut(&field_name) {
+                field.attributes.push(native_type.into());
+            }
+        }
+
+        model
+            .attributes
+            .extend(ir_model.indexes.into_iter().map(Into::into));
+
         Ok(model)
     }
 }
 
+impl<'a> TryFrom<ir::Model<'a>> for Model<'a> {
+    type Error = SchemaError<'a>;
+
+    fn try_from(ir_model: ir::Model<'a>) -> Result<Self, Self::Error> {
+        Self::from_ir_model(ir_model, &OrdStrMap::new())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -291,6 +448,10 @@ mod tests {
     fn test_try_from_ir_model() {
         let mut ir_model = ir::Model::new("User");
 
+        ir_model
+            .insert_timestamps(ir::model::Timestamps::standard())
+            .unwrap();
+
         ir_model.insert_enums_relation("roles", "Role").unwrap();
         ir_model.insert_enum_relation("role", "Role").unwrap();
 
@@ -299,6 +460,7 @@ mod tests {
                 name: "age".into(),
                 r#type: ir::Type::Int,
                 cardinality: ir::Cardinality::One,
+                doc: None,
             })
             .unwrap();
 
@@ -322,15 +484,133 @@ mod tests {
   role      Role
   profile   Profile? @relation(name: \"profileOnUser\")
   posts     Post[]   @relation(name: \"postsOnUser\")
-  country   Country? @relation(name: \"countryOnUser\", fields: [countryId], \
+  country   Country  @relation(name: \"countryOnUser\", fields: [countryId], \
              references: [id])
-  countryId Int?     @unique
+  countryId Int
   friends   User[]   @relation(name: \"friendsOnUser\")
 }
 "
         );
     }
 
+    #[test]
+    fn test_try_from_ir_model_doc_comments() {
+        let mut ir_model = ir::Model::new("User");
+
+        ir_model
+            .insert_timestamps(ir::model::Timestamps::standard())
+            .unwrap();
+
+        ir_model.doc = Some("A user.\n\nCan log in.".into());
+
+        ir_model
+            .insert_field(ir::model::Field {
+                name: "age".into(),
+                r#type: ir::Type::Int,
+                cardinality: ir::Cardinality::One,
+                doc: Some("In years.".into()),
+            })
+            .unwrap();
+
+        ir_model.insert_many_to_one("country", "Country").unwrap();
+        ir_model.insert_field_doc("country", "Where the user lives.".into());
+
+        let model: Model = ir_model.try_into().unwrap();
+        let mut f = Vec::new();
+
+        model.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "/// A user.
+///
+/// Can log in.
+model User {
+  id        Int      @id @default(autoincrement())
+  createdAt DateTime @default(now())
+  /// In years.
+  age       Int
+  /// Where the user lives.
+  country   Country  @relation(name: \"countryOnUser\", fields: [countryId], \
+             references: [id])
+  countryId Int
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_try_from_ir_model_attributes() {
+        let mut ir_model = ir::Model::new("User");
+
+        ir_model
+            .insert_timestamps(ir::model::Timestamps::standard())
+            .unwrap();
+
+        ir_model
+            .insert_field(ir::model::Field {
+                name: "email".into(),
+                r#type: ir::Type::String,
+                cardinality: ir::Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        ir_model
+            .insert_field(ir::model::Field {
+                name: "visits".into(),
+                r#type: ir::Type::Int,
+                cardinality: ir::Cardinality::One,
+                doc: None,
+            })
+            .unwrap();
+
+        ir_model.insert_enum_relation("role", "Role").unwrap();
+        ir_model.insert_unique("email").unwrap();
+
+        ir_model
+            .insert_index(ir::model::Index {
+                fields: vec!["email".into(), "role".into()],
+                unique: false,
+            })
+            .unwrap();
+
+        let _: Option<ir::Value> = ir_model
+            .defaults
+            .insert("visits", ir::Value::Int("0".into()));
+
+        let _: Option<ir::Value> = ir_model
+            .defaults
+            .insert("role", ir::Value::EnumValue("Member".into()));
+
+        let _: Option<ir::model::NativeType> = ir_model.native_types.insert(
+            "email",
+            ir::model::NativeType {
+                name: "VarChar".into(),
+                arguments: vec!["320".into()],
+            },
+        );
+
+        let model: Model = ir_model.try_into().unwrap();
+        let mut f = Vec::new();
+
+        model.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "model User {
+  id        Int      @id @default(autoincrement())
+  createdAt DateTime @default(now())
+  email     String   @unique @db.VarChar(320)
+  visits    Int      @default(0)
+  role      Role     @default(Member)
+
+  @@index([email, role])
+}
+"
+        );
+    }
+
     #[test]
     fn test_try_from_ir_model_duplicate_field() {
         let mut ir_model = ir::Model::new("User");
@@ -339,7 +619,8 @@ mod tests {
             .insert_field(ir::model::Field {
                 name: "id".into(),
                 r#type: ir::Type::Int,
-                cardinality: Cardinality::One,
+                cardinality: ir::Cardinality::One,
+                doc: None,
             })
             .unwrap();
 
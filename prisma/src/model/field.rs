@@ -3,6 +3,7 @@ use {
     crate::attribute,
     ir::{
         self,
+        model::id::Strategy,
         Cardinality,
     },
     print::PrintInline,
@@ -39,20 +40,32 @@ pub struct Field<'a> {
     pub modifier: Modifier,
     /// Field attributes.
     pub attributes: Vec<attribute::Field<'a>>,
+    /// The documentation of the field.
+    pub doc: Option<Cow<'a, str>>,
 }
 
 impl<'a> Field<'a> {
-    /// Standard `id` field.
+    /// Standard `id` field, generated with the given strategy.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The way the values of the field are created.
     #[must_use]
-    pub fn id() -> Self {
+    pub fn id(strategy: Strategy) -> Self {
+        let default = match strategy {
+            Strategy::AutoIncrement => {
+                attribute::Field::default_auto_increment()
+            }
+            Strategy::Cuid => attribute::Field::default_cuid(),
+            Strategy::Uuid => attribute::Field::default_uuid(),
+        };
+
         Self {
             name: "id".into(),
-            r#type: Type::Name("Int".into()),
+            r#type: strategy.r#type().into(),
             modifier: Modifier::None,
-            attributes: vec![
-                attribute::Field::id(),
-                attribute::Field::default_auto_increment(),
-            ],
+            attributes: vec![attribute::Field::id(), default],
+            doc: None,
         }
     }
 
@@ -64,6 +77,7 @@ impl<'a> Field<'a> {
             r#type: Type::Name("DateTime".into()),
             modifier: Modifier::None,
             attributes: vec![attribute::Field::default_now()],
+            doc: None,
         }
     }
 
@@ -100,89 +114,25 @@ impl<'a> From<ir::model::Field<'a>> for Field<'a> {
             name,
             r#type,
             cardinality,
+            doc,
         } = value;
 
-        match (r#type, cardinality) {
-            (ir::Type::Boolean, Cardinality::One) => {
-                Self {
-                    name,
-                    r#type: Type::Name("Boolean".into()),
-                    modifier: Modifier::None,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::DateTime, Cardinality::One) => {
-                Self {
-                    name,
-                    r#type: Type::Name("DateTime".into()),
-                    modifier: Modifier::None,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::Float, Cardinality::One) => {
-                Self {
-                    name,
-                    r#type: Type::Name("Float".into()),
-                    modifier: Modifier::None,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::Int, Cardinality::One) => {
-                Self {
-                    name,
-                    r#type: Type::Name("Int".into()),
-                    modifier: Modifier::None,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::String, Cardinality::One) => {
-                Self {
-                    name,
-                    r#type: Type::Name("String".into()),
-                    modifier: Modifier::None,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::Boolean, Cardinality::Many) => {
-                Self {
-                    name,
-                    r#type: Type::Name("Boolean".into()),
-                    modifier: Modifier::List,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::DateTime, Cardinality::Many) => {
-                Self {
-                    name,
-                    r#type: Type::Name("DateTime".into()),
-                    modifier: Modifier::List,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::Float, Cardinality::Many) => {
-                Self {
-                    name,
-                    r#type: Type::Name("Float".into()),
-                    modifier: Modifier::List,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::Int, Cardinality::Many) => {
-                Self {
-                    name,
-                    r#type: Type::Name("Int".into()),
-                    modifier: Modifier::List,
-                    attributes: Vec::new(),
-                }
-            }
-            (ir::Type::String, Cardinality::Many) => {
-                Self {
-                    name,
-                    r#type: Type::Name("String".into()),
-                    modifier: Modifier::List,
-                    attributes: Vec::new(),
-                }
-            }
+        Self {
+            name,
+            r#type: r#type.into(),
+            modifier: cardinality.into(),
+            attributes: Vec::new(),
+            doc,
+        }
+    }
+}
+
+impl From<Cardinality> for Modifier {
+    fn from(cardinality: Cardinality) -> Self {
+        match cardinality {
+            Cardinality::One => Self::None,
+            Cardinality::Optional => Self::Optional,
+            Cardinality::Many => Self::List,
         }
     }
 }
@@ -198,6 +148,7 @@ mod tests {
             r#type: Type::Name("Int".into()),
             modifier: Modifier::List,
             attributes: Vec::new(),
+            doc: None,
         };
 
         let mut f = Vec::new();
@@ -214,6 +165,7 @@ mod tests {
             r#type: Type::Name("Int".into()),
             modifier: Modifier::Optional,
             attributes: Vec::new(),
+            doc: None,
         };
 
         let mut f = Vec::new();
@@ -223,12 +175,29 @@ mod tests {
         assert_eq!(String::from_utf8(f).unwrap(), "Int?");
     }
 
+    #[test]
+    fn test_from_optional_string() {
+        let field = Field::from(ir::model::Field {
+            name: "caption".into(),
+            r#type: ir::Type::String,
+            cardinality: Cardinality::Optional,
+            doc: None,
+        });
+
+        let mut f = Vec::new();
+
+        field.print_type(&mut f).unwrap();
+
+        assert_eq!(String::from_utf8(f).unwrap(), "String?");
+    }
+
     #[test]
     fn test_from_one_boolean() {
         let field = Field::from(ir::model::Field {
             name: "predicate".into(),
             r#type: ir::Type::Boolean,
             cardinality: Cardinality::One,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -244,6 +213,7 @@ mod tests {
             name: "createdAt".into(),
             r#type: ir::Type::DateTime,
             cardinality: Cardinality::One,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -259,6 +229,7 @@ mod tests {
             name: "price".into(),
             r#type: ir::Type::Float,
             cardinality: Cardinality::One,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -274,6 +245,7 @@ mod tests {
             name: "id".into(),
             r#type: ir::Type::Int,
             cardinality: Cardinality::One,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -289,6 +261,7 @@ mod tests {
             name: "name".into(),
             r#type: ir::Type::String,
             cardinality: Cardinality::One,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -304,6 +277,7 @@ mod tests {
             name: "predicates".into(),
             r#type: ir::Type::Boolean,
             cardinality: Cardinality::Many,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -319,6 +293,7 @@ mod tests {
             name: "createdAt".into(),
             r#type: ir::Type::DateTime,
             cardinality: Cardinality::Many,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -334,6 +309,7 @@ mod tests {
             name: "prices".into(),
             r#type: ir::Type::Float,
             cardinality: Cardinality::Many,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -349,6 +325,7 @@ mod tests {
             name: "ids".into(),
             r#type: ir::Type::Int,
             cardinality: Cardinality::Many,
+            doc: None,
         });
 
         let mut f = Vec::new();
@@ -364,6 +341,7 @@ mod tests {
             name: "names".into(),
             r#type: ir::Type::String,
             cardinality: Cardinality::Many,
+            doc: None,
         });
 
         let mut f = Vec::new();
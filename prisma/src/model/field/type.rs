@@ -28,6 +28,24 @@ impl PrintInline for Type<'_> {
     }
 }
 
+impl From<ir::Type> for Type<'_> {
+    fn from(r#type: ir::Type) -> Self {
+        let name = match r#type {
+            ir::Type::BigInt => "BigInt",
+            ir::Type::Boolean => "Boolean",
+            ir::Type::Bytes => "Bytes",
+            ir::Type::DateTime => "DateTime",
+            ir::Type::Decimal => "Decimal",
+            ir::Type::Float => "Float",
+            ir::Type::Int => "Int",
+            ir::Type::Json => "Json",
+            ir::Type::String | ir::Type::Uuid => "String",
+        };
+
+        Self::Name(name.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -61,4 +79,14 @@ mod tests {
 
         assert_eq!(String::from_utf8(f).unwrap(), "foo(\"bar\", \"baz\")");
     }
+
+    #[test]
+    fn test_from_ir_type() {
+        assert_eq!(Type::from(ir::Type::String), Type::Name("String".into()));
+        assert_eq!(Type::from(ir::Type::BigInt), Type::Name("BigInt".into()));
+        assert_eq!(Type::from(ir::Type::Bytes), Type::Name("Bytes".into()));
+        assert_eq!(Type::from(ir::Type::Decimal), Type::Name("Decimal".into()));
+        assert_eq!(Type::from(ir::Type::Json), Type::Name("Json".into()));
+        assert_eq!(Type::from(ir::Type::Uuid), Type::Name("String".into()));
+    }
 }
@@ -63,6 +63,25 @@ impl PrintInline for Value<'_> {
     }
 }
 
+impl<'a> From<ir::Value<'a>> for Value<'a> {
+    fn from(value: ir::Value<'a>) -> Self {
+        match value {
+            ir::Value::Boolean(value) => Self::Boolean(value),
+            ir::Value::EnumValue(value) => Self::Keyword(value),
+            ir::Value::Float(value) | ir::Value::Int(value) => {
+                Self::Number(value)
+            }
+            ir::Value::Now => {
+                Self::Function(Function {
+                    name: "now".into(),
+                    parameters: Vec::new(),
+                })
+            }
+            ir::Value::String(value) => Self::String(value),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,4 +153,25 @@ mod tests {
 
         assert_eq!(String::from_utf8(f).unwrap(), "foo");
     }
+
+    #[test]
+    fn test_from_ir_value() {
+        assert_eq!(
+            Value::from(ir::Value::EnumValue("Admin".into())),
+            Value::Keyword("Admin".into())
+        );
+
+        assert_eq!(
+            Value::from(ir::Value::Float("1.5".into())),
+            Value::Number("1.5".into())
+        );
+
+        assert_eq!(
+            Value::from(ir::Value::Now),
+            Value::Function(Function {
+                name: "now".into(),
+                parameters: Vec::new(),
+            })
+        );
+    }
 }
@@ -4,7 +4,14 @@ use {
         type_parameter::TypeParameter,
         ExpressionWithTypeArguments,
     },
-    crate::Type,
+    crate::{
+        print_jsdoc,
+        r#type::{
+            Keyword,
+            ObjectLiteralProperty,
+        },
+        Type,
+    },
     ir,
     print::{
         Print,
@@ -19,6 +26,19 @@ use {
 /// Interface properties.
 pub mod property;
 
+/// Capitalize the first character of a name.
+///
+/// # Arguments
+///
+/// * `name` - The name.
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
 /// An interface declaration.
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Interface<'a> {
@@ -33,6 +53,64 @@ pub struct Interface<'a> {
     pub type_parameters: Vec<TypeParameter<'a>>,
     /// The properties of the interface.
     pub properties: Vec<Property<'a>>,
+    /// The documentation of the interface.
+    pub doc: Option<Cow<'a, str>>,
+}
+
+impl<'a> Interface<'a> {
+    /// Create the interface of the arguments of a mutation or aggregate,
+    /// named after it with an `Input` suffix.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the mutation or aggregate.
+    /// * `arguments` - The arguments.
+    /// * `doc` - The documentation of the interface.
+    fn input<I>(
+        name: &str,
+        arguments: I,
+        doc: Option<Cow<'a, str>>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = ir::query::Argument<'a>>,
+    {
+        Self {
+            extends: vec![],
+            identifier: format!("{}Input", capitalize(name)).into(),
+            type_parameters: vec![],
+            properties: arguments
+                .into_iter()
+                .map(|argument| {
+                    Property {
+                        identifier: argument.name.clone(),
+                        optional: argument.cardinality
+                            == ir::Cardinality::Optional,
+                        r#type: argument.into(),
+                        doc: None,
+                    }
+                })
+                .collect(),
+            doc,
+        }
+    }
+
+    /// Create the interface of the arguments of an aggregate, named after
+    /// the aggregate with an `Input` suffix. Returns `None` if the aggregate
+    /// has no arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `ir_aggregate` - The aggregate.
+    #[must_use]
+    pub fn aggregate_input(ir_aggregate: &ir::Aggregate<'a>) -> Option<Self> {
+        (!ir_aggregate.arguments.is_empty()).then(|| {
+            Self::input(
+                &ir_aggregate.name,
+                ir_aggregate.arguments.values().cloned(),
+                None,
+            )
+        })
+    }
 }
 
 impl Print for Interface<'_> {
@@ -48,9 +126,16 @@ impl Print for Interface<'_> {
             extends,
             type_parameters: parameters,
             properties,
+            doc,
         } = self;
 
-        write!(f, "{}interface {name}", Self::indent(level))?;
+        let indent = Self::indent(level);
+
+        if let Some(doc) = doc {
+            print_jsdoc(doc, &indent, f)?;
+        }
+
+        write!(f, "{indent}interface {name}")?;
 
         if !parameters.is_empty() {
             write!(f, "<")?;
@@ -80,25 +165,43 @@ impl<'a> From<ir::Model<'a>> for Interface<'a> {
             identifier: ir_model.name,
             type_parameters: vec![],
             properties: vec![],
+            doc: ir_model.doc,
         };
 
+        if let ir::model::Id::Generated(strategy) = ir_model.id {
+            interface.properties.push(Property {
+                identifier: "id".into(),
+                optional: false,
+                r#type: strategy.r#type().into(),
+                doc: None,
+            });
+        }
+
         for (name, field) in ir_model.fields {
+            let doc = field.doc.clone();
+
             interface.properties.push(Property {
                 identifier: name.into(),
+                optional: field.cardinality == ir::Cardinality::Optional,
                 r#type: field.into(),
-                optional: false,
+                doc,
             });
         }
 
         for (relation_name, relation) in ir_model.enums {
+            let doc = relation.doc.clone();
+
             interface.properties.push(Property {
                 identifier: relation_name.into(),
+                optional: relation.cardinality == ir::Cardinality::Optional,
                 r#type: relation.into(),
-                optional: false,
+                doc,
             });
         }
 
         for (relation_name, relation) in ir_model.relations {
+            let doc = relation.doc.clone();
+
             interface.properties.push(match relation.r#type {
                 ir::model::model_relation::Type::OneToOne => {
                     Property {
@@ -108,6 +211,7 @@ impl<'a> From<ir::Model<'a>> for Interface<'a> {
                             type_arguments: vec![],
                         },
                         optional: true,
+                        doc,
                     }
                 }
                 ir::model::model_relation::Type::ManyToOne => {
@@ -117,7 +221,8 @@ impl<'a> From<ir::Model<'a>> for Interface<'a> {
                             identifier: relation.model_name.clone(),
                             type_arguments: vec![],
                         },
-                        optional: false,
+                        optional: relation.optional,
+                        doc,
                     }
                 }
                 ir::model::model_relation::Type::OneToMany
@@ -129,6 +234,7 @@ impl<'a> From<ir::Model<'a>> for Interface<'a> {
                             type_arguments: vec![],
                         })),
                         optional: false,
+                        doc,
                     }
                 }
             });
@@ -138,6 +244,88 @@ impl<'a> From<ir::Model<'a>> for Interface<'a> {
     }
 }
 
+impl<'a> From<ir::Aggregate<'a>> for Interface<'a> {
+    fn from(ir_aggregate: ir::Aggregate<'a>) -> Self {
+        let mut interface = Self {
+            extends: vec![],
+            identifier: capitalize(&ir_aggregate.name).into(),
+            type_parameters: vec![],
+            properties: vec![],
+            doc: ir_aggregate.doc,
+        };
+
+        for group_by in ir_aggregate.group_by {
+            interface.properties.push(Property {
+                identifier: group_by.field_name,
+                r#type: match group_by.r#type {
+                    ir::query::ArgumentType::Enum(name) => {
+                        Type::TypeReference {
+                            identifier: name,
+                            type_arguments: vec![],
+                        }
+                    }
+                    ir::query::ArgumentType::Type(r#type) => r#type.into(),
+                },
+                optional: group_by.cardinality == ir::Cardinality::Optional,
+                doc: None,
+            });
+        }
+
+        if ir_aggregate.count {
+            interface.properties.push(Property {
+                identifier: "_count".into(),
+                r#type: Type::Keyword(Keyword::Number),
+                optional: false,
+                doc: None,
+            });
+        }
+
+        let mut functions = Vec::new();
+
+        for aggregation in &ir_aggregate.aggregations {
+            if !functions.contains(&aggregation.function) {
+                functions.push(aggregation.function);
+            }
+        }
+
+        for function in functions {
+            let properties = ir_aggregate
+                .aggregations
+                .iter()
+                .filter(|aggregation| aggregation.function == function)
+                .map(|aggregation| {
+                    ObjectLiteralProperty {
+                        name: aggregation.field_name.clone(),
+                        r#type: Type::Union(vec![
+                            aggregation.r#type.into(),
+                            Type::Keyword(Keyword::Null),
+                        ]),
+                    }
+                })
+                .collect();
+
+            interface.properties.push(Property {
+                identifier: format!("_{function}").into(),
+                r#type: Type::ObjectLiteral(properties),
+                optional: false,
+                doc: None,
+            });
+        }
+
+        interface
+    }
+}
+
+impl<'a> From<ir::Mutation<'a>> for Interface<'a> {
+    fn from(ir_mutation: ir::Mutation<'a>) -> Self {
+        Self::input(
+            &ir_mutation.name,
+            ir_mutation.arguments.into_values(),
+            ir_mutation.doc,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -160,60 +348,70 @@ mod tests {
             name: "isPublic".into(),
             r#type: ir::Type::Boolean,
             cardinality: ir::Cardinality::One,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "createdAt".into(),
             r#type: ir::Type::DateTime,
             cardinality: ir::Cardinality::One,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "latitude".into(),
             r#type: ir::Type::Float,
             cardinality: ir::Cardinality::One,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "height".into(),
             r#type: ir::Type::Int,
             cardinality: ir::Cardinality::One,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "title".into(),
             r#type: ir::Type::String,
             cardinality: ir::Cardinality::One,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "anArrayOfBooleansIsDumb".into(),
             r#type: ir::Type::Boolean,
             cardinality: ir::Cardinality::Many,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "events".into(),
             r#type: ir::Type::DateTime,
             cardinality: ir::Cardinality::Many,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "latitudes".into(),
             r#type: ir::Type::Float,
             cardinality: ir::Cardinality::Many,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "heights".into(),
             r#type: ir::Type::Int,
             cardinality: ir::Cardinality::Many,
+            doc: None,
         })?;
 
         model.insert_field(ir::model::Field {
             name: "names".into(),
             r#type: ir::Type::String,
             cardinality: ir::Cardinality::Many,
+            doc: None,
         })?;
 
         model.insert_many_to_one("owner", "User")?;
@@ -229,6 +427,7 @@ mod tests {
         assert_eq!(
             String::from_utf8(f).unwrap(),
             "interface Image {
+    id: number;
     isPublic: boolean;
     createdAt: Date;
     latitude: number;
@@ -253,6 +452,231 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_ir_model_optional() -> Result<(), ir::TypeError<'static>> {
+        let mut model = ir::Model::new("Image");
+
+        model.insert_field(ir::model::Field {
+            name: "caption".into(),
+            r#type: ir::Type::String,
+            cardinality: ir::Cardinality::Optional,
+            doc: None,
+        })?;
+
+        model.insert_optional_enum_relation("kind", "Kind")?;
+        model.insert_optional_many_to_one("country", "Country")?;
+
+        let interface = Interface::from(model);
+        let mut f = Vec::new();
+
+        interface.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "interface Image {
+    id: number;
+    caption?: string;
+    kind?: Kind;
+    country?: Country;
+}
+
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_ir_model_self_relations() -> Result<(), ir::TypeError<'static>>
+    {
+        let mut model = ir::Model::new("Category");
+
+        model.insert_optional_many_to_one("parent", "Category")?;
+        model.insert_many_to_many("children", "Category")?;
+
+        let interface = Interface::from(model);
+        let mut f = Vec::new();
+
+        interface.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "interface Category {
+    id: number;
+    parent?: Category;
+    children: Array<Category>;
+}
+
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_ir_aggregate() {
+        let mut aggregate = ir::Aggregate::new("postStats", "Post");
+
+        aggregate.doc = Some("Post statistics per status.".into());
+        aggregate.count = true;
+
+        aggregate.aggregations = vec![
+            ir::aggregate::Aggregation {
+                function: ir::aggregate::Function::Sum,
+                field_name: "views".into(),
+                r#type: ir::Type::Int,
+            },
+            ir::aggregate::Aggregation {
+                function: ir::aggregate::Function::Avg,
+                field_name: "views".into(),
+                r#type: ir::Type::Float,
+            },
+            ir::aggregate::Aggregation {
+                function: ir::aggregate::Function::Avg,
+                field_name: "rating".into(),
+                r#type: ir::Type::Float,
+            },
+            ir::aggregate::Aggregation {
+                function: ir::aggregate::Function::Max,
+                field_name: "publishedAt".into(),
+                r#type: ir::Type::DateTime,
+            },
+        ];
+
+        aggregate.group_by = vec![
+            ir::aggregate::GroupBy {
+                field_name: "status".into(),
+                r#type: ir::query::ArgumentType::Enum("Status".into()),
+                cardinality: ir::Cardinality::One,
+            },
+            ir::aggregate::GroupBy {
+                field_name: "category".into(),
+                r#type: ir::query::ArgumentType::Type(ir::Type::String),
+                cardinality: ir::Cardinality::Optional,
+            },
+        ];
+
+        let interface = Interface::from(aggregate);
+        let mut f = Vec::new();
+
+        interface.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "/** Post statistics per status. */
+interface PostStats {
+    status: Status;
+    category?: string;
+    _count: number;
+    _sum: { views: number | null };
+    _avg: { views: number | null, rating: number | null };
+    _max: { publishedAt: Date | null };
+}
+
+"
+        );
+    }
+
+    #[test]
+    fn test_aggregate_input() {
+        let mut aggregate = ir::Aggregate::new("postStats", "Post");
+
+        assert_eq!(Interface::aggregate_input(&aggregate), None);
+
+        for (name, r#type, cardinality) in [
+            (
+                "status",
+                ir::query::ArgumentType::Enum("Status".into()),
+                ir::Cardinality::One,
+            ),
+            (
+                "since",
+                ir::query::ArgumentType::Type(ir::Type::DateTime),
+                ir::Cardinality::Optional,
+            ),
+        ] {
+            let _: Option<ir::query::Argument<'_>> =
+                aggregate.arguments.insert(
+                    name,
+                    ir::query::Argument {
+                        name: name.into(),
+                        r#type,
+                        cardinality,
+                    },
+                );
+        }
+
+        let interface = Interface::aggregate_input(&aggregate).unwrap();
+        let mut f = Vec::new();
+
+        interface.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "interface PostStatsInput {
+    status: Status;
+    since?: Date;
+}
+
+"
+        );
+    }
+
+    #[test]
+    fn test_from_ir_mutation() {
+        let mut mutation = ir::Mutation::new(
+            "createPost",
+            "Post",
+            ir::mutation::Operation::Create(vec![]),
+        );
+
+        mutation.doc = Some("Create a post.".into());
+
+        for (name, r#type, cardinality) in [
+            (
+                "title",
+                ir::query::ArgumentType::Type(ir::Type::String),
+                ir::Cardinality::One,
+            ),
+            (
+                "subtitle",
+                ir::query::ArgumentType::Type(ir::Type::String),
+                ir::Cardinality::Optional,
+            ),
+            (
+                "tags",
+                ir::query::ArgumentType::Enum("Tag".into()),
+                ir::Cardinality::Many,
+            ),
+        ] {
+            let _: Option<ir::query::Argument<'_>> = mutation.arguments.insert(
+                name,
+                ir::query::Argument {
+                    name: name.into(),
+                    r#type,
+                    cardinality,
+                },
+            );
+        }
+
+        let interface = Interface::from(mutation);
+        let mut f = Vec::new();
+
+        interface.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "/** Create a post. */
+interface CreatePostInput {
+    title: string;
+    subtitle?: string;
+    tags: Array<Tag>;
+}
+
+"
+        );
+    }
+
     #[test]
     fn test_print() {
         let interface = Interface {
@@ -273,6 +697,7 @@ mod tests {
                     identifier: "title".into(),
                     r#type: Type::Keyword(Keyword::String),
                     optional: false,
+                    doc: None,
                 },
                 Property {
                     identifier: "countryName".into(),
@@ -281,6 +706,7 @@ mod tests {
                         type_arguments: vec![],
                     },
                     optional: true,
+                    doc: None,
                 },
                 Property {
                     identifier: "tags".into(),
@@ -289,8 +715,10 @@ mod tests {
                         type_arguments: vec![],
                     })),
                     optional: false,
+                    doc: None,
                 },
             ],
+            doc: None,
         };
 
         let mut f = Vec::new();
@@ -308,4 +736,90 @@ mod tests {
 "
         );
     }
+
+    #[test]
+    fn test_from_ir_model_ids() -> Result<(), ir::TypeError<'static>> {
+        let mut session = ir::Model::new("Session");
+
+        session.insert_field(ir::model::Field {
+            name: "token".into(),
+            r#type: ir::Type::String,
+            cardinality: ir::Cardinality::One,
+            doc: None,
+        })?;
+
+        session.insert_id(ir::model::Id::Generated(
+            ir::model::id::Strategy::Uuid,
+        ))?;
+
+        let mut country = ir::Model::new("Country");
+
+        country.insert_field(ir::model::Field {
+            name: "code".into(),
+            r#type: ir::Type::String,
+            cardinality: ir::Cardinality::One,
+            doc: None,
+        })?;
+
+        country.insert_id(ir::model::Id::Field("code".into()))?;
+
+        let mut f = Vec::new();
+
+        Interface::from(session).print(0, &mut f).unwrap();
+        Interface::from(country).print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "interface Session {
+    id: string;
+    token: string;
+}
+
+interface Country {
+    code: string;
+}
+
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_ir_model_timestamps() -> Result<(), ir::TypeError<'static>> {
+        let mut model = ir::Model::new("Post");
+
+        model.insert_timestamps(ir::model::Timestamps {
+            created_at: Some("publishedAt".into()),
+            updated_at: Some("updatedAt".into()),
+            deleted_at: Some("deletedAt".into()),
+        })?;
+
+        model.insert_field(ir::model::Field {
+            name: "title".into(),
+            r#type: ir::Type::String,
+            cardinality: ir::Cardinality::One,
+            doc: None,
+        })?;
+
+        let interface = Interface::from(model);
+        let mut f = Vec::new();
+
+        interface.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "interface Post {
+    id: number;
+    publishedAt: Date;
+    updatedAt: Date;
+    deletedAt?: Date;
+    title: string;
+}
+
+"
+        );
+
+        Ok(())
+    }
 }
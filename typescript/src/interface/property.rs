@@ -1,5 +1,8 @@
 use {
-    crate::r#type::Type,
+    crate::{
+        print_jsdoc,
+        r#type::Type,
+    },
     print::{
         Print,
         PrintInline,
@@ -19,6 +22,8 @@ pub struct Property<'a> {
     pub r#type: Type<'a>,
     /// Whether the property is optional.
     pub optional: bool,
+    /// The documentation of the property.
+    pub doc: Option<Cow<'a, str>>,
 }
 
 impl Print for Property<'_> {
@@ -33,9 +38,16 @@ impl Print for Property<'_> {
             identifier,
             r#type: type_reference,
             optional,
+            doc,
         } = self;
 
-        write!(f, "{}{identifier}", Self::indent(level))?;
+        let indent = Self::indent(level);
+
+        if let Some(doc) = doc {
+            print_jsdoc(doc, &indent, f)?;
+        }
+
+        write!(f, "{indent}{identifier}")?;
 
         if *optional {
             write!(f, "?")?;
@@ -57,12 +69,32 @@ mod tests {
         },
     };
 
+    #[test]
+    fn test_print_doc_comment() {
+        let property = Property {
+            identifier: "foo".into(),
+            r#type: Type::Keyword(Keyword::String),
+            optional: false,
+            doc: Some("Ends a comment: */".into()),
+        };
+
+        let mut f = Vec::new();
+
+        property.print(1, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "    /** Ends a comment: *\\/ */\n    foo: string;\n"
+        );
+    }
+
     #[test]
     fn test_print() {
         let property = Property {
             identifier: "foo".into(),
             r#type: Type::Keyword(Keyword::String),
             optional: false,
+            doc: None,
         };
 
         let mut f = Vec::new();
@@ -75,6 +107,7 @@ mod tests {
             identifier: "foo".into(),
             r#type: Type::Keyword(Keyword::String),
             optional: true,
+            doc: None,
         };
 
         let mut f = Vec::new();
@@ -32,6 +32,7 @@
     variant_size_differences
 )]
 
+use std::io;
 pub use {
     expression_with_type_arguments::ExpressionWithTypeArguments,
     import::Import,
@@ -65,3 +66,39 @@ pub mod type_parameter;
 
 /// Tab size.
 pub const TAB_SIZE: usize = 4;
+
+/// Print documentation as a `/** */` comment. Single-line documentation is
+/// printed on one line.
+///
+/// # Arguments
+///
+/// * `doc` - The documentation to print.
+/// * `indent` - The indentation of the comment.
+/// * `f` - The writer to print to.
+///
+/// # Errors
+///
+/// If an error occurs while writing to the stream.
+pub fn print_jsdoc(
+    doc: &str,
+    indent: &str,
+    f: &mut dyn io::Write,
+) -> io::Result<()> {
+    let doc = doc.replace("*/", "*\\/");
+
+    if !doc.contains('\n') {
+        return writeln!(f, "{indent}/** {doc} */");
+    }
+
+    writeln!(f, "{indent}/**")?;
+
+    for line in doc.lines() {
+        if line.is_empty() {
+            writeln!(f, "{indent} *")?;
+        } else {
+            writeln!(f, "{indent} * {line}")?;
+        }
+    }
+
+    writeln!(f, "{indent} */")
+}
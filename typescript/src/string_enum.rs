@@ -1,4 +1,5 @@
 use {
+    crate::print_jsdoc,
     ir,
     ord_str_map::OrdStrMap,
     print::Print,
@@ -17,6 +18,8 @@ pub struct Variant<'a> {
     pub name: Cow<'a, str>,
     /// The value of the variant. May differ from the name.
     pub value: Cow<'a, str>,
+    /// The documentation of the variant.
+    pub doc: Option<Cow<'a, str>>,
 }
 
 impl<'a, S> From<S> for Variant<'a>
@@ -27,6 +30,7 @@ where
         Self {
             name: name.clone().into(),
             value: name.into(),
+            doc: None,
         }
     }
 }
@@ -39,9 +43,14 @@ impl Print for Variant<'_> {
         level: usize,
         f: &mut dyn io::Write,
     ) -> io::Result<()> {
-        let Self { name, value } = self;
+        let Self { name, value, doc } = self;
+        let indent = Self::indent(level);
+
+        if let Some(doc) = doc {
+            print_jsdoc(doc, &indent, f)?;
+        }
 
-        writeln!(f, "{}{name} = \"{value}\",", Self::indent(level))
+        writeln!(f, "{indent}{name} = \"{value}\",")
     }
 }
 
@@ -52,6 +61,8 @@ pub struct StringEnum<'a> {
     pub identifier: Cow<'a, str>,
     /// Enum variants, usually called `members` in TypeScript ASTs.
     pub variants: OrdStrMap<Variant<'a>>,
+    /// The documentation of the enum.
+    pub doc: Option<Cow<'a, str>>,
 }
 
 impl Print for StringEnum<'_> {
@@ -65,10 +76,15 @@ impl Print for StringEnum<'_> {
         let Self {
             identifier: name,
             variants,
+            doc,
         } = self;
 
         let indent = Self::indent(level);
 
+        if let Some(doc) = doc {
+            print_jsdoc(doc, &indent, f)?;
+        }
+
         writeln!(f, "{indent}enum {name} {{")?;
 
         for variant in variants.values() {
@@ -81,17 +97,27 @@ impl Print for StringEnum<'_> {
 
 impl<'a> From<ir::Enum<'a>> for StringEnum<'a> {
     fn from(ir_enum: ir::Enum<'a>) -> Self {
-        let ir::Enum { name, values } = ir_enum;
+        let ir::Enum {
+            name,
+            values,
+            doc,
+            value_docs,
+        } = ir_enum;
 
         let mut r#enum = Self {
             identifier: name,
             variants: OrdStrMap::new(),
+            doc,
         };
 
         for value in values.iter() {
-            let _: Option<Variant> = r#enum
-                .variants
-                .insert(value.to_owned(), Variant::from(value.to_owned()));
+            let variant = Variant {
+                doc: value_docs.get(value).cloned(),
+                ..Variant::from(value.to_owned())
+            };
+
+            let _: Option<Variant> =
+                r#enum.variants.insert(value.to_owned(), variant);
         }
 
         r#enum
@@ -111,6 +137,8 @@ mod tests {
             StringEnum::from(ir::Enum {
                 name: "CountryName".into(),
                 values: TokenSet::from_iter(["France", "Germany", "Italy"]),
+                doc: None,
+                value_docs: OrdStrMap::new(),
             }),
             StringEnum {
                 identifier: "CountryName".into(),
@@ -118,7 +146,8 @@ mod tests {
                     ("France", Variant::from("France")),
                     ("Germany", Variant::from("Germany")),
                     ("Italy", Variant::from("Italy")),
-                ])
+                ]),
+                doc: None,
             }
         );
     }
@@ -135,6 +164,7 @@ mod tests {
                 ("UnitedKingdom", Variant::from("UnitedKingdom")),
                 ("UnitedStates", Variant::from("UnitedStates")),
             ]),
+            doc: None,
         };
 
         let mut f = Vec::new();
@@ -161,6 +191,7 @@ mod tests {
         let variant = Variant {
             name: "France".into(),
             value: "France".into(),
+            doc: None,
         };
 
         let mut f = Vec::new();
@@ -170,6 +201,39 @@ mod tests {
         assert_eq!(String::from_utf8(f).unwrap(), "    France = \"France\",\n");
     }
 
+    #[test]
+    fn test_print_doc_comments() {
+        let r#enum = StringEnum::from(ir::Enum {
+            name: "DrivingSide".into(),
+            values: TokenSet::from_iter(["Left", "Right"]),
+            doc: Some("The side of the road.\n\nDiffers per country.".into()),
+            value_docs: OrdStrMap::from_iter([(
+                "Left",
+                "As in the United Kingdom.".into(),
+            )]),
+        });
+
+        let mut f = Vec::new();
+
+        r#enum.print(0, &mut f).unwrap();
+
+        assert_eq!(
+            String::from_utf8(f).unwrap(),
+            "/**
+ * The side of the road.
+ *
+ * Differs per country.
+ */
+enum DrivingSide {
+    /** As in the United Kingdom. */
+    Left = \"Left\",
+    Right = \"Right\",
+}
+
+"
+        );
+    }
+
     #[test]
     fn test_enum_from_ir_enum() {
         let ir_enum = ir::Enum {
@@ -182,6 +246,8 @@ mod tests {
                 "UnitedKingdom",
                 "UnitedStates",
             ]),
+            doc: None,
+            value_docs: OrdStrMap::new(),
         };
 
         let expected = StringEnum {
@@ -194,6 +260,7 @@ mod tests {
                 ("UnitedKingdom", Variant::from("UnitedKingdom")),
                 ("UnitedStates", Variant::from("UnitedStates")),
             ]),
+            doc: None,
         };
 
         assert_eq!(StringEnum::from(ir_enum.clone()), expected);
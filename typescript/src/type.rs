@@ -59,10 +59,15 @@ impl From<ir::Type> for Type<'_> {
     fn from(r#type: ir::Type) -> Self {
         Self::TypeReference {
             identifier: match r#type {
+                ir::Type::BigInt => "bigint".into(),
                 ir::Type::Boolean => "boolean".into(),
+                ir::Type::Bytes => "Buffer".into(),
                 ir::Type::DateTime => "Date".into(),
                 ir::Type::Float | ir::Type::Int => "number".into(),
-                ir::Type::String => "string".into(),
+                ir::Type::Json => "unknown".into(),
+                ir::Type::Decimal | ir::Type::String | ir::Type::Uuid => {
+                    "string".into()
+                }
             },
             type_arguments: Vec::new(),
         }
@@ -71,12 +76,12 @@ impl From<ir::Type> for Type<'_> {
 
 impl<'a> From<ir::model::EnumRelation<'a>> for Type<'a> {
     fn from(
-        ir::model::EnumRelation { name, cardinality }: ir::model::EnumRelation<
-            'a,
-        >
+        ir::model::EnumRelation {
+            name, cardinality, ..
+        }: ir::model::EnumRelation<'a>
     ) -> Self {
         match cardinality {
-            ir::Cardinality::One => {
+            ir::Cardinality::One | ir::Cardinality::Optional => {
                 Self::TypeReference {
                     identifier: name,
                     type_arguments: Vec::new(),
@@ -101,12 +106,37 @@ impl<'a> From<ir::model::Field<'a>> for Type<'a> {
         }: ir::model::Field
     ) -> Self {
         match cardinality {
-            ir::Cardinality::One => r#type.into(),
+            ir::Cardinality::One | ir::Cardinality::Optional => r#type.into(),
             ir::Cardinality::Many => Self::Array(Box::new(r#type.into())),
         }
     }
 }
 
+impl<'a> From<ir::query::Argument<'a>> for Type<'a> {
+    fn from(
+        ir::query::Argument {
+            r#type,
+            cardinality,
+            ..
+        }: ir::query::Argument<'a>
+    ) -> Self {
+        let r#type = match r#type {
+            ir::query::ArgumentType::Enum(name) => {
+                Self::TypeReference {
+                    identifier: name,
+                    type_arguments: Vec::new(),
+                }
+            }
+            ir::query::ArgumentType::Type(r#type) => r#type.into(),
+        };
+
+        match cardinality {
+            ir::Cardinality::One | ir::Cardinality::Optional => r#type,
+            ir::Cardinality::Many => Self::Array(Box::new(r#type)),
+        }
+    }
+}
+
 impl PrintInline for Type<'_> {
     fn print(
         &self,
@@ -173,6 +203,7 @@ mod tests {
             Type::from(ir::model::EnumRelation {
                 name: "foo".into(),
                 cardinality: ir::Cardinality::One,
+                doc: None,
             }),
             Type::TypeReference {
                 identifier: "foo".into(),
@@ -187,6 +218,7 @@ mod tests {
             Type::from(ir::model::EnumRelation {
                 name: "foo".into(),
                 cardinality: ir::Cardinality::Many,
+                doc: None,
             }),
             Type::Array(Box::new(Type::TypeReference {
                 identifier: "foo".into(),
@@ -195,6 +227,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_ir_type() {
+        for (r#type, identifier) in [
+            (ir::Type::BigInt, "bigint"),
+            (ir::Type::Boolean, "boolean"),
+            (ir::Type::Bytes, "Buffer"),
+            (ir::Type::DateTime, "Date"),
+            (ir::Type::Decimal, "string"),
+            (ir::Type::Float, "number"),
+            (ir::Type::Int, "number"),
+            (ir::Type::Json, "unknown"),
+            (ir::Type::String, "string"),
+            (ir::Type::Uuid, "string"),
+        ] {
+            assert_eq!(
+                Type::from(r#type),
+                Type::TypeReference {
+                    identifier: identifier.into(),
+                    type_arguments: Vec::new()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_ir_argument() {
+        assert_eq!(
+            Type::from(ir::query::Argument {
+                name: "tags".into(),
+                r#type: ir::query::ArgumentType::Enum("Tag".into()),
+                cardinality: ir::Cardinality::Many,
+            }),
+            Type::Array(Box::new(Type::TypeReference {
+                identifier: "Tag".into(),
+                type_arguments: Vec::new()
+            }))
+        );
+    }
+
     #[test]
     fn test_print_function() {
         let function = Type::Function {